@@ -5,19 +5,23 @@
 //! # 主要功能
 //!
 //! - 查询部门列表: [list_depts]
+//! - 分页查询部门列表: [page_depts]
 //! - 获取部门树: [get_dept_tree]
 //! - 添加部门: [add_dept]
 //! - 编辑部门: [edit_dept]
 //! - 删除部门: [delete_dept]
+//! - 恢复部门: [restore_dept]
+//! - 彻底删除部门: [purge_dept]
+//! - 移动部门: [move_dept]
 //! - 修改部门状态: [edit_dept_status]
 //!
 
-use actix_web::{HttpResponse, Responder, delete, post, put, web};
-use common_wrapper::ListWrapper;
+use actix_web::{Responder, delete, post, put, web};
+use common_validation::{CreateGroup, UpdateGroup, ValidatedJson};
+use common_wrapper::i18n::Locale;
 
-use crate::params::dept_param::DeptParam;
-use crate::services::dept::dept_service::DeptService;
-use crate::views::dept_vo::DeptVO;
+use dept_core::params::dept_param::DeptParam;
+use dept_core::services::dept::dept_service::DeptService;
 
 /// 查询部门列表
 ///
@@ -27,16 +31,36 @@ use crate::views::dept_vo::DeptVO;
 ///
 /// - `dept_param`: 部门查询参数，类型: [web::Json]<[DeptParam]>，通过请求体传入
 /// - `dept_service`: 部门服务实例，类型: [web::Data]<[Box]<dyn [DeptService] + Send + Sync>>，通过Actix Web依赖注入提供
+/// - `locale`: 响应消息的语言，类型: [Locale]，由请求头 `Accept-Language` 解析
 ///
 /// # 返回值
 ///
-/// 返回JSON格式的部门列表结果，类型: [HttpResponse]，包含: [ListWrapper]<[DeptVO]>
-#[post("/dept/list")]
-pub async fn list_depts(dept_param: web::Json<DeptParam>, dept_service: web::Data<Box<dyn DeptService + Send + Sync>>) -> impl Responder {
-    let result: ListWrapper<DeptVO> = dept_service
-        .select_dept_vo_list(dept_param.into_inner())
-        .await;
-    HttpResponse::Ok().json(result)
+/// 返回JSON格式的部门列表结果，HTTP状态码由响应码决定，类型: [ListWrapper]<[DeptVO]>
+#[post("/list")]
+pub async fn list_depts(dept_param: web::Json<DeptParam>, dept_service: web::Data<Box<dyn DeptService + Send + Sync>>, locale: Locale) -> impl Responder {
+    dept_service
+        .select_dept_vo_list(dept_param.into_inner(), locale)
+        .await
+}
+
+/// 分页查询部门列表
+///
+/// 根据参数中的 `pageNum`、`pageSize` 在数据库端分页查询部门信息，返回当前页的部门VO列表及分页信息
+///
+/// # 参数
+///
+/// - `dept_param`: 部门查询参数，类型: [web::Json]<[DeptParam]>，通过请求体传入
+/// - `dept_service`: 部门服务实例，类型: [web::Data]<[Box]<dyn [DeptService] + Send + Sync>>，通过Actix Web依赖注入提供
+/// - `locale`: 响应消息的语言，类型: [Locale]，由请求头 `Accept-Language` 解析
+///
+/// # 返回值
+///
+/// 返回JSON格式的部门分页结果，HTTP状态码由响应码决定，类型: [PageWrapper]<[DeptVO]>
+#[post("/page")]
+pub async fn page_depts(dept_param: web::Json<DeptParam>, dept_service: web::Data<Box<dyn DeptService + Send + Sync>>, locale: Locale) -> impl Responder {
+    dept_service
+        .select_dept_vo_page(dept_param.into_inner(), locale)
+        .await
 }
 
 /// 获取部门树
@@ -47,88 +71,149 @@ pub async fn list_depts(dept_param: web::Json<DeptParam>, dept_service: web::Dat
 ///
 /// - `dept_param`: 部门查询参数，类型: [web::Json]<[DeptParam]>，通过请求体传入
 /// - `dept_service`: 部门服务实例，类型: [web::Data]<[Box]<dyn [DeptService] + Send + Sync>>，通过Actix Web依赖注入提供
+/// - `locale`: 响应消息的语言，类型: [Locale]，由请求头 `Accept-Language` 解析
 ///
 /// # 返回值
 ///
-/// 返回JSON格式的部门树结果，类型: [HttpResponse]，包含: [ListWrapper]<[DeptTree]>
-#[post("/dept/getDeptTree")]
-pub async fn get_dept_tree(dept_param: web::Json<DeptParam>, dept_service: web::Data<Box<dyn DeptService + Send + Sync>>) -> impl Responder {
-    let result = dept_service.get_dept_tree(dept_param.into_inner()).await;
-    HttpResponse::Ok().json(result)
+/// 返回JSON格式的部门树结果，HTTP状态码由响应码决定，类型: [ListWrapper]<[DeptTree]>
+#[post("/getDeptTree")]
+pub async fn get_dept_tree(dept_param: web::Json<DeptParam>, dept_service: web::Data<Box<dyn DeptService + Send + Sync>>, locale: Locale) -> impl Responder {
+    dept_service
+        .get_dept_tree(dept_param.into_inner(), locale)
+        .await
 }
 
 /// 添加部门
 ///
-/// 新增一个部门信息
+/// 新增一个部门信息，请求体按 [CreateGroup] 分组验证，验证失败时直接返回参数错误
 ///
 /// # 参数
 ///
-/// - `dept_param`: 部门参数，类型: [web::Json]<[DeptParam]>，通过请求体传入
+/// - `dept_param`: 部门参数，类型: [ValidatedJson]<[DeptParam], [CreateGroup]>，通过请求体传入
 /// - `dept_service`: 部门服务实例，类型: [web::Data]<[Box]<dyn [DeptService] + Send + Sync>>，通过Actix Web依赖注入提供
+/// - `locale`: 响应消息的语言，类型: [Locale]，由请求头 `Accept-Language` 解析
 ///
 /// # 返回值
 ///
-/// 返回操作结果，类型: [HttpResponse]，包含: [ResponseWrapper]
-#[post("/dept/add")]
-pub async fn add_dept(dept_param: web::Json<DeptParam>, dept_service: web::Data<Box<dyn DeptService + Send + Sync>>) -> impl Responder {
-    let result = dept_service.add_dept(dept_param.into_inner()).await;
-    HttpResponse::Ok().json(result)
+/// 返回操作结果，HTTP状态码由响应码决定，类型: [ResponseWrapper]
+#[post("/add")]
+pub async fn add_dept(dept_param: ValidatedJson<DeptParam, CreateGroup>, dept_service: web::Data<Box<dyn DeptService + Send + Sync>>, locale: Locale) -> impl Responder {
+    dept_service.add_dept(dept_param.into_inner(), locale).await
 }
 
 /// 编辑部门
 ///
-/// 修改部门信息
+/// 修改部门信息，请求体按 [UpdateGroup] 分组验证，验证失败时直接返回参数错误
 ///
 /// # 参数
 ///
-/// - `dept_param`: 部门参数，类型: [web::Json]<[DeptParam]>，通过请求体传入
+/// - `dept_param`: 部门参数，类型: [ValidatedJson]<[DeptParam], [UpdateGroup]>，通过请求体传入
 /// - `dept_service`: 部门服务实例，类型: [web::Data]<[Box]<dyn [DeptService] + Send + Sync>>，通过Actix Web依赖注入提供
+/// - `locale`: 响应消息的语言，类型: [Locale]，由请求头 `Accept-Language` 解析
 ///
 /// # 返回值
 ///
-/// 返回操作结果，类型: [HttpResponse]，包含: [ResponseWrapper]
-#[put("/dept/edit")]
-pub async fn edit_dept(dept_param: web::Json<DeptParam>, dept_service: web::Data<Box<dyn DeptService + Send + Sync>>) -> impl Responder {
-    let result = dept_service.edit_dept(dept_param.into_inner()).await;
-    HttpResponse::Ok().json(result)
+/// 返回操作结果，HTTP状态码由响应码决定，类型: [ResponseWrapper]
+#[put("/edit")]
+pub async fn edit_dept(dept_param: ValidatedJson<DeptParam, UpdateGroup>, dept_service: web::Data<Box<dyn DeptService + Send + Sync>>, locale: Locale) -> impl Responder {
+    dept_service
+        .edit_dept(dept_param.into_inner(), locale)
+        .await
 }
 
 /// 删除部门
 ///
-/// 根据部门ID删除指定部门
+/// 根据部门ID逻辑删除指定部门，删除后可以恢复
+///
+/// # 参数
+///
+/// - `path`: 路径参数，包含部门ID，类型: [web::Path]<(String,)>，通过URL路径传入
+/// - `dept_service`: 部门服务实例，类型: [web::Data]<[Box]<dyn [DeptService] + Send + Sync>>，通过Actix Web依赖注入提供
+/// - `locale`: 响应消息的语言，类型: [Locale]，由请求头 `Accept-Language` 解析
+///
+/// # 返回值
+///
+/// 返回操作结果，HTTP状态码由响应码决定，类型: [ResponseWrapper]
+#[delete("/delete/{dept_id}")]
+pub async fn delete_dept(path: web::Path<(String,)>, dept_service: web::Data<Box<dyn DeptService + Send + Sync>>, locale: Locale) -> impl Responder {
+    let dept_id = path.into_inner().0;
+    dept_service.delete_dept(&dept_id, locale).await
+}
+
+/// 恢复部门
+///
+/// 根据部门ID恢复已删除的部门，原父级部门需要存在且未停用
 ///
 /// # 参数
 ///
 /// - `path`: 路径参数，包含部门ID，类型: [web::Path]<(String,)>，通过URL路径传入
 /// - `dept_service`: 部门服务实例，类型: [web::Data]<[Box]<dyn [DeptService] + Send + Sync>>，通过Actix Web依赖注入提供
+/// - `locale`: 响应消息的语言，类型: [Locale]，由请求头 `Accept-Language` 解析
 ///
 /// # 返回值
 ///
-/// 返回操作结果，类型: [HttpResponse]，包含: [ResponseWrapper]
-#[delete("/dept/delete/{dept_id}")]
-pub async fn delete_dept(path: web::Path<(String,)>, dept_service: web::Data<Box<dyn DeptService + Send + Sync>>) -> impl Responder {
+/// 返回操作结果，HTTP状态码由响应码决定，类型: [ResponseWrapper]
+#[post("/restore/{dept_id}")]
+pub async fn restore_dept(path: web::Path<(String,)>, dept_service: web::Data<Box<dyn DeptService + Send + Sync>>, locale: Locale) -> impl Responder {
     let dept_id = path.into_inner().0;
-    let result = dept_service.delete_dept(&dept_id).await;
-    HttpResponse::Ok().json(result)
+    dept_service.restore_dept(&dept_id, locale).await
+}
+
+/// 彻底删除部门
+///
+/// 根据部门ID彻底删除已删除的部门，删除后无法恢复
+///
+/// # 参数
+///
+/// - `path`: 路径参数，包含部门ID，类型: [web::Path]<(String,)>，通过URL路径传入
+/// - `dept_service`: 部门服务实例，类型: [web::Data]<[Box]<dyn [DeptService] + Send + Sync>>，通过Actix Web依赖注入提供
+/// - `locale`: 响应消息的语言，类型: [Locale]，由请求头 `Accept-Language` 解析
+///
+/// # 返回值
+///
+/// 返回操作结果，HTTP状态码由响应码决定，类型: [ResponseWrapper]
+#[delete("/purge/{dept_id}")]
+pub async fn purge_dept(path: web::Path<(String,)>, dept_service: web::Data<Box<dyn DeptService + Send + Sync>>, locale: Locale) -> impl Responder {
+    let dept_id = path.into_inner().0;
+    dept_service.purge_dept(&dept_id, locale).await
+}
+
+/// 移动部门
+///
+/// 将部门连同其下级部门移动到新的父级部门下，新的父级部门不能为部门本身或其下级部门
+///
+/// # 参数
+///
+/// - `path`: 路径参数，包含部门ID和新的父级部门ID，类型: [web::Path]<(String, String)>，通过URL路径传入
+/// - `dept_service`: 部门服务实例，类型: [web::Data]<[Box]<dyn [DeptService] + Send + Sync>>，通过Actix Web依赖注入提供
+/// - `locale`: 响应消息的语言，类型: [Locale]，由请求头 `Accept-Language` 解析
+///
+/// # 返回值
+///
+/// 返回操作结果，HTTP状态码由响应码决定，类型: [ResponseWrapper]
+#[put("/move/{id}/{new_parent_id}")]
+pub async fn move_dept(path: web::Path<(String, String)>, dept_service: web::Data<Box<dyn DeptService + Send + Sync>>, locale: Locale) -> impl Responder {
+    let (id, new_parent_id) = path.into_inner();
+    dept_service.move_dept(&id, &new_parent_id, locale).await
 }
 
 /// 修改部门状态
 ///
-/// 根据部门ID修改部门状态
+/// 根据部门ID修改部门状态，按部门状态策略停用下级部门、检查上级部门
 ///
 /// # 参数
 ///
 /// - `path`: 路径参数，包含部门ID和状态，类型: [web::Path]<(String, i32)>，通过URL路径传入
 /// - `dept_service`: 部门服务实例，类型: [web::Data]<[Box]<dyn [DeptService] + Send + Sync>>，通过Actix Web依赖注入提供
+/// - `locale`: 响应消息的语言，类型: [Locale]，由请求头 `Accept-Language` 解析
 ///
 /// # 返回值
 ///
-/// 返回操作结果，类型: [HttpResponse]，包含: [ResponseWrapper]
-#[put("/dept/editStatus/{id}/{status}")]
-pub async fn edit_dept_status(path: web::Path<(String, i32)>, dept_service: web::Data<Box<dyn DeptService + Send + Sync>>) -> impl Responder {
+/// 返回部门本身及下级部门实际更新的行数，HTTP状态码由响应码决定，类型: [SingleWrapper]<[u64]>
+#[put("/editStatus/{id}/{status}")]
+pub async fn edit_dept_status(path: web::Path<(String, i32)>, dept_service: web::Data<Box<dyn DeptService + Send + Sync>>, locale: Locale) -> impl Responder {
     let (id, status) = path.into_inner();
-    let result = dept_service.edit_dept_status(&id, status).await;
-    HttpResponse::Ok().json(result)
+    dept_service.edit_dept_status(&id, status, locale).await
 }
 
 /// 注册部门相关路由
@@ -146,10 +231,14 @@ pub fn config(cfg: &mut web::ServiceConfig) {
     cfg.service(
         web::scope("/dept")
             .service(list_depts)
+            .service(page_depts)
             .service(get_dept_tree)
             .service(add_dept)
             .service(edit_dept)
             .service(delete_dept)
+            .service(restore_dept)
+            .service(purge_dept)
+            .service(move_dept)
             .service(edit_dept_status),
     );
 }
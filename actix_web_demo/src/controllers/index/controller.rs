@@ -24,5 +24,5 @@ pub async fn hello() -> impl Responder {
 ///
 /// 无返回值，直接修改服务配置
 pub fn config(cfg: &mut web::ServiceConfig) {
-    cfg.service(web::scope("/").service(hello));
+    cfg.service(hello);
 }
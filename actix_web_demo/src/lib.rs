@@ -0,0 +1,6 @@
+//! Actix Web 部门管理演示
+//!
+//! 部门的模型、数据访问层和业务层由 [dept_core] 提供，本库只导出路由相关的控制器，
+//! `main.rs` 通过它们组装并启动服务，`tests/` 下的集成测试启用 `memory_impl` 特性后也可以在进程内组装服务，无需数据库。
+
+pub mod controllers;
@@ -1,20 +1,17 @@
 //! 集成测试
 //!
 //! 使用 Actix Web 提供的测试工具进行集成测试
+//!
+//! 部门相关的测试使用内存实现的数据访问层，需要启用 `memory_impl` 特性：
+//! `cargo test -p actix_web_demo --features memory_impl --test integration_test`
 
 use actix_web::{
     App,
     http::StatusCode,
     test::{self, TestRequest},
 };
-use serde_json::json;
 
-use actix_web_demo::{
-    controllers::{dept::controller as dept_controller, index::controller as index_controller},
-    repositories::dept::{dept_repository::DeptRepository, sqlx_impl::DeptRepositorySqlxImpl},
-    services::dept::{dept_service::DeptService, dept_service_impl::DeptServiceImpl},
-};
-use std::sync::Arc;
+use actix_web_demo::controllers::index::controller as index_controller;
 
 /// 测试首页路由
 #[actix_web::test]
@@ -32,142 +29,875 @@ async fn test_index() {
     assert_eq!(resp.status(), StatusCode::OK);
 }
 
-/// 测试部门列表接口
-#[actix_web::test]
-async fn test_dept_list() {
-    // 注意：由于数据库依赖，这个测试可能需要mock repository才能正常运行
-    // 这里提供一个基本的测试框架示例
+#[cfg(feature = "memory_impl")]
+mod dept {
+    use super::*;
+    use actix_web::web;
+    use actix_web_demo::controllers::dept::controller as dept_controller;
+    use dept_core::{
+        models::Dept,
+        repositories::dept::{
+            dept_repository::{DeptQueryOptions, DeptRepository},
+            memory_impl::DeptRepositoryMemoryImpl,
+        },
+        services::dept::{dept_service::DeptService, dept_service_impl::DeptServiceImpl, dept_status_policy::DeptStatusPolicy},
+    };
+    use serde_json::{Value, json};
+    use std::sync::Arc;
 
-    /*
-    // 创建mock repository（实际使用中可能需要mock框架）
-    let repository = Arc::new(MockDeptRepository::new()) as Arc<dyn DeptRepository>;
-    let dept_service = Box::new(DeptServiceImpl::new(repository)) as Box<dyn DeptService + Send + Sync>;
-    let dept_service_data = actix_web::web::Data::new(dept_service);
+    /// 已启用的演示部门 `XXX分公司`
+    const ENABLED_DEPT_ID: &str = "a308936d3b5d49e185fe15bf7d71b688";
+    /// 已停用的演示部门 `市场部`，其上级部门均已启用
+    const DISABLED_DEPT_ID: &str = "0753bee6314c4f6fa01cb5166e6e4b14";
+    /// 已启用的演示根部门 `NB PLUS 科技`
+    const ROOT_DEPT_ID: &str = "065a3eb180214ccfbb653f63287d285d";
 
-    // 初始化测试服务
-    let app = test::init_service(
-        App::new()
-            .app_data(dept_service_data)
-            .configure(dept_controller::config)
-    ).await;
-
-    // 创建测试数据
-    let dept_param = json!({
-        "parentId": null,
-        "name": null,
-        "email": null,
-        "telephone": null,
-        "address": null,
-        "logo": null,
-        "deptLevel": null,
-        "seqNo": null,
-        "status": null,
-        "createBy": null,
-        "createTime": null,
-        "updateBy": null,
-        "updateTime": null,
-        "remark": null,
-        "pageNum": 1,
-        "pageSize": 10
-    });
+    /// 创建基于演示数据的部门服务
+    fn dept_service_data() -> web::Data<Box<dyn DeptService + Send + Sync>> {
+        let repository = Arc::new(DeptRepositoryMemoryImpl::with_demo_data()) as Arc<dyn DeptRepository>;
+        let dept_service = Box::new(DeptServiceImpl::new(repository)) as Box<dyn DeptService + Send + Sync>;
+        web::Data::new(dept_service)
+    }
 
-    // 创建测试请求
-    let req = TestRequest::post()
-        .uri("/dept/list")
-        .set_json(&dept_param)
-        .to_request();
+    /// 测试部门列表接口
+    #[actix_web::test]
+    async fn test_dept_list() {
+        // 初始化测试服务
+        let app = test::init_service(
+            App::new()
+                .app_data(dept_service_data())
+                .configure(dept_controller::config),
+        )
+        .await;
 
-    // 发送请求并获取响应
-    let resp = test::call_service(&app, req).await;
+        // 不带条件时返回全部演示数据
+        let req = TestRequest::post()
+            .uri("/dept/list")
+            .set_json(json!({}))
+            .to_request();
+        let body: Value = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(body["code"], 1);
+        assert_eq!(body["data"].as_array().unwrap().len(), 8);
 
-    // 验证响应状态
-    assert_eq!(resp.status(), StatusCode::OK);
-    */
-}
+        // 名称模糊匹配，其余字段相等匹配
+        let req = TestRequest::post()
+            .uri("/dept/list")
+            .set_json(json!({ "name": "公司", "status": 1 }))
+            .to_request();
+        let body: Value = test::call_and_read_body_json(&app, req).await;
+        let mut ids: Vec<_> = body["data"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|dept| dept["id"].as_str().unwrap())
+            .collect();
+        ids.sort();
+        assert_eq!(ids, ["6bcbfa6a71fa454d8363b885ccbcb30a", ENABLED_DEPT_ID]);
+    }
 
-/// 测试添加部门接口
-#[actix_web::test]
-async fn test_add_dept() {
-    // 同样，这个测试需要mock repository
+    /// 测试部门分页接口
+    #[actix_web::test]
+    async fn test_dept_page() {
+        // 初始化测试服务
+        let app = test::init_service(
+            App::new()
+                .app_data(dept_service_data())
+                .configure(dept_controller::config),
+        )
+        .await;
 
-    /*
-    // 创建mock repository
-    let repository = Arc::new(MockDeptRepository::new()) as Arc<dyn DeptRepository>;
-    let dept_service = Box::new(DeptServiceImpl::new(repository)) as Box<dyn DeptService + Send + Sync>;
-    let dept_service_data = actix_web::web::Data::new(dept_service);
+        // 创建测试请求
+        let req = TestRequest::post()
+            .uri("/dept/page")
+            .set_json(json!({ "pageNum": 3, "pageSize": 3 }))
+            .to_request();
+        let body: Value = test::call_and_read_body_json(&app, req).await;
 
-    // 初始化测试服务
-    let app = test::init_service(
-        App::new()
-            .app_data(dept_service_data)
-            .configure(dept_controller::config)
-    ).await;
-
-    // 创建测试数据
-    let dept_data = json!({
-        "parentId": "065a3eb180214ccfbb653f63287d285d",
-        "name": "测试部门",
-        "seqNo": 1,
-        "telephone": "13800000000",
-        "email": "test@example.com",
-        "status": 1,
-        "createBy": "test_user"
-    });
+        // 共8条数据，第3页只剩2条
+        assert_eq!(body["code"], 1);
+        assert_eq!(body["total"], 8);
+        assert_eq!(body["data"].as_array().unwrap().len(), 2);
+    }
 
-    // 创建测试请求
-    let req = TestRequest::post()
-        .uri("/dept/add")
-        .set_json(&dept_data)
-        .to_request();
+    /// 测试按请求头 `Accept-Language` 返回对应语言的消息
+    #[actix_web::test]
+    async fn test_accept_language() {
+        // 初始化测试服务
+        let app = test::init_service(
+            App::new()
+                .app_data(dept_service_data())
+                .configure(dept_controller::config),
+        )
+        .await;
 
-    // 发送请求并获取响应
-    let resp = test::call_service(&app, req).await;
+        // 未指定语言时使用简体中文
+        let req = TestRequest::post()
+            .uri("/dept/list")
+            .set_json(json!({ "id": ENABLED_DEPT_ID }))
+            .to_request();
+        let body: Value = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(body["message"], "成功");
+        assert_eq!(body["data"][0]["statusDesc"], "启用");
 
-    // 验证响应状态
-    assert_eq!(resp.status(), StatusCode::OK);
-    */
-}
+        // 响应消息及状态描述
+        let req = TestRequest::post()
+            .uri("/dept/list")
+            .insert_header(("accept-language", "en-US,en;q=0.9,zh-CN;q=0.8"))
+            .set_json(json!({ "id": ENABLED_DEPT_ID }))
+            .to_request();
+        let body: Value = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(body["message"], "Success");
+        assert_eq!(body["data"][0]["statusDesc"], "Enabled");
 
-/// 测试编辑部门接口
-#[actix_web::test]
-async fn test_edit_dept() {
-    // 同样，这个测试需要mock repository
+        // 业务错误
+        let req = TestRequest::post()
+            .uri("/dept/add")
+            .insert_header(("accept-language", "en"))
+            .set_json(json!({ "parentId": DISABLED_DEPT_ID, "name": "测试部门", "status": 1 }))
+            .to_request();
+        let body: Value = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(body["message"], "The parent department is disabled!");
 
-    /*
-    // 创建mock repository
-    let repository = Arc::new(MockDeptRepository::new()) as Arc<dyn DeptRepository>;
-    let dept_service = Box::new(DeptServiceImpl::new(repository)) as Box<dyn DeptService + Send + Sync>;
-    let dept_service_data = actix_web::web::Data::new(dept_service);
+        // 验证错误，字段描述为简体中文，英文错误信息使用字段路径
+        let req = TestRequest::post()
+            .uri("/dept/add")
+            .insert_header(("accept-language", "en-US"))
+            .set_json(json!({ "parentId": ENABLED_DEPT_ID, "name": "测试部门" }))
+            .to_request();
+        let body: Value = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(body["message"], "status must not be empty");
 
-    // 初始化测试服务
-    let app = test::init_service(
-        App::new()
-            .app_data(dept_service_data)
-            .configure(dept_controller::config)
-    ).await;
-
-    // 创建测试数据
-    let dept_data = json!({
-        "id": "test_dept_id",
-        "parentId": "065a3eb180214ccfbb653f63287d285d",
-        "name": "更新测试部门",
-        "seqNo": 2,
-        "telephone": "13900000000",
-        "email": "update@example.com",
-        "status": 1,
-        "updateBy": "test_user"
-    });
+        // 结构体级别的自定义验证错误同样从消息目录中获取
+        let req = TestRequest::put()
+            .uri("/dept/edit")
+            .insert_header(("accept-language", "en-US"))
+            .set_json(json!({ "id": ENABLED_DEPT_ID, "parentId": ENABLED_DEPT_ID, "name": "测试部门", "status": 1 }))
+            .to_request();
+        let body: Value = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(body["message"], "The parent department cannot be the department itself!");
+    }
 
-    // 创建测试请求
-    let req = TestRequest::put()
-        .uri("/dept/edit")
-        .set_json(&dept_data)
-        .to_request();
+    /// 测试添加部门接口
+    #[actix_web::test]
+    async fn test_add_dept() {
+        // 初始化测试服务
+        let app = test::init_service(
+            App::new()
+                .app_data(dept_service_data())
+                .configure(dept_controller::config),
+        )
+        .await;
 
-    // 发送请求并获取响应
-    let resp = test::call_service(&app, req).await;
+        // 父级部门已停用时不允许添加
+        let req = TestRequest::post()
+            .uri("/dept/add")
+            .set_json(json!({ "parentId": DISABLED_DEPT_ID, "name": "测试部门", "seqNo": 1, "status": 1 }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body["code"], -5);
 
-    // 验证响应状态
-    assert_eq!(resp.status(), StatusCode::OK);
-    */
+        // 创建测试数据
+        let dept_data = json!({
+            "parentId": ENABLED_DEPT_ID,
+            "name": "测试部门",
+            "seqNo": 1,
+            "telephone": "13800000000",
+            "email": "test@example.com",
+            "status": 1,
+            "createBy": "test_user"
+        });
+        let req = TestRequest::post()
+            .uri("/dept/add")
+            .set_json(&dept_data)
+            .to_request();
+        let body: Value = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(body["code"], 1);
+
+        // 新增的部门可以查询到
+        let req = TestRequest::post()
+            .uri("/dept/list")
+            .set_json(json!({ "parentId": ENABLED_DEPT_ID, "name": "测试部门" }))
+            .to_request();
+        let body: Value = test::call_and_read_body_json(&app, req).await;
+        let data = body["data"].as_array().unwrap();
+        assert_eq!(data.len(), 1);
+        assert_eq!(data[0]["parentName"], "XXX分公司");
+
+        // 同一父级部门下不允许重名
+        let req = TestRequest::post()
+            .uri("/dept/add")
+            .set_json(&dept_data)
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body["message"], "已存在相同部门名称!");
+
+        // 父级部门不存在时不允许添加
+        let req = TestRequest::post()
+            .uri("/dept/add")
+            .set_json(json!({ "parentId": "not_exists", "name": "测试部门", "status": 1 }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body["message"], "传入的父级部门信息不存在!");
+    }
+
+    /// 测试添加部门时按分组验证请求体
+    #[actix_web::test]
+    async fn test_add_dept_validation() {
+        // 初始化测试服务
+        let app = test::init_service(
+            App::new()
+                .app_data(dept_service_data())
+                .configure(dept_controller::config),
+        )
+        .await;
+
+        // 部门名称超过 varchar(30)
+        let req = TestRequest::post()
+            .uri("/dept/add")
+            .set_json(json!({ "parentId": ENABLED_DEPT_ID, "name": "部".repeat(31), "status": 1 }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body["code"], -3);
+        assert_eq!(body["message"], "部门名称 长度不符合要求: 必须在 1~30 个字符之间");
+
+        // 联系电话超过 varchar(11)
+        let req = TestRequest::post()
+            .uri("/dept/add")
+            .set_json(json!({ "parentId": ENABLED_DEPT_ID, "name": "测试部门", "telephone": "138000000000", "status": 1 }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body["message"], "联系电话 长度不符合要求: 必须在 1~11 个字符之间; 联系电话 不是有效的手机号码");
+
+        // 邮箱、联系电话、logo地址的格式
+        let req = TestRequest::post()
+            .uri("/dept/add")
+            .set_json(json!({ "parentId": ENABLED_DEPT_ID, "name": "测试部门", "email": "test", "telephone": "12345678901", "logo": "logo.png", "status": 1 }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body["data"]["email"][0]["rule"], "email");
+        assert_eq!(body["data"]["telephone"][0]["message"], "联系电话 不是有效的手机号码");
+        assert_eq!(body["data"]["logo"][0]["rule"], "url");
+
+        // 部门状态不能为空
+        let req = TestRequest::post()
+            .uri("/dept/add")
+            .set_json(json!({ "parentId": ENABLED_DEPT_ID, "name": "测试部门" }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body["message"], "部门状态 不能为空");
+
+        // 编辑时部门ID不能为空
+        let req = TestRequest::put()
+            .uri("/dept/edit")
+            .set_json(json!({ "name": "测试部门", "status": 1 }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body["message"], "部门ID 不能为空");
+
+        // 编辑时父部门不能为部门本身，结构体级别的错误字段路径为空
+        let req = TestRequest::put()
+            .uri("/dept/edit")
+            .set_json(json!({ "id": ENABLED_DEPT_ID, "parentId": ENABLED_DEPT_ID, "name": "测试部门", "status": 1 }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body["message"], "父部门不能为部门本身!");
+        assert_eq!(body["data"][""][0]["rule"], "custom");
+
+        // 一次返回全部字段的验证错误，以请求体中的字段名为键
+        let req = TestRequest::post()
+            .uri("/dept/add")
+            .set_json(json!({ "parentId": "0".repeat(33), "name": "部".repeat(31), "email": "a".repeat(51) }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body["code"], -3);
+        assert_eq!(body["data"]["parentId"][0]["rule"], "length");
+        assert_eq!(body["data"]["name"][0]["message"], "部门名称 长度不符合要求: 必须在 1~30 个字符之间");
+        assert_eq!(body["data"]["email"][0]["rule"], "length");
+        assert_eq!(body["data"]["status"][0]["rule"], "not_null");
+
+        // 请求体不是合法的JSON
+        let req = TestRequest::post()
+            .uri("/dept/add")
+            .insert_header(("content-type", "application/json"))
+            .set_payload("{")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body["code"], -3);
+    }
+
+    /// 测试编辑部门接口
+    #[actix_web::test]
+    async fn test_edit_dept() {
+        // 初始化测试服务
+        let app = test::init_service(
+            App::new()
+                .app_data(dept_service_data())
+                .configure(dept_controller::config),
+        )
+        .await;
+
+        // 创建测试数据
+        let dept_data = json!({
+            "id": "88921a7b70a04ea2b7d1db1651ffe666",
+            "parentId": ENABLED_DEPT_ID,
+            "name": "更新测试部门",
+            "seqNo": 2,
+            "telephone": "13900000000",
+            "email": "update@example.com",
+            "status": 1,
+            "updateBy": "test_user"
+        });
+
+        // 创建测试请求
+        let req = TestRequest::put()
+            .uri("/dept/edit")
+            .set_json(&dept_data)
+            .to_request();
+        let body: Value = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(body["code"], 1);
+
+        // 验证修改后的数据
+        let req = TestRequest::post()
+            .uri("/dept/list")
+            .set_json(json!({ "id": "88921a7b70a04ea2b7d1db1651ffe666" }))
+            .to_request();
+        let body: Value = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(body["data"][0]["name"], "更新测试部门");
+        assert_eq!(body["data"][0]["parent_id"], ENABLED_DEPT_ID);
+    }
+
+    /// 测试移动部门接口
+    #[actix_web::test]
+    async fn test_move_dept() {
+        // 初始化测试服务
+        let app = test::init_service(
+            App::new()
+                .app_data(dept_service_data())
+                .configure(dept_controller::config),
+        )
+        .await;
+
+        // 已启用的 `研发部` 连同其下级部门移动到 `XXX分公司` 下
+        let req = TestRequest::put()
+            .uri(&format!("/dept/move/88921a7b70a04ea2b7d1db1651ffe666/{ENABLED_DEPT_ID}"))
+            .to_request();
+        let body: Value = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(body["code"], 1);
+
+        let req = TestRequest::post()
+            .uri("/dept/list")
+            .set_json(json!({ "id": "88921a7b70a04ea2b7d1db1651ffe666" }))
+            .to_request();
+        let body: Value = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(body["data"][0]["parent_id"], ENABLED_DEPT_ID);
+
+        // 不能移动到下级部门下
+        let req = TestRequest::put()
+            .uri(&format!("/dept/move/{ENABLED_DEPT_ID}/88921a7b70a04ea2b7d1db1651ffe666"))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body["code"], -5);
+        assert_eq!(body["message"], "父级部门不能为部门本身或其下级部门!");
+
+        // 不能移动到部门本身下
+        let req = TestRequest::put()
+            .uri(&format!("/dept/move/{ENABLED_DEPT_ID}/{ENABLED_DEPT_ID}"))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+
+        // 编辑部门时同样不能以下级部门为父级部门
+        let req = TestRequest::put()
+            .uri("/dept/edit")
+            .set_json(json!({ "id": ENABLED_DEPT_ID, "parentId": "88921a7b70a04ea2b7d1db1651ffe666", "name": "XXX分公司", "status": 1 }))
+            .insert_header(("Accept-Language", "en-US"))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body["message"], "The parent department cannot be the department itself or one of its sub-departments!");
+
+        // 不能移动到已停用的部门下
+        let req = TestRequest::put()
+            .uri(&format!("/dept/move/88921a7b70a04ea2b7d1db1651ffe666/{DISABLED_DEPT_ID}"))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body["message"], "传入的父级部门已停用!");
+
+        // 部门不存在
+        let req = TestRequest::put()
+            .uri(&format!("/dept/move/not_exists/{ENABLED_DEPT_ID}"))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    /// 测试新增、编辑、移动部门时维护祖级路径
+    #[actix_web::test]
+    async fn test_dept_ancestors() {
+        const HEAD_OFFICE_ID: &str = "6bcbfa6a71fa454d8363b885ccbcb30a";
+        const RD_DEPT_ID: &str = "88921a7b70a04ea2b7d1db1651ffe666";
+        const OPS_DEPT_ID: &str = "ebd8118fe94247809abab8c72579cf7d";
+
+        // 初始化测试服务，保留仓储以便直接查询上级、下级部门
+        let repository = Arc::new(DeptRepositoryMemoryImpl::with_demo_data()) as Arc<dyn DeptRepository>;
+        let dept_service = Box::new(DeptServiceImpl::new(repository.clone())) as Box<dyn DeptService + Send + Sync>;
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(dept_service))
+                .configure(dept_controller::config),
+        )
+        .await;
+        let ancestors = async |id: &str| {
+            repository
+                .select_by_primary_key(id)
+                .await
+                .unwrap()
+                .and_then(|dept| dept.ancestors)
+                .unwrap()
+        };
+        let ids = |depts: Vec<Dept>| depts.into_iter().map(|dept| dept.id).collect::<Vec<_>>();
+        assert_eq!(ancestors(OPS_DEPT_ID).await, format!("/{ROOT_DEPT_ID}/{ENABLED_DEPT_ID}/"));
+
+        // 编辑部门修改父级部门，`XXX分公司` 连同其下的 `运维` 移动到 `研发部` 下
+        let req = TestRequest::put()
+            .uri("/dept/edit")
+            .set_json(json!({ "id": ENABLED_DEPT_ID, "parentId": RD_DEPT_ID, "name": "XXX分公司", "status": 1 }))
+            .to_request();
+        let body: Value = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(body["code"], 1);
+        let rd_path = format!("/{ROOT_DEPT_ID}/{HEAD_OFFICE_ID}/{RD_DEPT_ID}/");
+        assert_eq!(ancestors(ENABLED_DEPT_ID).await, rd_path);
+        assert_eq!(ancestors(OPS_DEPT_ID).await, format!("{rd_path}{ENABLED_DEPT_ID}/"));
+
+        // 上级部门按从根部门到父级部门的顺序排列
+        let depts = repository.select_ancestors(OPS_DEPT_ID).await.unwrap();
+        assert_eq!(ids(depts), [ROOT_DEPT_ID, HEAD_OFFICE_ID, RD_DEPT_ID, ENABLED_DEPT_ID]);
+        assert!(
+            repository
+                .select_ancestors(ROOT_DEPT_ID)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        // 新增的根部门祖级路径为 `/`
+        let req = TestRequest::post()
+            .uri("/dept/add")
+            .set_json(json!({ "name": "新根部门", "status": 1 }))
+            .to_request();
+        let body: Value = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(body["code"], 1);
+        let req = TestRequest::post()
+            .uri("/dept/list")
+            .set_json(json!({ "name": "新根部门" }))
+            .to_request();
+        let body: Value = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(body["data"][0]["ancestors"], "/");
+        let new_root_id = body["data"][0]["id"].as_str().unwrap().to_string();
+
+        // 移动部门时整棵子树的祖级路径一起改写
+        let req = TestRequest::put()
+            .uri(&format!("/dept/move/{RD_DEPT_ID}/{new_root_id}"))
+            .to_request();
+        let body: Value = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(body["code"], 1);
+        assert_eq!(ancestors(OPS_DEPT_ID).await, format!("/{new_root_id}/{RD_DEPT_ID}/{ENABLED_DEPT_ID}/"));
+        let depts = repository.select_descendants(&new_root_id).await.unwrap();
+        assert_eq!(ids(depts), [RD_DEPT_ID, ENABLED_DEPT_ID, OPS_DEPT_ID]);
+        let depts = repository.select_descendants(HEAD_OFFICE_ID).await.unwrap();
+        assert!(!ids(depts).contains(&RD_DEPT_ID.to_string()));
+    }
+
+    /// 测试部门树接口的排序、层数及子树参数
+    #[actix_web::test]
+    async fn test_dept_tree() {
+        const HEAD_OFFICE_ID: &str = "6bcbfa6a71fa454d8363b885ccbcb30a";
+        const SALES_DEPT_ID: &str = "3fd2fbc8c93e4a20a6e46387b36c0264";
+
+        // 初始化测试服务，保留仓储以便直接修改显示顺序
+        let repository = Arc::new(DeptRepositoryMemoryImpl::with_demo_data()) as Arc<dyn DeptRepository>;
+        let dept_service = Box::new(DeptServiceImpl::new(repository.clone())) as Box<dyn DeptService + Send + Sync>;
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(dept_service))
+                .configure(dept_controller::config),
+        )
+        .await;
+        let names = |nodes: &Value| {
+            nodes
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|node| node["name"].as_str().unwrap().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        // 完整的部门树，显示顺序相同时按名称排序，第3层的部门不会丢失
+        let req = TestRequest::post()
+            .uri("/dept/getDeptTree")
+            .set_json(json!({}))
+            .to_request();
+        let body: Value = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(body["code"], 1);
+        assert_eq!(names(&body["data"]), ["NB PLUS 科技"]);
+        let companies = &body["data"][0]["children"];
+        assert_eq!(names(companies), ["XXX分公司", "XXX总公司"]);
+        assert_eq!(names(&companies[0]["children"]), ["运维"]);
+        assert_eq!(names(&companies[1]["children"]), ["市场部", "研发部", "财务部", "销售部"]);
+
+        // 显示顺序优先于名称
+        let dept = Dept {
+            id: SALES_DEPT_ID.to_string(),
+            seq_no: Some(0),
+            ..Default::default()
+        };
+        repository
+            .update_by_primary_key_selective(&dept)
+            .await
+            .unwrap();
+
+        // 以 `XXX总公司` 为根的子树
+        let req = TestRequest::post()
+            .uri("/dept/getDeptTree")
+            .set_json(json!({ "rootId": HEAD_OFFICE_ID }))
+            .to_request();
+        let body: Value = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(names(&body["data"]), ["XXX总公司"]);
+        assert_eq!(names(&body["data"][0]["children"]), ["销售部", "市场部", "研发部", "财务部"]);
+
+        // 只返回前2层
+        let req = TestRequest::post()
+            .uri("/dept/getDeptTree")
+            .set_json(json!({ "maxDepth": 2 }))
+            .to_request();
+        let body: Value = test::call_and_read_body_json(&app, req).await;
+        let companies = &body["data"][0]["children"];
+        assert_eq!(names(companies), ["XXX分公司", "XXX总公司"]);
+        assert!(companies[0]["children"].as_array().unwrap().is_empty());
+        assert!(companies[1]["children"].as_array().unwrap().is_empty());
+
+        // 根节点不存在时返回空列表
+        let req = TestRequest::post()
+            .uri("/dept/getDeptTree")
+            .set_json(json!({ "rootId": "not-exists" }))
+            .to_request();
+        let body: Value = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(body["code"], 1);
+        assert!(body["data"].as_array().unwrap().is_empty());
+    }
+
+    /// 测试按条件查询部门树时保留上级部门及返回节点详情
+    #[actix_web::test]
+    async fn test_dept_tree_matched() {
+        // 初始化测试服务
+        let app = test::init_service(
+            App::new()
+                .app_data(dept_service_data())
+                .configure(dept_controller::config),
+        )
+        .await;
+
+        // 上级部门不满足条件时仍保留在树中，只有 `运维` 满足条件
+        let req = TestRequest::post()
+            .uri("/dept/getDeptTree")
+            .set_json(json!({ "name": "运维" }))
+            .to_request();
+        let body: Value = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(body["code"], 1);
+        let root = &body["data"][0];
+        let branch_office = &root["children"][0];
+        let ops_dept = &branch_office["children"][0];
+        assert_eq!(body["data"].as_array().unwrap().len(), 1);
+        assert_eq!((&root["name"], &root["matched"]), (&json!("NB PLUS 科技"), &json!(false)));
+        assert_eq!((&branch_office["name"], &branch_office["matched"]), (&json!("XXX分公司"), &json!(false)));
+        assert_eq!((&ops_dept["name"], &ops_dept["matched"]), (&json!("运维"), &json!(true)));
+        assert_eq!(branch_office["children"].as_array().unwrap().len(), 1);
+        // 未请求节点详情时不返回详情字段
+        assert!(ops_dept.get("status").is_none());
+        assert!(ops_dept.get("childCount").is_none());
+
+        // 节点详情，子部门数量不受查询条件影响
+        let req = TestRequest::post()
+            .uri("/dept/getDeptTree")
+            .set_json(json!({ "name": "研发", "detail": true }))
+            .to_request();
+        let body: Value = test::call_and_read_body_json(&app, req).await;
+        let head_office = &body["data"][0]["children"][0];
+        assert_eq!(head_office["name"], "XXX总公司");
+        assert_eq!(head_office["childCount"], 4);
+        assert_eq!(head_office["disabled"], false);
+        assert_eq!(head_office["status"], 1);
+        let rd_dept = &head_office["children"][0];
+        assert_eq!(head_office["children"].as_array().unwrap().len(), 1);
+        assert_eq!(rd_dept["name"], "研发部");
+        assert_eq!(rd_dept["matched"], true);
+        assert_eq!(rd_dept["childCount"], 0);
+        assert_eq!(rd_dept["disabled"], false);
+        assert_eq!(rd_dept["seqNo"], 1);
+    }
+
+    /// 测试删除部门接口
+    #[actix_web::test]
+    async fn test_delete_dept() {
+        // 初始化测试服务
+        let app = test::init_service(
+            App::new()
+                .app_data(dept_service_data())
+                .configure(dept_controller::config),
+        )
+        .await;
+
+        // 创建测试请求
+        let req = TestRequest::delete()
+            .uri("/dept/delete/ebd8118fe94247809abab8c72579cf7d")
+            .to_request();
+        let body: Value = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(body["code"], 1);
+
+        // 删除后查询不到
+        let req = TestRequest::post()
+            .uri("/dept/list")
+            .set_json(json!({ "id": "ebd8118fe94247809abab8c72579cf7d" }))
+            .to_request();
+        let body: Value = test::call_and_read_body_json(&app, req).await;
+        assert!(body["data"].as_array().unwrap().is_empty());
+
+        // 已删除的部门再次删除时返回404
+        let req = TestRequest::delete()
+            .uri("/dept/delete/ebd8118fe94247809abab8c72579cf7d")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body["code"], -4);
+
+        // 存在子部门的部门不允许删除
+        let req = TestRequest::delete()
+            .uri("/dept/delete/6bcbfa6a71fa454d8363b885ccbcb30a")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body["code"], -5);
+    }
+
+    /// 测试恢复及彻底删除部门
+    #[actix_web::test]
+    async fn test_restore_and_purge_dept() {
+        const OPS_DEPT_ID: &str = "ebd8118fe94247809abab8c72579cf7d";
+
+        let repository = Arc::new(DeptRepositoryMemoryImpl::with_demo_data()) as Arc<dyn DeptRepository>;
+        let dept_service = Box::new(DeptServiceImpl::new(repository.clone())) as Box<dyn DeptService + Send + Sync>;
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(dept_service))
+                .configure(dept_controller::config),
+        )
+        .await;
+        // 包含已删除部门时查询部门是否已删除，部门不存在时为None
+        let deleted = async |id: &str| {
+            let condition = Dept { id: id.to_string(), ..Default::default() };
+            repository
+                .select_dept_list_with_options(&condition, DeptQueryOptions::include_deleted())
+                .await
+                .unwrap()
+                .first()
+                .map(|dept| dept.deleted_at.is_some())
+        };
+
+        // 未删除的部门不能恢复或彻底删除
+        let req = TestRequest::post()
+            .uri(&format!("/dept/restore/{OPS_DEPT_ID}"))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body["code"], -5);
+        let req = TestRequest::delete()
+            .uri(&format!("/dept/purge/{OPS_DEPT_ID}"))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+
+        // 删除后查询不到，但仍保留在数据库中
+        let req = TestRequest::delete()
+            .uri(&format!("/dept/delete/{OPS_DEPT_ID}"))
+            .to_request();
+        let body: Value = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(body["code"], 1);
+        assert!(
+            repository
+                .select_by_primary_key(OPS_DEPT_ID)
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert_eq!(deleted(OPS_DEPT_ID).await, Some(true));
+
+        // 恢复后重新出现在查询结果中
+        let req = TestRequest::post()
+            .uri(&format!("/dept/restore/{OPS_DEPT_ID}"))
+            .to_request();
+        let body: Value = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(body["code"], 1);
+        let req = TestRequest::post()
+            .uri("/dept/list")
+            .set_json(json!({ "id": OPS_DEPT_ID }))
+            .to_request();
+        let body: Value = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(body["data"].as_array().unwrap().len(), 1);
+        assert_eq!(deleted(OPS_DEPT_ID).await, Some(false));
+
+        // 父级部门已删除时需要先恢复父级部门，存在已删除的子部门时不能彻底删除父级部门
+        for id in [OPS_DEPT_ID, ENABLED_DEPT_ID] {
+            let req = TestRequest::delete()
+                .uri(&format!("/dept/delete/{id}"))
+                .to_request();
+            let body: Value = test::call_and_read_body_json(&app, req).await;
+            assert_eq!(body["code"], 1);
+        }
+        let req = TestRequest::post()
+            .uri(&format!("/dept/restore/{OPS_DEPT_ID}"))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let req = TestRequest::delete()
+            .uri(&format!("/dept/purge/{ENABLED_DEPT_ID}"))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+
+        // 彻底删除后无法恢复
+        for id in [OPS_DEPT_ID, ENABLED_DEPT_ID] {
+            let req = TestRequest::delete()
+                .uri(&format!("/dept/purge/{id}"))
+                .to_request();
+            let body: Value = test::call_and_read_body_json(&app, req).await;
+            assert_eq!(body["code"], 1);
+            assert_eq!(deleted(id).await, None);
+        }
+        let req = TestRequest::post()
+            .uri(&format!("/dept/restore/{OPS_DEPT_ID}"))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    /// 测试按部门状态策略修改部门状态
+    #[actix_web::test]
+    async fn test_edit_dept_status() {
+        const HEAD_OFFICE_ID: &str = "6bcbfa6a71fa454d8363b885ccbcb30a";
+        const RD_DEPT_ID: &str = "88921a7b70a04ea2b7d1db1651ffe666";
+        const OPS_DEPT_ID: &str = "ebd8118fe94247809abab8c72579cf7d";
+
+        // 严格策略：停用时级联停用下级部门，上级部门已停用时拒绝启用
+        let repository = Arc::new(DeptRepositoryMemoryImpl::with_demo_data()) as Arc<dyn DeptRepository>;
+        let dept_service = Box::new(DeptServiceImpl::new(repository.clone()).with_status_policy(DeptStatusPolicy::Strict)) as Box<dyn DeptService + Send + Sync>;
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(dept_service))
+                .configure(dept_controller::config),
+        )
+        .await;
+        let status = async |id: &str| {
+            repository
+                .select_by_primary_key(id)
+                .await
+                .unwrap()
+                .and_then(|dept| dept.status)
+                .unwrap()
+        };
+
+        // `运维` 的上级部门 `NB PLUS 科技`、`XXX分公司` 均已启用，可以启用
+        let req = TestRequest::put()
+            .uri(&format!("/dept/editStatus/{OPS_DEPT_ID}/1"))
+            .to_request();
+        let body: Value = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(body["code"], 1);
+        assert_eq!(body["data"], 1);
+        assert_eq!(status(OPS_DEPT_ID).await, 1);
+
+        // 停用 `XXX总公司`，下级部门中只有 `研发部` 尚未停用，共更新2行
+        let req = TestRequest::put()
+            .uri(&format!("/dept/editStatus/{HEAD_OFFICE_ID}/0"))
+            .to_request();
+        let body: Value = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(body["code"], 1);
+        assert_eq!(body["data"], 2);
+        assert_eq!(status(RD_DEPT_ID).await, 0);
+
+        // `市场部` 的上级部门 `XXX总公司` 已停用，不能启用
+        let req = TestRequest::put()
+            .uri(&format!("/dept/editStatus/{DISABLED_DEPT_ID}/1"))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body["code"], -5);
+        assert_eq!(status(DISABLED_DEPT_ID).await, 0);
+
+        // 重新启用 `XXX总公司`，下级部门不会随之启用
+        let req = TestRequest::put()
+            .uri(&format!("/dept/editStatus/{HEAD_OFFICE_ID}/1"))
+            .to_request();
+        let body: Value = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(body["data"], 1);
+        assert_eq!(status(RD_DEPT_ID).await, 0);
+
+        // 默认策略只修改部门本身
+        let repository = Arc::new(DeptRepositoryMemoryImpl::with_demo_data()) as Arc<dyn DeptRepository>;
+        let dept_service = Box::new(DeptServiceImpl::new(repository.clone())) as Box<dyn DeptService + Send + Sync>;
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(dept_service))
+                .configure(dept_controller::config),
+        )
+        .await;
+        let req = TestRequest::put()
+            .uri(&format!("/dept/editStatus/{HEAD_OFFICE_ID}/0"))
+            .to_request();
+        let body: Value = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(body["data"], 1);
+        let dept = repository
+            .select_by_primary_key(RD_DEPT_ID)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(dept.status, Some(1));
+
+        // 上级部门已停用时同样可以启用
+        let req = TestRequest::put()
+            .uri(&format!("/dept/editStatus/{DISABLED_DEPT_ID}/1"))
+            .to_request();
+        let body: Value = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(body["code"], 1);
+        assert_eq!(body["data"], 1);
+    }
 }
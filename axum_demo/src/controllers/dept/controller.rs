@@ -5,10 +5,14 @@
 //! # 主要功能
 //!
 //! - 查询部门列表: [list_depts]
+//! - 分页查询部门列表: [page_depts]
 //! - 获取部门树: [get_dept_tree]
 //! - 添加部门: [add_dept]
 //! - 编辑部门: [edit_dept]
 //! - 删除部门: [delete_dept]
+//! - 恢复部门: [restore_dept]
+//! - 彻底删除部门: [purge_dept]
+//! - 移动部门: [move_dept]
 //! - 修改部门状态: [edit_dept_status]
 //!
 
@@ -17,12 +21,14 @@ use axum::{
     extract::{Path, State},
     routing::{delete, post, put},
 };
-use common_wrapper::{ListWrapper, ResponseWrapper};
+use common_validation::{CreateGroup, UpdateGroup, ValidatedJson};
+use common_wrapper::i18n::Locale;
+use common_wrapper::{ListWrapper, PageWrapper, ResponseWrapper, SingleWrapper};
 use std::sync::Arc;
 
-use crate::params::dept_param::DeptParam;
-use crate::services::dept::dept_service::DeptService;
-use crate::views::{dept_tree::DeptTree, dept_vo::DeptVO};
+use dept_core::params::dept_param::DeptParam;
+use dept_core::services::dept::dept_service::DeptService;
+use dept_core::views::{dept_tree::DeptTree, dept_vo::DeptVO};
 
 /// 查询部门列表
 ///
@@ -32,13 +38,30 @@ use crate::views::{dept_tree::DeptTree, dept_vo::DeptVO};
 ///
 /// - `dept_param`: 部门查询参数，类型: [Json]<[DeptParam]>，通过请求体传入
 /// - `dept_service`: 部门服务实例，通过Axum状态管理提供
+/// - `locale`: 响应消息的语言，由请求头 `Accept-Language` 解析
 ///
 /// # 返回值
 ///
-/// 返回JSON格式的部门列表结果，类型: [Json]<[ListWrapper]<[DeptVO]>>，参见: [ListWrapper]<[DeptVO]>
-pub async fn list_depts(State(dept_service): State<Arc<dyn DeptService + Send + Sync>>, Json(dept_param): Json<DeptParam>) -> Json<ListWrapper<DeptVO>> {
-    let result: ListWrapper<DeptVO> = dept_service.select_dept_vo_list(dept_param).await;
-    Json(result)
+/// 返回JSON格式的部门列表结果，HTTP状态码由响应码决定，类型: [ListWrapper]<[DeptVO]>，参见: [ListWrapper]<[DeptVO]>
+pub async fn list_depts(State(dept_service): State<Arc<dyn DeptService + Send + Sync>>, locale: Locale, Json(dept_param): Json<DeptParam>) -> ListWrapper<DeptVO> {
+    dept_service.select_dept_vo_list(dept_param, locale).await
+}
+
+/// 分页查询部门列表
+///
+/// 根据参数中的 `pageNum`、`pageSize` 在数据库端分页查询部门信息，返回当前页的部门VO列表及分页信息
+///
+/// # 参数
+///
+/// - `dept_param`: 部门查询参数，类型: [Json]<[DeptParam]>，通过请求体传入
+/// - `dept_service`: 部门服务实例，通过Axum状态管理提供
+/// - `locale`: 响应消息的语言，由请求头 `Accept-Language` 解析
+///
+/// # 返回值
+///
+/// 返回JSON格式的部门分页结果，HTTP状态码由响应码决定，类型: [PageWrapper]<[DeptVO]>，参见: [PageWrapper]<[DeptVO]>
+pub async fn page_depts(State(dept_service): State<Arc<dyn DeptService + Send + Sync>>, locale: Locale, Json(dept_param): Json<DeptParam>) -> PageWrapper<DeptVO> {
+    dept_service.select_dept_vo_page(dept_param, locale).await
 }
 
 /// 获取部门树
@@ -49,82 +72,134 @@ pub async fn list_depts(State(dept_service): State<Arc<dyn DeptService + Send +
 ///
 /// - `dept_param`: 部门查询参数，类型: [Json]<[DeptParam]>，通过请求体传入
 /// - `dept_service`: 部门服务实例，通过Axum状态管理提供
+/// - `locale`: 响应消息的语言，由请求头 `Accept-Language` 解析
 ///
 /// # 返回值
 ///
-/// 返回JSON格式的部门树结果，类型: [Json]<[ListWrapper]<[DeptTree]>>
-pub async fn get_dept_tree(State(dept_service): State<Arc<dyn DeptService + Send + Sync>>, Json(dept_param): Json<DeptParam>) -> Json<ListWrapper<DeptTree>> {
-    let result = dept_service.get_dept_tree(dept_param).await;
-    Json(result)
+/// 返回JSON格式的部门树结果，HTTP状态码由响应码决定，类型: [ListWrapper]<[DeptTree]>
+pub async fn get_dept_tree(State(dept_service): State<Arc<dyn DeptService + Send + Sync>>, locale: Locale, Json(dept_param): Json<DeptParam>) -> ListWrapper<DeptTree> {
+    dept_service.get_dept_tree(dept_param, locale).await
 }
 
 /// 添加部门
 ///
-/// 新增一个部门信息
+/// 新增一个部门信息，请求体按 [CreateGroup] 分组验证，验证失败时直接返回参数错误
 ///
 /// # 参数
 ///
-/// - `dept_param`: 部门参数，类型: [Json]<[DeptParam]>，通过请求体传入
+/// - `dept_param`: 部门参数，类型: [ValidatedJson]<[DeptParam], [CreateGroup]>，通过请求体传入
 /// - `dept_service`: 部门服务实例，通过Axum状态管理提供
+/// - `locale`: 响应消息的语言，由请求头 `Accept-Language` 解析
 ///
 /// # 返回值
 ///
-/// 返回操作结果，类型: [Json]<[ResponseWrapper]>
-pub async fn add_dept(State(dept_service): State<Arc<dyn DeptService + Send + Sync>>, Json(dept_param): Json<DeptParam>) -> Json<ResponseWrapper> {
-    let result = dept_service.add_dept(dept_param).await;
-    Json(result)
+/// 返回操作结果，HTTP状态码由响应码决定，类型: [ResponseWrapper]
+pub async fn add_dept(State(dept_service): State<Arc<dyn DeptService + Send + Sync>>, locale: Locale, ValidatedJson(dept_param, _): ValidatedJson<DeptParam, CreateGroup>) -> ResponseWrapper {
+    dept_service.add_dept(dept_param, locale).await
 }
 
 /// 编辑部门
 ///
-/// 修改部门信息
+/// 修改部门信息，请求体按 [UpdateGroup] 分组验证，验证失败时直接返回参数错误
 ///
 /// # 参数
 ///
-/// - `dept_param`: 部门参数，类型: [Json]<[DeptParam]>，通过请求体传入
+/// - `dept_param`: 部门参数，类型: [ValidatedJson]<[DeptParam], [UpdateGroup]>，通过请求体传入
 /// - `dept_service`: 部门服务实例，通过Axum状态管理提供
+/// - `locale`: 响应消息的语言，由请求头 `Accept-Language` 解析
 ///
 /// # 返回值
 ///
-/// 返回操作结果，类型: [Json]<[ResponseWrapper]>
-pub async fn edit_dept(State(dept_service): State<Arc<dyn DeptService + Send + Sync>>, Json(dept_param): Json<DeptParam>) -> Json<ResponseWrapper> {
-    let result = dept_service.edit_dept(dept_param).await;
-    Json(result)
+/// 返回操作结果，HTTP状态码由响应码决定，类型: [ResponseWrapper]
+pub async fn edit_dept(State(dept_service): State<Arc<dyn DeptService + Send + Sync>>, locale: Locale, ValidatedJson(dept_param, _): ValidatedJson<DeptParam, UpdateGroup>) -> ResponseWrapper {
+    dept_service.edit_dept(dept_param, locale).await
 }
 
 /// 删除部门
 ///
-/// 根据部门ID删除指定部门
+/// 根据部门ID逻辑删除指定部门，删除后可以恢复
+///
+/// # 参数
+///
+/// - `dept_id`: 部门ID，通过URL路径传入
+/// - `dept_service`: 部门服务实例，通过Axum状态管理提供
+/// - `locale`: 响应消息的语言，由请求头 `Accept-Language` 解析
+///
+/// # 返回值
+///
+/// 返回操作结果，HTTP状态码由响应码决定，类型: [ResponseWrapper]
+pub async fn delete_dept(State(dept_service): State<Arc<dyn DeptService + Send + Sync>>, locale: Locale, Path(dept_id): Path<String>) -> ResponseWrapper {
+    dept_service.delete_dept(&dept_id, locale).await
+}
+
+/// 恢复部门
+///
+/// 根据部门ID恢复已删除的部门，原父级部门需要存在且未停用
 ///
 /// # 参数
 ///
 /// - `dept_id`: 部门ID，通过URL路径传入
 /// - `dept_service`: 部门服务实例，通过Axum状态管理提供
+/// - `locale`: 响应消息的语言，由请求头 `Accept-Language` 解析
+///
+/// # 返回值
+///
+/// 返回操作结果，HTTP状态码由响应码决定，类型: [ResponseWrapper]
+pub async fn restore_dept(State(dept_service): State<Arc<dyn DeptService + Send + Sync>>, locale: Locale, Path(dept_id): Path<String>) -> ResponseWrapper {
+    dept_service.restore_dept(&dept_id, locale).await
+}
+
+/// 彻底删除部门
+///
+/// 根据部门ID彻底删除已删除的部门，删除后无法恢复
+///
+/// # 参数
+///
+/// - `dept_id`: 部门ID，通过URL路径传入
+/// - `dept_service`: 部门服务实例，通过Axum状态管理提供
+/// - `locale`: 响应消息的语言，由请求头 `Accept-Language` 解析
+///
+/// # 返回值
+///
+/// 返回操作结果，HTTP状态码由响应码决定，类型: [ResponseWrapper]
+pub async fn purge_dept(State(dept_service): State<Arc<dyn DeptService + Send + Sync>>, locale: Locale, Path(dept_id): Path<String>) -> ResponseWrapper {
+    dept_service.purge_dept(&dept_id, locale).await
+}
+
+/// 移动部门
+///
+/// 将部门连同其下级部门移动到新的父级部门下，新的父级部门不能为部门本身或其下级部门
+///
+/// # 参数
+///
+/// - `id`: 部门ID，通过URL路径传入
+/// - `new_parent_id`: 新的父级部门ID，通过URL路径传入
+/// - `dept_service`: 部门服务实例，通过Axum状态管理提供
+/// - `locale`: 响应消息的语言，由请求头 `Accept-Language` 解析
 ///
 /// # 返回值
 ///
-/// 返回操作结果，类型: [Json]<[ResponseWrapper]>
-pub async fn delete_dept(State(dept_service): State<Arc<dyn DeptService + Send + Sync>>, Path(dept_id): Path<String>) -> Json<ResponseWrapper> {
-    let result = dept_service.delete_dept(&dept_id).await;
-    Json(result)
+/// 返回操作结果，HTTP状态码由响应码决定，类型: [ResponseWrapper]
+pub async fn move_dept(State(dept_service): State<Arc<dyn DeptService + Send + Sync>>, locale: Locale, Path((id, new_parent_id)): Path<(String, String)>) -> ResponseWrapper {
+    dept_service.move_dept(&id, &new_parent_id, locale).await
 }
 
 /// 修改部门状态
 ///
-/// 根据部门ID修改部门状态
+/// 根据部门ID修改部门状态，按部门状态策略停用下级部门、检查上级部门
 ///
 /// # 参数
 ///
 /// - `id`: 部门ID，通过URL路径传入
 /// - `status`: 部门状态，通过URL路径传入
 /// - `dept_service`: 部门服务实例，通过Axum状态管理提供
+/// - `locale`: 响应消息的语言，由请求头 `Accept-Language` 解析
 ///
 /// # 返回值
 ///
-/// 返回操作结果，类型: [Json]<[ResponseWrapper]>
-pub async fn edit_dept_status(State(dept_service): State<Arc<dyn DeptService + Send + Sync>>, Path((id, status)): Path<(String, i32)>) -> Json<ResponseWrapper> {
-    let result = dept_service.edit_dept_status(&id, status).await;
-    Json(result)
+/// 返回部门本身及下级部门实际更新的行数，HTTP状态码由响应码决定，类型: [SingleWrapper]<[u64]>
+pub async fn edit_dept_status(State(dept_service): State<Arc<dyn DeptService + Send + Sync>>, locale: Locale, Path((id, status)): Path<(String, i32)>) -> SingleWrapper<u64> {
+    dept_service.edit_dept_status(&id, status, locale).await
 }
 
 /// 注册部门相关路由
@@ -137,10 +212,14 @@ pub async fn edit_dept_status(State(dept_service): State<Arc<dyn DeptService + S
 pub fn routes(dept_service: Arc<dyn DeptService + Send + Sync>) -> Router {
     Router::new()
         .route("/list", post(list_depts))
+        .route("/page", post(page_depts))
         .route("/getDeptTree", post(get_dept_tree))
         .route("/add", post(add_dept))
         .route("/edit", put(edit_dept))
         .route("/delete/:dept_id", delete(delete_dept))
+        .route("/restore/:dept_id", post(restore_dept))
+        .route("/purge/:dept_id", delete(purge_dept))
+        .route("/move/:id/:new_parent_id", put(move_dept))
         .route("/editStatus/:id/:status", put(edit_dept_status))
         .with_state(dept_service)
 }
@@ -5,7 +5,7 @@
 //!
 //! # 模块组织
 //!
-//! - [dept] - 部门相关控制器，参见: [crate::services::dept]
+//! - [dept] - 部门相关控制器，参见: [dept_core::services::dept]
 //! - [index] - 首页相关控制器
 
 pub mod dept;
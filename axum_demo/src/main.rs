@@ -1,62 +1,55 @@
-mod config;
 mod controllers;
-mod models;
-mod params;
-mod repositories;
-mod services;
-mod views;
-
-// 只在使用SeaORM时才导入entities模块
-#[cfg(feature = "seaorm_impl")]
-mod entities;
-// 只在使用Diesel时才导入schema模块
-#[cfg(feature = "diesel_impl")]
-mod schema;
 
 use controllers::{dept::controller as dept_controller, index::controller as index_controller};
-use services::dept::{dept_service::DeptService, dept_service_impl::DeptServiceImpl};
+use dept_core::services::dept::{dept_service::DeptService, dept_service_impl::DeptServiceImpl, dept_status_policy::DeptStatusPolicy};
 
 // 为每种实现定义类型别名，简化条件编译代码
 #[cfg(feature = "sqlx_impl")]
-use repositories::dept::sqlx_impl::DeptRepositorySqlxImpl as DeptRepositoryImpl;
+use dept_core::repositories::dept::sqlx_impl::DeptRepositorySqlxImpl as DeptRepositoryImpl;
 
 #[cfg(feature = "diesel_impl")]
-use repositories::dept::diesel_impl::DeptRepositoryDieselImpl as DeptRepositoryImpl;
+use dept_core::repositories::dept::diesel_impl::DeptRepositoryDieselImpl as DeptRepositoryImpl;
 
 #[cfg(feature = "seaorm_impl")]
-use repositories::dept::seaorm_impl::DeptRepositorySeaormImpl as DeptRepositoryImpl;
+use dept_core::repositories::dept::seaorm_impl::DeptRepositorySeaormImpl as DeptRepositoryImpl;
+
+#[cfg(feature = "memory_impl")]
+use dept_core::repositories::dept::memory_impl::DeptRepositoryMemoryImpl as DeptRepositoryImpl;
 
 // 统一导入trait
 use axum::Router;
-use repositories::dept::dept_repository::DeptRepository;
+use dept_core::repositories::dept::dept_repository::DeptRepository;
 use std::sync::Arc;
 
 #[tokio::main]
 async fn main() {
     // 根据启用的特性初始化对应的数据访问层实现
     #[cfg(feature = "diesel_impl")]
-    let repository: Arc<dyn DeptRepository> = { Arc::new(DeptRepositoryImpl::new().expect("无法创建Diesel数据库连接")) };
+    let repository = DeptRepositoryImpl::new().expect("无法创建Diesel数据库连接");
 
     #[cfg(feature = "seaorm_impl")]
-    let repository: Arc<dyn DeptRepository> = {
-        Arc::new(
-            DeptRepositoryImpl::new()
-                .await
-                .expect("无法创建SeaORM数据库连接"),
-        )
-    };
+    let repository = DeptRepositoryImpl::new()
+        .await
+        .expect("无法创建SeaORM数据库连接");
 
     #[cfg(feature = "sqlx_impl")]
-    let repository: Arc<dyn DeptRepository> = {
-        Arc::new(
-            DeptRepositoryImpl::new()
-                .await
-                .expect("无法创建SQLx数据库连接"),
-        )
-    };
-
-    // 初始化部门服务
-    let dept_service = Arc::new(DeptServiceImpl::new(repository)) as Arc<dyn DeptService + Send + Sync>;
+    let repository = DeptRepositoryImpl::new()
+        .await
+        .expect("无法创建SQLx数据库连接");
+
+    // 执行 migrate 子命令后直接退出，否则在启动服务前应用或校验数据库迁移
+    #[cfg(any(feature = "sqlx_impl", feature = "diesel_impl", feature = "seaorm_impl"))]
+    dept_core::migrations::prepare(&repository).await;
+
+    // 内存实现不需要数据库，启动时载入演示数据，进程退出后修改即丢失
+    #[cfg(feature = "memory_impl")]
+    let repository = DeptRepositoryImpl::with_demo_data();
+
+    let repository: Arc<dyn DeptRepository> = Arc::new(repository);
+
+    // 初始化部门服务，修改部门状态时的层级策略可通过环境变量 DEPT_STATUS_POLICY 设置，默认为 independent
+    let status_policy = DeptStatusPolicy::from_env().expect("无法解析部门状态策略");
+    let dept_service = Arc::new(DeptServiceImpl::new(repository).with_status_policy(status_policy)) as Arc<dyn DeptService + Send + Sync>;
 
     // 构建Axum应用
     let app = Router::new()
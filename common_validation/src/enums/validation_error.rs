@@ -2,26 +2,34 @@
 //!
 //! 定义验证过程中可能发生的各种错误类型
 
+use std::fmt;
+
+use common_wrapper::i18n::{Locale, Localize, MessageCatalog};
 use thiserror::Error;
 
+use crate::Number;
+
 // 错误类型定义：用于表单或数据校验时的各种错误情况
-#[derive(Debug, PartialEq, Eq, Hash, Error)]
+//
+// 错误信息（Display）为简体中文，通过 [Localize] 输出指定语言的错误信息，参数中的字段描述和自定义错误信息原样输出；
+// 需要多语言的自定义错误信息使用 [ValidationErrorEnum::Message]，字段描述参见 [ValidationErrorEnum::localize_field]
+#[derive(Debug, Clone, PartialEq, Error)]
 pub enum ValidationErrorEnum {
     /// 字段不能为空
     #[error("{0} 不能为空")]
     NotNull(String),
-    /// 字段长度不符合要求，第二个参数为具体说明
+    /// 字段长度不符合要求，第二个参数为长度限制
     #[error("{0} 长度不符合要求: {1}")]
-    Length(String, String),
+    Length(String, LengthLimit),
     /// 字段格式不正确
     #[error("{0} 格式不正确")]
     Format(String),
     /// 数值不能小于指定最小值
     #[error("{0} 值不能小于 {1}")]
-    NumberMin(String, i64),
+    NumberMin(String, Number),
     /// 数值不能大于指定最大值
     #[error("{0} 值不能大于 {1}")]
-    NumberMax(String, i64),
+    NumberMax(String, Number),
     /// 长度区间设置本身有误
     #[error("长度区间设置错误: {0}")]
     LengthRangeError(String),
@@ -55,4 +63,263 @@ pub enum ValidationErrorEnum {
     /// 必须为指定数字的倍数
     #[error("{0} 必须为 {1} 的倍数")]
     MultipleOf(String, i64),
+    /// 不符合正则表达式
+    #[error("{0} 不符合格式要求")]
+    Pattern(String),
+    /// 正则表达式本身有误，参数为具体说明
+    #[error("正则表达式设置错误: {0}")]
+    PatternError(String),
+    /// 不是有效的邮箱地址
+    #[error("{0} 不是有效的邮箱地址")]
+    Email(String),
+    /// 不是有效的手机号码
+    #[error("{0} 不是有效的手机号码")]
+    MobilePhone(String),
+    /// 不是有效的URL
+    #[error("{0} 不是有效的URL")]
+    Url(String),
+    /// 不是可选值之一，第二个参数为以 `、` 分隔的可选值
+    #[error("{0} 必须为以下值之一: {1}")]
+    OneOf(String, String),
+    /// 自定义验证函数未通过，参数为完整的错误信息
+    #[error("{0}")]
+    Custom(String),
+    /// 自定义验证函数未通过，第一个参数为消息目录，第二个参数为消息编码，按语言从消息目录中获取错误信息
+    #[error("{}", .0.format(Locale::ZhCn, .1, &[]))]
+    Message(&'static MessageCatalog, &'static str),
+}
+
+impl ValidationErrorEnum {
+    /// 获取错误编码，用于在消息目录中查找各语言的错误信息
+    ///
+    /// # 返回值
+    ///
+    /// 错误编码，如 `VALIDATION_NOT_NULL`、`VALIDATION_LENGTH`
+    pub const fn code(&self) -> &'static str {
+        match self {
+            ValidationErrorEnum::NotNull(_) => "VALIDATION_NOT_NULL",
+            ValidationErrorEnum::Length(..) => "VALIDATION_LENGTH",
+            ValidationErrorEnum::Format(_) => "VALIDATION_FORMAT",
+            ValidationErrorEnum::NumberMin(..) => "VALIDATION_NUMBER_MIN",
+            ValidationErrorEnum::NumberMax(..) => "VALIDATION_NUMBER_MAX",
+            ValidationErrorEnum::LengthRangeError(_) => "VALIDATION_LENGTH_RANGE_ERROR",
+            ValidationErrorEnum::DateTimeFormatNotSet => "VALIDATION_DATE_TIME_FORMAT_NOT_SET",
+            ValidationErrorEnum::NumberFormatError => "VALIDATION_NUMBER_FORMAT_ERROR",
+            ValidationErrorEnum::UnsupportedType(..) => "VALIDATION_UNSUPPORTED_TYPE",
+            ValidationErrorEnum::PositiveNumber(_) => "VALIDATION_POSITIVE_NUMBER",
+            ValidationErrorEnum::NonNegativeNumber(_) => "VALIDATION_NON_NEGATIVE_NUMBER",
+            ValidationErrorEnum::Integer(_) => "VALIDATION_INTEGER",
+            ValidationErrorEnum::DecimalScale(..) => "VALIDATION_DECIMAL_SCALE",
+            ValidationErrorEnum::OddNumber(_) => "VALIDATION_ODD_NUMBER",
+            ValidationErrorEnum::EvenNumber(_) => "VALIDATION_EVEN_NUMBER",
+            ValidationErrorEnum::MultipleOf(..) => "VALIDATION_MULTIPLE_OF",
+            ValidationErrorEnum::Pattern(_) => "VALIDATION_PATTERN",
+            ValidationErrorEnum::PatternError(_) => "VALIDATION_PATTERN_ERROR",
+            ValidationErrorEnum::Email(_) => "VALIDATION_EMAIL",
+            ValidationErrorEnum::MobilePhone(_) => "VALIDATION_MOBILE_PHONE",
+            ValidationErrorEnum::Url(_) => "VALIDATION_URL",
+            ValidationErrorEnum::OneOf(..) => "VALIDATION_ONE_OF",
+            ValidationErrorEnum::Custom(_) | ValidationErrorEnum::Message(..) => "VALIDATION_CUSTOM",
+        }
+    }
+
+    /// 获取未通过的验证规则名称，与派生宏的验证属性名称一致
+    ///
+    /// # 返回值
+    ///
+    /// 验证规则名称，如 `not_null`、`length`、`min`
+    pub const fn rule(&self) -> &'static str {
+        match self {
+            ValidationErrorEnum::NotNull(_) => "not_null",
+            ValidationErrorEnum::Length(..) => "length",
+            ValidationErrorEnum::Format(_) => "date_format",
+            ValidationErrorEnum::NumberMin(..) => "min",
+            ValidationErrorEnum::NumberMax(..) => "max",
+            ValidationErrorEnum::LengthRangeError(_) => "length_range",
+            ValidationErrorEnum::DateTimeFormatNotSet => "date_format",
+            ValidationErrorEnum::NumberFormatError => "number_format",
+            ValidationErrorEnum::UnsupportedType(..) => "unsupported_type",
+            ValidationErrorEnum::PositiveNumber(_) => "positive_number",
+            ValidationErrorEnum::NonNegativeNumber(_) => "non_negative_number",
+            ValidationErrorEnum::Integer(_) => "integer",
+            ValidationErrorEnum::DecimalScale(..) => "decimal_scale",
+            ValidationErrorEnum::OddNumber(_) => "odd_number",
+            ValidationErrorEnum::EvenNumber(_) => "even_number",
+            ValidationErrorEnum::MultipleOf(..) => "multiple_of",
+            ValidationErrorEnum::Pattern(_) | ValidationErrorEnum::PatternError(_) => "pattern",
+            ValidationErrorEnum::Email(_) => "email",
+            ValidationErrorEnum::MobilePhone(_) => "mobile_phone",
+            ValidationErrorEnum::Url(_) => "url",
+            ValidationErrorEnum::OneOf(..) => "one_of",
+            ValidationErrorEnum::Custom(_) | ValidationErrorEnum::Message(..) => "custom",
+        }
+    }
+
+    /// 获取字段的错误信息
+    ///
+    /// 字段描述（`desc`）按简体中文编写，简体中文以外的语言用字段路径代替字段描述，
+    /// 避免输出中英混杂的错误信息；字段路径为空（结构体级别的错误）时与 [Localize::localize] 相同
+    ///
+    /// # 参数
+    ///
+    /// * `locale` - 语言
+    /// * `field` - 字段路径，如 `name`、`address.city`
+    ///
+    /// # 返回值
+    ///
+    /// 指定语言的错误信息
+    pub fn localize_field(&self, locale: Locale, field: &str) -> String {
+        if locale == Locale::ZhCn || field.is_empty() {
+            return self.localize(locale);
+        }
+        let desc = field.to_string();
+        let error = match self.clone() {
+            ValidationErrorEnum::NotNull(_) => ValidationErrorEnum::NotNull(desc),
+            ValidationErrorEnum::Length(_, limit) => ValidationErrorEnum::Length(desc, limit),
+            ValidationErrorEnum::Format(_) => ValidationErrorEnum::Format(desc),
+            ValidationErrorEnum::NumberMin(_, value) => ValidationErrorEnum::NumberMin(desc, value),
+            ValidationErrorEnum::NumberMax(_, value) => ValidationErrorEnum::NumberMax(desc, value),
+            ValidationErrorEnum::PositiveNumber(_) => ValidationErrorEnum::PositiveNumber(desc),
+            ValidationErrorEnum::NonNegativeNumber(_) => ValidationErrorEnum::NonNegativeNumber(desc),
+            ValidationErrorEnum::Integer(_) => ValidationErrorEnum::Integer(desc),
+            ValidationErrorEnum::DecimalScale(_, scale) => ValidationErrorEnum::DecimalScale(desc, scale),
+            ValidationErrorEnum::OddNumber(_) => ValidationErrorEnum::OddNumber(desc),
+            ValidationErrorEnum::EvenNumber(_) => ValidationErrorEnum::EvenNumber(desc),
+            ValidationErrorEnum::MultipleOf(_, value) => ValidationErrorEnum::MultipleOf(desc, value),
+            ValidationErrorEnum::Pattern(_) => ValidationErrorEnum::Pattern(desc),
+            ValidationErrorEnum::Email(_) => ValidationErrorEnum::Email(desc),
+            ValidationErrorEnum::MobilePhone(_) => ValidationErrorEnum::MobilePhone(desc),
+            ValidationErrorEnum::Url(_) => ValidationErrorEnum::Url(desc),
+            ValidationErrorEnum::OneOf(_, values) => ValidationErrorEnum::OneOf(desc, values),
+            // 参数不是字段描述的错误原样输出
+            error => error,
+        };
+        error.localize(locale)
+    }
+}
+
+/// 按错误编码输出指定语言的错误信息，简体中文与错误信息（Display）相同
+impl Localize for ValidationErrorEnum {
+    fn localize(&self, locale: Locale) -> String {
+        let code = self.code();
+        match self {
+            ValidationErrorEnum::DateTimeFormatNotSet | ValidationErrorEnum::NumberFormatError => VALIDATION_MESSAGES.format(locale, code, &[]),
+            ValidationErrorEnum::Length(desc, limit) => VALIDATION_MESSAGES.format(locale, code, &[desc, &limit.localize(locale)]),
+            ValidationErrorEnum::NumberMin(desc, value) | ValidationErrorEnum::NumberMax(desc, value) => VALIDATION_MESSAGES.format(locale, code, &[desc, value]),
+            ValidationErrorEnum::DecimalScale(desc, scale) => VALIDATION_MESSAGES.format(locale, code, &[desc, scale]),
+            ValidationErrorEnum::MultipleOf(desc, value) => VALIDATION_MESSAGES.format(locale, code, &[desc, value]),
+            ValidationErrorEnum::UnsupportedType(type_name, rule) => VALIDATION_MESSAGES.format(locale, code, &[type_name, rule]),
+            ValidationErrorEnum::OneOf(desc, values) => VALIDATION_MESSAGES.format(locale, code, &[desc, values]),
+            ValidationErrorEnum::NotNull(arg)
+            | ValidationErrorEnum::Format(arg)
+            | ValidationErrorEnum::LengthRangeError(arg)
+            | ValidationErrorEnum::PositiveNumber(arg)
+            | ValidationErrorEnum::NonNegativeNumber(arg)
+            | ValidationErrorEnum::Integer(arg)
+            | ValidationErrorEnum::OddNumber(arg)
+            | ValidationErrorEnum::EvenNumber(arg)
+            | ValidationErrorEnum::Pattern(arg)
+            | ValidationErrorEnum::PatternError(arg)
+            | ValidationErrorEnum::Email(arg)
+            | ValidationErrorEnum::MobilePhone(arg)
+            | ValidationErrorEnum::Url(arg)
+            | ValidationErrorEnum::Custom(arg) => VALIDATION_MESSAGES.format(locale, code, &[arg]),
+            ValidationErrorEnum::Message(catalog, code) => catalog.format(locale, code, &[]),
+        }
+    }
 }
+
+/// 长度限制，字符串按字符数计算，集合按元素个数计算
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LengthLimit {
+    /// 必须为指定的字符数
+    Chars(usize),
+    /// 字符数必须在指定区间内
+    CharsRange(usize, usize),
+    /// 必须为指定的元素个数
+    Items(usize),
+    /// 元素个数必须在指定区间内
+    ItemsRange(usize, usize),
+}
+
+impl fmt::Display for LengthLimit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.localize(Locale::ZhCn))
+    }
+}
+
+/// 输出指定语言的长度限制说明
+impl Localize for LengthLimit {
+    fn localize(&self, locale: Locale) -> String {
+        match self {
+            LengthLimit::Chars(len) => VALIDATION_MESSAGES.format(locale, "LENGTH_CHARS", &[len]),
+            LengthLimit::CharsRange(min, max) => VALIDATION_MESSAGES.format(locale, "LENGTH_CHARS_RANGE", &[min, max]),
+            LengthLimit::Items(len) => VALIDATION_MESSAGES.format(locale, "LENGTH_ITEMS", &[len]),
+            LengthLimit::ItemsRange(min, max) => VALIDATION_MESSAGES.format(locale, "LENGTH_ITEMS_RANGE", &[min, max]),
+        }
+    }
+}
+
+/// 验证错误的消息，简体中文与 `#[error]` 中的错误信息保持一致
+pub(crate) static VALIDATION_MESSAGES: MessageCatalog = MessageCatalog::new(
+    &[
+        ("VALIDATION_NOT_NULL", "{0} 不能为空"),
+        ("VALIDATION_LENGTH", "{0} 长度不符合要求: {1}"),
+        ("VALIDATION_FORMAT", "{0} 格式不正确"),
+        ("VALIDATION_NUMBER_MIN", "{0} 值不能小于 {1}"),
+        ("VALIDATION_NUMBER_MAX", "{0} 值不能大于 {1}"),
+        ("VALIDATION_LENGTH_RANGE_ERROR", "长度区间设置错误: {0}"),
+        ("VALIDATION_DATE_TIME_FORMAT_NOT_SET", "日期时间格式未设置"),
+        ("VALIDATION_NUMBER_FORMAT_ERROR", "数字格式错误"),
+        ("VALIDATION_UNSUPPORTED_TYPE", "类型 {0} 不支持验证规则 {1}"),
+        ("VALIDATION_POSITIVE_NUMBER", "{0} 必须为正数"),
+        ("VALIDATION_NON_NEGATIVE_NUMBER", "{0} 必须为非负数"),
+        ("VALIDATION_INTEGER", "{0} 必须为整数"),
+        ("VALIDATION_DECIMAL_SCALE", "{0} 小数位数不能超过 {1} 位"),
+        ("VALIDATION_ODD_NUMBER", "{0} 必须为奇数"),
+        ("VALIDATION_EVEN_NUMBER", "{0} 必须为偶数"),
+        ("VALIDATION_MULTIPLE_OF", "{0} 必须为 {1} 的倍数"),
+        ("VALIDATION_PATTERN", "{0} 不符合格式要求"),
+        ("VALIDATION_PATTERN_ERROR", "正则表达式设置错误: {0}"),
+        ("VALIDATION_EMAIL", "{0} 不是有效的邮箱地址"),
+        ("VALIDATION_MOBILE_PHONE", "{0} 不是有效的手机号码"),
+        ("VALIDATION_URL", "{0} 不是有效的URL"),
+        ("VALIDATION_ONE_OF", "{0} 必须为以下值之一: {1}"),
+        ("VALIDATION_CUSTOM", "{0}"),
+        ("VALIDATION_BODY", "请求体格式错误: {0}"),
+        ("LENGTH_CHARS", "必须为 {0} 个字符"),
+        ("LENGTH_CHARS_RANGE", "必须在 {0}~{1} 个字符之间"),
+        ("LENGTH_ITEMS", "必须为 {0} 个元素"),
+        ("LENGTH_ITEMS_RANGE", "必须在 {0}~{1} 个元素之间"),
+    ],
+    &[
+        ("VALIDATION_NOT_NULL", "{0} must not be empty"),
+        ("VALIDATION_LENGTH", "{0} has an invalid length: {1}"),
+        ("VALIDATION_FORMAT", "{0} has an invalid format"),
+        ("VALIDATION_NUMBER_MIN", "{0} must not be less than {1}"),
+        ("VALIDATION_NUMBER_MAX", "{0} must not be greater than {1}"),
+        ("VALIDATION_LENGTH_RANGE_ERROR", "Invalid length range: {0}"),
+        ("VALIDATION_DATE_TIME_FORMAT_NOT_SET", "Date time format is not set"),
+        ("VALIDATION_NUMBER_FORMAT_ERROR", "Invalid number format"),
+        ("VALIDATION_UNSUPPORTED_TYPE", "Type {0} does not support validation rule {1}"),
+        ("VALIDATION_POSITIVE_NUMBER", "{0} must be a positive number"),
+        ("VALIDATION_NON_NEGATIVE_NUMBER", "{0} must be a non-negative number"),
+        ("VALIDATION_INTEGER", "{0} must be an integer"),
+        ("VALIDATION_DECIMAL_SCALE", "{0} must not have more than {1} decimal places"),
+        ("VALIDATION_ODD_NUMBER", "{0} must be an odd number"),
+        ("VALIDATION_EVEN_NUMBER", "{0} must be an even number"),
+        ("VALIDATION_MULTIPLE_OF", "{0} must be a multiple of {1}"),
+        ("VALIDATION_PATTERN", "{0} does not match the required pattern"),
+        ("VALIDATION_PATTERN_ERROR", "Invalid regular expression: {0}"),
+        ("VALIDATION_EMAIL", "{0} is not a valid email address"),
+        ("VALIDATION_MOBILE_PHONE", "{0} is not a valid mobile phone number"),
+        ("VALIDATION_URL", "{0} is not a valid URL"),
+        ("VALIDATION_ONE_OF", "{0} must be one of: {1}"),
+        ("VALIDATION_CUSTOM", "{0}"),
+        ("VALIDATION_BODY", "Invalid request body: {0}"),
+        ("LENGTH_CHARS", "must be {0} characters"),
+        ("LENGTH_CHARS_RANGE", "must be between {0} and {1} characters"),
+        ("LENGTH_ITEMS", "must have {0} items"),
+        ("LENGTH_ITEMS_RANGE", "must have between {0} and {1} items"),
+    ],
+);
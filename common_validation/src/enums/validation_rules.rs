@@ -2,10 +2,10 @@
 //!
 //! 定义支持的各种验证规则类型
 
-use crate::DateTimeFormatEnum;
+use crate::{DateTimeFormatEnum, Number};
 
 // 验证规则枚举
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ValidationRulesEnum {
     /// 不允许为空
     NotNull,
@@ -39,13 +39,13 @@ pub enum ValidationRulesEnum {
     /// 数字最小值，min：最小值
     ///
     /// # 参数
-    /// * `i64` - 最小数值
-    NumberMin(i64),
+    /// * [Number] - 最小数值，可以为整数或浮点数
+    NumberMin(Number),
     /// 数字最大值，max：最大值
     ///
     /// # 参数
-    /// * `i64` - 最大数值
-    NumberMax(i64),
+    /// * [Number] - 最大数值，可以为整数或浮点数
+    NumberMax(Number),
     /// 正数验证
     PositiveNumber,
     /// 非负数验证
@@ -66,6 +66,55 @@ pub enum ValidationRulesEnum {
     /// # 参数
     /// * `i64` - 倍数
     MultipleOf(i64),
+    /// 正则表达式验证，值不为空时必须完整匹配正则表达式
+    ///
+    /// # 参数
+    /// * `&'static str` - 正则表达式，如 `^[A-Z]{2}\d{4}$`
+    Pattern(&'static str),
+    /// 邮箱验证，值不为空时必须为有效的邮箱地址
+    Email,
+    /// 手机号验证，值不为空时必须为中国大陆11位手机号码
+    MobilePhone,
+    /// URL验证，值不为空时必须为 http、https 或 ftp 协议的URL
+    Url,
+    /// 枚举值验证，值不为空时必须为可选值之一
+    ///
+    /// # 参数
+    /// * `&'static [&'static str]` - 可选值列表，数值按字符串形式比较，如 `&["0", "1"]`
+    OneOf(&'static [&'static str]),
     /// 用于判断是否递归校验自定义类型，使用此枚举值表示需要递归校验
     Nested,
 }
+
+impl ValidationRulesEnum {
+    /// 获取验证规则名称，与派生宏的验证属性名称一致
+    ///
+    /// # 返回值
+    ///
+    /// 验证规则名称，如 `not_null`、`length_range`、`min`
+    pub const fn name(&self) -> &'static str {
+        match self {
+            ValidationRulesEnum::NotNull => "not_null",
+            ValidationRulesEnum::Length(_) => "length",
+            ValidationRulesEnum::LengthRange(..) => "length_range",
+            ValidationRulesEnum::ExistLength(_) => "exist_length",
+            ValidationRulesEnum::ExistLengthRange(..) => "exist_length_range",
+            ValidationRulesEnum::DateFormat(_) => "date_format",
+            ValidationRulesEnum::NumberMin(_) => "min",
+            ValidationRulesEnum::NumberMax(_) => "max",
+            ValidationRulesEnum::PositiveNumber => "positive_number",
+            ValidationRulesEnum::NonNegativeNumber => "non_negative_number",
+            ValidationRulesEnum::Integer => "integer",
+            ValidationRulesEnum::DecimalScale(_) => "decimal_scale",
+            ValidationRulesEnum::OddNumber => "odd_number",
+            ValidationRulesEnum::EvenNumber => "even_number",
+            ValidationRulesEnum::MultipleOf(_) => "multiple_of",
+            ValidationRulesEnum::Pattern(_) => "pattern",
+            ValidationRulesEnum::Email => "email",
+            ValidationRulesEnum::MobilePhone => "mobile_phone",
+            ValidationRulesEnum::Url => "url",
+            ValidationRulesEnum::OneOf(_) => "one_of",
+            ValidationRulesEnum::Nested => "nested",
+        }
+    }
+}
@@ -0,0 +1,49 @@
+//! Actix Web 提取器实现模块
+//!
+//! 为 [ValidatedJson] 提供 Actix Web 框架的 FromRequest 实现，为 [ValidationRejection] 提供 ResponseError 实现
+//! 这个模块是可选的，只有在启用 actix_extractor 特性时才会编译
+
+use std::future::Future;
+use std::pin::Pin;
+
+use actix_web::dev::Payload;
+use actix_web::http::StatusCode;
+use actix_web::{FromRequest, HttpRequest, HttpResponse, ResponseError, web};
+use common_wrapper::i18n::Locale;
+use common_wrapper::{ResponseTrait, SingleWrapper};
+use serde::de::DeserializeOwned;
+
+use crate::extract::{ValidatedJson, ValidationRejection};
+use crate::{GroupFields, GroupValidatable, ValidationErrors, ValidationGroup};
+
+/// 为 ValidatedJson 实现 Actix Web FromRequest，先按 [web::Json] 解析请求体，再按分组验证
+impl<T, G> FromRequest for ValidatedJson<T, G>
+where
+    T: DeserializeOwned + GroupValidatable + GroupFields<G> + 'static,
+    G: ValidationGroup + 'static,
+{
+    type Error = ValidationRejection;
+    type Future = Pin<Box<dyn Future<Output = Result<Self, Self::Error>>>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let Ok(locale) = Locale::extract(req).into_inner();
+        let json = web::Json::<T>::from_request(req, payload);
+        Box::pin(async move {
+            let web::Json(value) = json
+                .await
+                .map_err(|e| ValidationRejection::Body(e.to_string(), locale))?;
+            ValidatedJson::validate(value, locale)
+        })
+    }
+}
+
+/// 为 ValidationRejection 实现 Actix Web ResponseError，响应体为 [SingleWrapper] 的JSON
+impl ResponseError for ValidationRejection {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::from_u16(SingleWrapper::<ValidationErrors>::from(self).http_status()).unwrap_or(StatusCode::BAD_REQUEST)
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).json(SingleWrapper::<ValidationErrors>::from(self))
+    }
+}
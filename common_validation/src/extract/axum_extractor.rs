@@ -0,0 +1,42 @@
+//! Axum 提取器实现模块
+//!
+//! 为 [ValidatedJson] 提供 Axum Web 框架的 FromRequest 实现，为 [ValidationRejection] 提供 IntoResponse 实现
+//! 这个模块是可选的，只有在启用 axum_extractor 特性时才会编译
+
+use axum::Json;
+use axum::async_trait;
+use axum::extract::{FromRequest, FromRequestParts, Request};
+use axum::response::{IntoResponse, Response};
+use common_wrapper::SingleWrapper;
+use common_wrapper::i18n::Locale;
+use serde::de::DeserializeOwned;
+
+use crate::extract::{ValidatedJson, ValidationRejection};
+use crate::{GroupFields, GroupValidatable, ValidationErrors, ValidationGroup};
+
+/// 为 ValidatedJson 实现 Axum FromRequest，先按 [Json] 解析请求体，再按分组验证
+#[async_trait]
+impl<T, G, S> FromRequest<S> for ValidatedJson<T, G>
+where
+    T: DeserializeOwned + GroupValidatable + GroupFields<G>,
+    G: ValidationGroup,
+    S: Send + Sync,
+{
+    type Rejection = ValidationRejection;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let (mut parts, body) = req.into_parts();
+        let Ok(locale) = Locale::from_request_parts(&mut parts, state).await;
+        let Json(value) = Json::<T>::from_request(Request::from_parts(parts, body), state)
+            .await
+            .map_err(|e| ValidationRejection::Body(e.body_text(), locale))?;
+        ValidatedJson::validate(value, locale)
+    }
+}
+
+/// 为 ValidationRejection 实现 Axum IntoResponse，响应体为 [SingleWrapper] 的JSON
+impl IntoResponse for ValidationRejection {
+    fn into_response(self) -> Response {
+        SingleWrapper::<ValidationErrors>::from(&self).into_response()
+    }
+}
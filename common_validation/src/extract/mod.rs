@@ -0,0 +1,103 @@
+//! # 分组验证提取器模块
+//!
+//! 为各Web框架提供 [ValidatedJson] 提取器：从请求体解析JSON后按指定分组验证，
+//! 解析或验证失败时在进入控制器之前拒绝请求，响应体为 [SingleWrapper]，响应码为 [WrapperErrEnum::InvalidParam]，
+//! 验证失败时数据部分为按字段路径汇总的全部验证错误 [ValidationErrors]
+//!
+//! 错误信息的语言由请求头 `Accept-Language` 决定，参见: [Locale::from_accept_language]
+//!
+//! ## 模块组成
+//!
+//! - [axum_extractor] - Axum框架的提取器实现（可选）
+//! - [actix_extractor] - Actix Web框架的提取器实现（可选）
+//! - [rocket_extractor] - Rocket框架的提取器实现（可选）
+
+#[cfg(feature = "actix_extractor")]
+pub mod actix_extractor;
+#[cfg(feature = "axum_extractor")]
+pub mod axum_extractor;
+#[cfg(feature = "rocket_extractor")]
+pub mod rocket_extractor;
+
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+use common_wrapper::SingleWrapper;
+use common_wrapper::enums::wrapper_err::WrapperErrEnum;
+use common_wrapper::i18n::Locale;
+use thiserror::Error;
+
+use crate::{GroupFields, GroupValidatable, VALIDATION_MESSAGES, ValidationErrors, ValidationGroup};
+
+/// 按分组验证的JSON请求体
+///
+/// 第一个字段为验证通过的请求体，第二个字段仅用于在类型上标记验证分组
+///
+/// # 示例
+///
+/// ```rust,ignore
+/// async fn add_dept(ValidatedJson(dept_param, _): ValidatedJson<DeptParam, CreateGroup>) -> ResponseWrapper {
+///     // dept_param 已按创建分组验证通过
+/// }
+/// ```
+pub struct ValidatedJson<T, G: ValidationGroup>(pub T, pub PhantomData<G>);
+
+impl<T, G: ValidationGroup> ValidatedJson<T, G> {
+    /// 获取验证通过的请求体
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: GroupValidatable + GroupFields<G>, G: ValidationGroup> ValidatedJson<T, G> {
+    /// 按分组验证请求体，收集分组内全部字段的验证错误，验证通过时返回提取器
+    ///
+    /// # 参数
+    ///
+    /// * `value` - 已解析的请求体
+    /// * `locale` - 错误信息的语言
+    ///
+    /// # 返回值
+    ///
+    /// 验证通过返回 [ValidatedJson]，否则返回 [ValidationRejection::Invalid]
+    pub fn validate(value: T, locale: Locale) -> Result<Self, ValidationRejection> {
+        value.validate_all_with_group::<G>().map_err(|mut errors| {
+            errors.localize(locale);
+            errors
+        })?;
+        Ok(ValidatedJson(value, PhantomData))
+    }
+}
+
+impl<T, G: ValidationGroup> Deref for ValidatedJson<T, G> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// 提取器拒绝请求的原因
+#[derive(Debug, Clone, Error)]
+pub enum ValidationRejection {
+    /// 请求体不是合法的JSON或与参数结构不匹配，参数为框架给出的具体说明及错误信息的语言
+    #[error("{}", VALIDATION_MESSAGES.format(*.1, "VALIDATION_BODY", &[.0]))]
+    Body(String, Locale),
+    /// 请求参数未通过分组验证，包含全部字段的验证错误
+    #[error(transparent)]
+    Invalid(#[from] ValidationErrors),
+}
+
+/// 将拒绝原因转换为响应，响应码统一为参数错误
+///
+/// 消息为全部错误信息，验证失败时数据部分为按字段路径汇总的验证错误，请求体格式错误时数据为空
+impl From<&ValidationRejection> for SingleWrapper<ValidationErrors> {
+    fn from(rejection: &ValidationRejection) -> Self {
+        let mut wrapper = SingleWrapper::new();
+        match rejection {
+            ValidationRejection::Body(..) => wrapper.set_error(WrapperErrEnum::InvalidParam, rejection.to_string()),
+            ValidationRejection::Invalid(errors) => wrapper.set_error_with_data(WrapperErrEnum::InvalidParam, rejection.to_string(), errors.clone()),
+        }
+        wrapper
+    }
+}
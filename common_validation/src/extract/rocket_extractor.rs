@@ -0,0 +1,76 @@
+//! Rocket 提取器实现模块
+//!
+//! 为 [ValidatedJson] 提供 Rocket Web 框架的 FromData 实现
+//! 这个模块是可选的，只有在启用 rocket_extractor 特性时才会编译
+//!
+//! Rocket 的数据守卫失败时由错误捕获器生成响应，拒绝原因会缓存在请求中，
+//! 需要通过 [catchers] 注册400捕获器，才能以 [SingleWrapper] 的JSON返回拒绝原因
+
+use common_wrapper::SingleWrapper;
+use common_wrapper::enums::wrapper_err::WrapperErrEnum;
+use common_wrapper::i18n::{Locale, Localize};
+use rocket::data::{self, Data, FromData};
+use rocket::http::Status;
+use rocket::outcome::Outcome;
+use rocket::serde::json::Json;
+use rocket::{Catcher, Request, catch, catchers};
+use serde::de::DeserializeOwned;
+
+use crate::extract::{ValidatedJson, ValidationRejection};
+use crate::{GroupFields, GroupValidatable, ValidationErrors, ValidationGroup};
+
+/// 缓存在请求中的拒绝原因，供400捕获器读取
+struct CachedRejection(Option<ValidationRejection>);
+
+/// 缓存拒绝原因，并以400状态码结束数据守卫
+fn reject<'r, T>(req: &'r Request<'_>, rejection: ValidationRejection) -> data::Outcome<'r, T, ValidationRejection> {
+    req.local_cache(|| CachedRejection(Some(rejection.clone())));
+    Outcome::Error((Status::BadRequest, rejection))
+}
+
+/// 为 ValidatedJson 实现 Rocket FromData，先按 [Json] 解析请求体，再按分组验证
+#[rocket::async_trait]
+impl<'r, T, G> FromData<'r> for ValidatedJson<T, G>
+where
+    T: DeserializeOwned + GroupValidatable + GroupFields<G>,
+    G: ValidationGroup,
+{
+    type Error = ValidationRejection;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> data::Outcome<'r, Self> {
+        let locale = req.guard::<Locale>().await.succeeded().unwrap_or_default();
+        match Json::<T>::from_data(req, data).await {
+            Outcome::Success(json) => match ValidatedJson::validate(json.into_inner(), locale) {
+                Ok(validated) => Outcome::Success(validated),
+                Err(rejection) => reject(req, rejection),
+            },
+            Outcome::Error((_, e)) => reject(req, ValidationRejection::Body(e.to_string(), locale)),
+            Outcome::Forward(forward) => Outcome::Forward(forward),
+        }
+    }
+}
+
+/// 400捕获器，返回提取器缓存的拒绝原因；不是由提取器拒绝的请求返回请求语言的默认参数错误
+#[catch(400)]
+async fn bad_request(req: &Request<'_>) -> SingleWrapper<ValidationErrors> {
+    match req.local_cache(|| CachedRejection(None)) {
+        CachedRejection(Some(rejection)) => SingleWrapper::from(rejection),
+        CachedRejection(None) => {
+            let locale = req.guard::<Locale>().await.succeeded().unwrap_or_default();
+            let mut wrapper = SingleWrapper::new();
+            wrapper.set_error(WrapperErrEnum::InvalidParam, WrapperErrEnum::InvalidParam.localize(locale));
+            wrapper
+        },
+    }
+}
+
+/// 获取提取器使用的错误捕获器，需要注册到Rocket实例中
+///
+/// # 示例
+///
+/// ```rust,ignore
+/// rocket::build().register("/", common_validation::extract::rocket_extractor::catchers())
+/// ```
+pub fn catchers() -> Vec<Catcher> {
+    catchers![bad_request]
+}
@@ -4,8 +4,10 @@
 //!
 //! ## 功能特性
 //!
-//! - 多种验证规则（非空、长度、日期格式、数值范围等）
+//! - 多种验证规则（非空、长度、日期格式、数值范围、正则、邮箱、手机号、URL、枚举值等）
+//! - 按值的原生类型验证（字符串、数字、日期时间、`Option<T>`、`Vec<T>`、`HashMap<K, V>`）
 //! - 灵活的验证规则组合
+//! - 通过上下文访问外部服务的异步验证
 //! - 自定义错误类型
 //! - 易于使用的验证器接口
 //!
@@ -22,9 +24,6 @@
 //! assert!(result.is_ok());
 //! ```
 
-use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
-use std::str::FromStr;
-
 // ====================== 验证规则配置 ======================
 
 /// 参数验证规则配置
@@ -144,26 +143,26 @@ impl ValidationRule {
     ///
     /// # 参数
     ///
-    /// * `min` - 最小值
+    /// * `min` - 最小值，可以为整数或浮点数
     ///
     /// # 返回值
     ///
     /// 返回Self以支持链式调用
-    pub fn min(self, min: i64) -> Self {
-        self.with_rule(ValidationRulesEnum::NumberMin(min))
+    pub fn min(self, min: impl Into<Number>) -> Self {
+        self.with_rule(ValidationRulesEnum::NumberMin(min.into()))
     }
 
     /// 便捷方法：添加最大值验证
     ///
     /// # 参数
     ///
-    /// * `max` - 最大值
+    /// * `max` - 最大值，可以为整数或浮点数
     ///
     /// # 返回值
     ///
     /// 返回Self以支持链式调用
-    pub fn max(self, max: i64) -> Self {
-        self.with_rule(ValidationRulesEnum::NumberMax(max))
+    pub fn max(self, max: impl Into<Number>) -> Self {
+        self.with_rule(ValidationRulesEnum::NumberMax(max.into()))
     }
 
     /// 便捷方法：添加数值范围验证
@@ -176,9 +175,9 @@ impl ValidationRule {
     /// # 返回值
     ///
     /// 返回Self以支持链式调用
-    pub fn number_range(self, min: i64, max: i64) -> Self {
-        self.with_rule(ValidationRulesEnum::NumberMin(min))
-            .with_rule(ValidationRulesEnum::NumberMax(max))
+    pub fn number_range(self, min: impl Into<Number>, max: impl Into<Number>) -> Self {
+        self.with_rule(ValidationRulesEnum::NumberMin(min.into()))
+            .with_rule(ValidationRulesEnum::NumberMax(max.into()))
     }
 
     /// 便捷方法：添加正数验证
@@ -251,431 +250,145 @@ impl ValidationRule {
     pub fn multiple_of(self, multiple: i64) -> Self {
         self.with_rule(ValidationRulesEnum::MultipleOf(multiple))
     }
-}
-
-// ====================== 验证器实现 ======================
-/// 参数验证器
-///
-/// 提供静态方法用于验证字符串值是否符合指定规则
-pub struct ParameterValidator;
-
-impl ParameterValidator {
-    /// 验证值是否符合规则
-    ///
-    /// # 参数
-    ///
-    /// * `value` - 要验证的字符串值
-    /// * `rule` - 验证规则
-    ///
-    /// # 返回值
-    ///
-    /// 如果验证通过返回Ok(())，否则返回相应的验证错误
-    pub fn validate_value(value: &str, rule: &ValidationRule) -> Result<(), ValidationErrorEnum> {
-        for &rule_type in &rule.rules {
-            match rule_type {
-                ValidationRulesEnum::NotNull => Self::validate_not_null(value, &rule.desc)?,
-                ValidationRulesEnum::Length(len) => Self::validate_length(value, len, &rule.desc)?,
-                ValidationRulesEnum::LengthRange(min, max) => Self::validate_length_range(value, min, max, &rule.desc)?,
-                ValidationRulesEnum::ExistLength(len) => Self::validate_exist_length(value, len, &rule.desc)?,
-                ValidationRulesEnum::ExistLengthRange(min, max) => Self::validate_exist_length_range(value, min, max, &rule.desc)?,
-                ValidationRulesEnum::DateFormat(format) => Self::validate_datetime(value, format, &rule.desc)?,
-                ValidationRulesEnum::NumberMin(min) => Self::validate_number_min(value, min, &rule.desc)?,
-                ValidationRulesEnum::NumberMax(max) => Self::validate_number_max(value, max, &rule.desc)?,
-                ValidationRulesEnum::PositiveNumber => Self::validate_positive_number(value, &rule.desc)?,
-                ValidationRulesEnum::NonNegativeNumber => Self::validate_non_negative_number(value, &rule.desc)?,
-                ValidationRulesEnum::Integer => Self::validate_integer(value, &rule.desc)?,
-                ValidationRulesEnum::DecimalScale(scale) => Self::validate_decimal_scale(value, scale, &rule.desc)?,
-                ValidationRulesEnum::OddNumber => Self::validate_odd_number(value, &rule.desc)?,
-                ValidationRulesEnum::EvenNumber => Self::validate_even_number(value, &rule.desc)?,
-                ValidationRulesEnum::MultipleOf(multiple) => Self::validate_multiple_of(value, multiple, &rule.desc)?,
-                ValidationRulesEnum::Nested => {
-                    // Nested规则不在此处处理，它应该在宏生成的代码中通过直接调用validate()处理
-                    // 此处保留空处理以避免编译错误
-                },
-            }
-        }
-        Ok(())
-    }
-
-    // =============== 具体验证方法 ===============
-    /// 验证非空
-    ///
-    /// # 参数
-    ///
-    /// * `value` - 要验证的值
-    /// * `desc` - 参数描述
-    ///
-    /// # 返回值
-    ///
-    /// Ok(()) 如果验证通过，否则返回 ValidationErrorEnum
-    ///
-    /// # 错误
-    ///
-    /// ValidationErrorEnum::NotNull(desc) 如果值为空或为 "null" 或 "undefined"
-    ///
-    /// # 注意
-    ///
-    /// 这里的 "null" 和 "undefined" 是字符串形式的检查
-    /// 例如: validate_not_null("undefined", "用户名") 返回 Err(ValidationErrorEnum::NotNull("用户名".to_string()))
-    fn validate_not_null(value: &str, desc: &str) -> Result<(), ValidationErrorEnum> {
-        if value.trim().is_empty() || value.eq_ignore_ascii_case("null") || value.eq_ignore_ascii_case("undefined") {
-            Err(ValidationErrorEnum::NotNull(desc.to_string()))
-        } else {
-            Ok(())
-        }
-    }
 
-    /// 验证存在时的固定长度（只有当值不为空时才验证长度）
+    /// 便捷方法：添加正则表达式验证
     ///
     /// # 参数
     ///
-    /// * `value` - 要验证的值
-    /// * `expected_len` - 期望的长度
-    /// * `desc` - 参数描述
+    /// * `pattern` - 正则表达式，值必须完整匹配
     ///
     /// # 返回值
     ///
-    /// Ok(()) 如果验证通过，否则返回 ValidationErrorEnum
-    ///
-    /// # 错误
-    ///
-    /// ValidationErrorEnum::Length(desc, format!("必须为 {expected_len} 个字符")) 如果长度不符合预期
-    fn validate_exist_length(value: &str, expected_len: usize, desc: &str) -> Result<(), ValidationErrorEnum> {
-        if !value.is_empty() { Self::validate_length(value, expected_len, desc) } else { Ok(()) }
-    }
-
-    /// 验证存在时的长度范围（只有当值不为空时才验证长度范围）
-    ///
-    /// # 参数
-    ///
-    /// * `value` - 要验证的值
-    /// * `min` - 最小长度
-    /// * `max` - 最大长度
-    /// * `desc` - 参数描述
-    ///
-    /// # 返回值
-    ///
-    /// Ok(()) 如果验证通过，否则返回 ValidationErrorEnum
-    ///
-    /// # 错误
-    ///
-    /// ValidationErrorEnum::Length(desc, format!("必须在 {min}~{max} 个字符之间"))
-    fn validate_exist_length_range(value: &str, min: usize, max: usize, desc: &str) -> Result<(), ValidationErrorEnum> {
-        if !value.is_empty() { Self::validate_length_range(value, min, max, desc) } else { Ok(()) }
-    }
-
-    /// 验证固定长度
-    ///
-    /// # 参数
-    ///
-    /// * `value` - 要验证的值
-    /// * `expected_len` - 期望的长度
-    /// * `desc` - 参数描述
-    ///
-    /// # 返回值
-    ///
-    /// Ok(()) 如果验证通过，否则返回 ValidationErrorEnum
-    ///
-    /// # 错误
-    ///
-    /// ValidationErrorEnum::Length(desc, format!("必须为 {expected_len} 个字符")) 如果长度不符合预期
-    ///
-    /// # 示例
-    ///
-    /// validate_length("abc", 3, "用户名") 返回 Ok(())
-    ///
-    /// # 注意
-    ///
-    /// 如果 value 的长度不等于 expected_len，则返回错误
-    fn validate_length(value: &str, expected_len: usize, desc: &str) -> Result<(), ValidationErrorEnum> {
-        let value_len = value.len();
-        if value_len != expected_len {
-            return Err(ValidationErrorEnum::Length(desc.to_string(), format!("必须为 {expected_len} 个字符")));
-        }
-        Ok(())
+    /// 返回Self以支持链式调用
+    pub fn pattern(self, pattern: &'static str) -> Self {
+        self.with_rule(ValidationRulesEnum::Pattern(pattern))
     }
 
-    /// 验证长度范围
-    ///
-    /// # 参数
-    ///
-    /// * `value` - 要验证的值
-    /// * `min` - 最小长度
-    /// * `max` - 最大长度
-    /// * `desc` - 参数描述
+    /// 便捷方法：添加邮箱验证
     ///
     /// # 返回值
     ///
-    /// Ok(()) 如果验证通过，否则返回 ValidationErrorEnum
-    ///
-    /// # 错误
-    ///
-    /// ValidationErrorEnum::Length(desc, format!("必须在 {min}~{max} 个字符之间"))
-    fn validate_length_range(value: &str, min: usize, max: usize, desc: &str) -> Result<(), ValidationErrorEnum> {
-        let value_len = value.len();
-        if value_len < min || value_len > max {
-            return Err(ValidationErrorEnum::Length(desc.to_string(), format!("必须在 {min}~{max} 个字符之间")));
-        }
-        Ok(())
-    }
-
-    /// 验证日期时间格式
-    ///
-    /// # 参数
-    ///
-    /// * `value` - 要验证的值
-    /// * `format` - 日期时间格式枚举
-    /// * `desc` - 参数描述
-    ///
-    /// # 返回值
-    ///
-    /// Ok(()) 如果验证通过，否则返回 ValidationErrorEnum
-    ///
-    /// # 错误
-    ///
-    /// ValidationErrorEnum::Format(desc) 如果格式不正确
-    fn validate_datetime(value: &str, format: DateTimeFormatEnum, desc: &str) -> Result<(), ValidationErrorEnum> {
-        match format {
-            DateTimeFormatEnum::Time => {
-                NaiveTime::parse_from_str(value, format.pattern().unwrap()).map_err(|_| ValidationErrorEnum::Format(desc.to_string()))?;
-            },
-            DateTimeFormatEnum::Year | DateTimeFormatEnum::YearNoSplit => {
-                NaiveDate::parse_from_str(value, format.pattern().unwrap()).map_err(|_| ValidationErrorEnum::Format(desc.to_string()))?;
-            },
-            _ => {
-                NaiveDateTime::parse_from_str(value, format.pattern().unwrap()).map_err(|_| ValidationErrorEnum::Format(desc.to_string()))?;
-            },
-        }
-        Ok(())
+    /// 返回Self以支持链式调用
+    pub fn email(self) -> Self {
+        self.with_rule(ValidationRulesEnum::Email)
     }
 
-    /// 验证数值最小值
-    ///
-    /// # 参数
-    ///
-    /// * `value` - 要验证的值
-    /// * `min` - 最小值
-    /// * `desc` - 参数描述
+    /// 便捷方法：添加手机号验证
     ///
     /// # 返回值
     ///
-    /// Ok(()) 如果验证通过，否则返回 ValidationErrorEnum
-    ///
-    /// # 错误
-    ///
-    /// ValidationErrorEnum::NumberMin(desc, min) 如果数值小于最小值
-    /// ValidationErrorEnum::NumberFormatError 如果不是有效数字
-    fn validate_number_min(value: &str, min: i64, desc: &str) -> Result<(), ValidationErrorEnum> {
-        let num = i64::from_str(value).map_err(|_| ValidationErrorEnum::NumberFormatError)?;
-
-        if num < min {
-            return Err(ValidationErrorEnum::NumberMin(desc.to_string(), min));
-        }
-        Ok(())
+    /// 返回Self以支持链式调用
+    pub fn mobile_phone(self) -> Self {
+        self.with_rule(ValidationRulesEnum::MobilePhone)
     }
 
-    /// 验证数值最大值
-    ///
-    /// # 参数
-    ///
-    /// * `value` - 要验证的值
-    /// * `max` - 最大值
-    /// * `desc` - 参数描述
+    /// 便捷方法：添加URL验证
     ///
     /// # 返回值
     ///
-    /// Ok(()) 如果验证通过，否则返回 ValidationErrorEnum
-    ///
-    /// # 错误
-    ///
-    /// ValidationErrorEnum::NumberMax(desc, max) 如果数值大于最大值
-    /// ValidationErrorEnum::NumberFormatError 如果不是有效数字
-    fn validate_number_max(value: &str, max: i64, desc: &str) -> Result<(), ValidationErrorEnum> {
-        let num = i64::from_str(value).map_err(|_| ValidationErrorEnum::NumberFormatError)?;
-
-        if num > max {
-            return Err(ValidationErrorEnum::NumberMax(desc.to_string(), max));
-        }
-        Ok(())
+    /// 返回Self以支持链式调用
+    pub fn url(self) -> Self {
+        self.with_rule(ValidationRulesEnum::Url)
     }
 
-    /// 验证正数
+    /// 便捷方法：添加枚举值验证
     ///
     /// # 参数
     ///
-    /// * `value` - 要验证的值
-    /// * `desc` - 参数描述
+    /// * `values` - 可选值列表
     ///
     /// # 返回值
     ///
-    /// Ok(()) 如果验证通过，否则返回 ValidationErrorEnum
-    ///
-    /// # 错误
-    ///
-    /// ValidationErrorEnum::PositiveNumber(desc) 如果不是正数
-    /// ValidationErrorEnum::NumberFormatError 如果不是有效数字
-    fn validate_positive_number(value: &str, desc: &str) -> Result<(), ValidationErrorEnum> {
-        let num = f64::from_str(value).map_err(|_| ValidationErrorEnum::NumberFormatError)?;
-
-        if num <= 0.0 {
-            return Err(ValidationErrorEnum::PositiveNumber(desc.to_string()));
-        }
-        Ok(())
+    /// 返回Self以支持链式调用
+    pub fn one_of(self, values: &'static [&'static str]) -> Self {
+        self.with_rule(ValidationRulesEnum::OneOf(values))
     }
+}
 
-    /// 验证非负数
-    ///
-    /// # 参数
-    ///
-    /// * `value` - 要验证的值
-    /// * `desc` - 参数描述
-    ///
-    /// # 返回值
-    ///
-    /// Ok(()) 如果验证通过，否则返回 ValidationErrorEnum
-    ///
-    /// # 错误
-    ///
-    /// ValidationErrorEnum::NonNegativeNumber(desc) 如果是负数
-    /// ValidationErrorEnum::NumberFormatError 如果不是有效数字
-    fn validate_non_negative_number(value: &str, desc: &str) -> Result<(), ValidationErrorEnum> {
-        let num = f64::from_str(value).map_err(|_| ValidationErrorEnum::NumberFormatError)?;
-
-        if num < 0.0 {
-            return Err(ValidationErrorEnum::NonNegativeNumber(desc.to_string()));
-        }
-        Ok(())
-    }
+// ====================== 验证器实现 ======================
+/// 参数验证器
+///
+/// 提供静态方法用于验证值是否符合指定规则，规则按值的原生类型验证，参见: [Validate]
+pub struct ParameterValidator;
 
-    /// 验证整数
+impl ParameterValidator {
+    /// 验证值是否符合规则
     ///
     /// # 参数
     ///
-    /// * `value` - 要验证的值
-    /// * `desc` - 参数描述
+    /// * `value` - 要验证的值，如字符串、数字、日期时间、`Option<T>`、`Vec<T>`
+    /// * `rule` - 验证规则
     ///
     /// # 返回值
     ///
-    /// Ok(()) 如果验证通过，否则返回 ValidationErrorEnum
-    ///
-    /// # 错误
-    ///
-    /// ValidationErrorEnum::Integer(desc) 如果不是整数
-    /// ValidationErrorEnum::NumberFormatError 如果不是有效数字
-    fn validate_integer(value: &str, desc: &str) -> Result<(), ValidationErrorEnum> {
-        let num = f64::from_str(value).map_err(|_| ValidationErrorEnum::NumberFormatError)?;
-
-        if num.fract() != 0.0 {
-            return Err(ValidationErrorEnum::Integer(desc.to_string()));
+    /// 如果验证通过返回Ok(())，否则返回第一个未通过规则的验证错误
+    pub fn validate<V: Validate + ?Sized>(value: &V, rule: &ValidationRule) -> Result<(), ValidationErrorEnum> {
+        for &rule_type in &rule.rules {
+            value.validate_rule(rule_type, &rule.desc)?;
         }
         Ok(())
     }
 
-    /// 验证小数位数
+    /// 验证值是否符合规则，收集全部未通过的规则
     ///
-    /// # 参数
-    ///
-    /// * `value` - 要验证的值
-    /// * `scale` - 最大小数位数
-    /// * `desc` - 参数描述
-    ///
-    /// # 返回值
-    ///
-    /// Ok(()) 如果验证通过，否则返回 ValidationErrorEnum
-    ///
-    /// # 错误
-    ///
-    /// ValidationErrorEnum::DecimalScale(desc, scale) 如果小数位数超过限制
-    /// ValidationErrorEnum::NumberFormatError 如果不是有效数字
-    fn validate_decimal_scale(value: &str, scale: u32, desc: &str) -> Result<(), ValidationErrorEnum> {
-        // 检查是否是有效数字
-        let _ = f64::from_str(value).map_err(|_| ValidationErrorEnum::NumberFormatError)?;
-
-        // 检查小数位数
-        if let Some(decimal_part) = value.split('.').nth(1)
-            && decimal_part.len() > scale as usize
-        {
-            return Err(ValidationErrorEnum::DecimalScale(desc.to_string(), scale));
-        }
-        Ok(())
-    }
-
-    /// 验证奇数
+    /// 非空验证未通过时不再验证其余规则
     ///
     /// # 参数
     ///
-    /// * `value` - 要验证的值
-    /// * `desc` - 参数描述
+    /// * `value` - 要验证的值，如字符串、数字、日期时间、`Option<T>`、`Vec<T>`
+    /// * `rule` - 验证规则
     ///
     /// # 返回值
     ///
-    /// Ok(()) 如果验证通过，否则返回 ValidationErrorEnum
-    ///
-    /// # 错误
-    ///
-    /// ValidationErrorEnum::OddNumber(desc) 如果不是奇数
-    /// ValidationErrorEnum::NumberFormatError 如果不是有效数字
-    fn validate_odd_number(value: &str, desc: &str) -> Result<(), ValidationErrorEnum> {
-        let num = i64::from_str(value).map_err(|_| ValidationErrorEnum::NumberFormatError)?;
-
-        if num % 2 == 0 {
-            return Err(ValidationErrorEnum::OddNumber(desc.to_string()));
+    /// 全部未通过规则的验证错误，验证通过时为空
+    pub fn validate_all<V: Validate + ?Sized>(value: &V, rule: &ValidationRule) -> Vec<ValidationErrorEnum> {
+        let mut errors = Vec::new();
+        for &rule_type in &rule.rules {
+            if let Err(e) = value.validate_rule(rule_type, &rule.desc) {
+                let is_not_null = matches!(e, ValidationErrorEnum::NotNull(_));
+                errors.push(e);
+                if is_not_null {
+                    break;
+                }
+            }
         }
-        Ok(())
+        errors
     }
 
-    /// 验证偶数
+    /// 验证字符串值是否符合规则
     ///
     /// # 参数
     ///
-    /// * `value` - 要验证的值
-    /// * `desc` - 参数描述
+    /// * `value` - 要验证的字符串值
+    /// * `rule` - 验证规则
     ///
     /// # 返回值
     ///
-    /// Ok(()) 如果验证通过，否则返回 ValidationErrorEnum
-    ///
-    /// # 错误
-    ///
-    /// ValidationErrorEnum::EvenNumber(desc) 如果不是偶数
-    /// ValidationErrorEnum::NumberFormatError 如果不是有效数字
-    fn validate_even_number(value: &str, desc: &str) -> Result<(), ValidationErrorEnum> {
-        let num = i64::from_str(value).map_err(|_| ValidationErrorEnum::NumberFormatError)?;
-
-        if num % 2 != 0 {
-            return Err(ValidationErrorEnum::EvenNumber(desc.to_string()));
-        }
-        Ok(())
+    /// 如果验证通过返回Ok(())，否则返回相应的验证错误
+    pub fn validate_value(value: &str, rule: &ValidationRule) -> Result<(), ValidationErrorEnum> {
+        Self::validate(value, rule)
     }
 
-    /// 验证倍数
+    /// 验证字符串值是否符合规则，收集全部未通过的规则
     ///
     /// # 参数
     ///
-    /// * `value` - 要验证的值
-    /// * `multiple` - 倍数
-    /// * `desc` - 参数描述
+    /// * `value` - 要验证的字符串值
+    /// * `rule` - 验证规则
     ///
     /// # 返回值
     ///
-    /// Ok(()) 如果验证通过，否则返回 ValidationErrorEnum
-    ///
-    /// # 错误
-    ///
-    /// ValidationErrorEnum::MultipleOf(desc, multiple) 如果不是指定倍数
-    /// ValidationErrorEnum::NumberFormatError 如果不是有效数字
-    fn validate_multiple_of(value: &str, multiple: i64, desc: &str) -> Result<(), ValidationErrorEnum> {
-        let num = i64::from_str(value).map_err(|_| ValidationErrorEnum::NumberFormatError)?;
-
-        if num % multiple != 0 {
-            return Err(ValidationErrorEnum::MultipleOf(desc.to_string(), multiple));
-        }
-        Ok(())
+    /// 全部未通过规则的验证错误，验证通过时为空
+    pub fn validate_value_all(value: &str, rule: &ValidationRule) -> Vec<ValidationErrorEnum> {
+        Self::validate_all(value, rule)
     }
 }
 
 // ====================== 分组验证接口 ======================
 /// 分组验证基础trait
+///
+/// 分组的标记类型为单元结构体，自定义分组可以使用派生宏 `#[derive(ValidationGroup)]` 实现，
+/// 分组名称为去掉 `Group` 后缀的类型名称转换为蛇形命名，如 `BatchDeleteGroup` 的分组名称为 `batch_delete`
 pub trait ValidationGroup {
     /// 获取分组名称
     fn group_name() -> &'static str;
@@ -721,26 +434,40 @@ impl ValidationGroup for PageQueryGroup {
     }
 }
 
+/// 结构体定义的验证分组
+///
+/// 派生宏为 #[group_fields(...)] 中声明的每个分组生成实现，按未定义的分组验证时编译报错
+///
+/// # 示例
+///
+/// ```rust,compile_fail
+/// use common_validation::{GroupValidatable, QueryGroup};
+/// use common_validation_macros::ValidatableImpl;
+///
+/// #[derive(ValidatableImpl)]
+/// #[group_fields(create = ["name"])]
+/// struct Dept {
+///     #[validate(not_null, desc = "名称")]
+///     name: String,
+/// }
+///
+/// // Dept 未定义查询分组
+/// let _ = Dept { name: "研发部".to_string() }.validate_with_group::<QueryGroup>();
+/// ```
+#[diagnostic::on_unimplemented(message = "`{Self}` 未定义验证分组 `{G}`", label = "未定义的验证分组", note = "在结构体的 #[group_fields(...)] 属性中声明该分组需要验证的字段")]
+pub trait GroupFields<G: ValidationGroup> {
+    /// 分组需要验证的字段
+    const FIELDS: &'static [&'static str];
+}
+
 /// 分组字段提供者trait
 ///
-/// 用于集中管理不同分组需要验证的字段
+/// 用于按分组名称在运行时查找需要验证的字段
 pub trait GroupFieldsProvider {
-    /// 获取创建分组需要验证的字段
-    fn get_create_group_fields() -> &'static [&'static str];
-
-    /// 获取更新分组需要验证的字段
-    fn get_update_group_fields() -> &'static [&'static str];
-
-    /// 获取查询分组需要验证的字段
-    fn get_query_group_fields() -> &'static [&'static str];
+    /// 结构体定义的全部分组名称
+    fn get_group_names() -> &'static [&'static str];
 
-    /// 获取状态更新分组需要验证的字段
-    fn get_status_update_group_fields() -> &'static [&'static str];
-
-    /// 获取分页查询分组需要验证的字段
-    fn get_page_query_group_fields() -> &'static [&'static str];
-
-    /// 根据分组名称获取需要验证的字段
+    /// 根据分组名称获取需要验证的字段，未定义的分组返回空列表
     fn get_group_fields(group_name: &str) -> &'static [&'static str];
 }
 
@@ -751,7 +478,24 @@ pub trait GroupValidatable: GroupFieldsProvider {
     /// # 返回值
     ///
     /// 如果验证通过返回Ok(())，否则返回相应的验证错误
-    fn validate_with_group<G: ValidationGroup>(&self) -> Result<(), ValidationErrorEnum>;
+    fn validate_with_group<G: ValidationGroup>(&self) -> Result<(), ValidationErrorEnum>
+    where
+        Self: GroupFields<G>;
+
+    /// 分组验证，收集分组内全部字段的验证错误
+    ///
+    /// 默认实现在第一个错误处停止，错误的字段路径为空；派生宏生成的实现会收集全部错误
+    ///
+    /// # 返回值
+    ///
+    /// 如果验证通过返回Ok(())，否则返回按字段路径汇总的验证错误
+    fn validate_all_with_group<G: ValidationGroup>(&self) -> Result<(), ValidationErrors>
+    where
+        Self: GroupFields<G>,
+    {
+        self.validate_with_group::<G>()
+            .map_err(ValidationErrors::from)
+    }
 }
 
 // ====================== 结构体验证接口 ======================
@@ -765,6 +509,80 @@ pub trait Validatable {
     ///
     /// 如果验证通过返回Ok(())，否则返回相应的验证错误
     fn validate(&self) -> Result<(), ValidationErrorEnum>;
+
+    /// 验证结构体，收集全部字段的验证错误
+    ///
+    /// 默认实现在第一个错误处停止，错误的字段路径为空；派生宏生成的实现会收集全部错误
+    ///
+    /// # 返回值
+    ///
+    /// 如果验证通过返回Ok(())，否则返回按字段路径汇总的验证错误
+    fn validate_all(&self) -> Result<(), ValidationErrors> {
+        self.validate().map_err(ValidationErrors::from)
+    }
+}
+
+// ====================== 异步验证接口 ======================
+/// 异步可验证 trait
+///
+/// 用于需要访问外部服务的验证规则，如名称唯一性、关联数据是否存在等，验证函数通过上下文（如数据访问层的仓储）查询数据。
+/// 异步验证只执行 `async_custom`、`async_schema` 配置的验证函数，不包含同步规则，通常在同步验证通过后调用。
+///
+/// # 示例
+///
+/// ```rust
+/// use common_validation::{AsyncValidatable, ValidationErrorEnum};
+/// use common_validation_macros::ValidatableImpl;
+///
+/// /// 已存在的用户名
+/// struct UserNames(Vec<String>);
+///
+/// async fn unique_username(username: &String, names: &UserNames) -> Result<(), ValidationErrorEnum> {
+///     if names.0.contains(username) {
+///         return Err(ValidationErrorEnum::Custom("用户名已存在".to_string()));
+///     }
+///     Ok(())
+/// }
+///
+/// #[derive(ValidatableImpl)]
+/// #[validate(context = "UserNames")]
+/// struct User {
+///     #[validate(not_null, async_custom = "unique_username", desc = "用户名")]
+///     username: String,
+/// }
+///
+/// # let _ = User { username: "admin".to_string() }.validate_async(&UserNames(vec!["admin".to_string()]));
+/// ```
+pub trait AsyncValidatable {
+    /// 验证上下文，如数据访问层的仓储
+    type Context: ?Sized + Sync;
+
+    /// 验证失败时的错误类型
+    type Error;
+
+    /// 异步验证结构体，在第一个错误处停止
+    ///
+    /// # 参数
+    ///
+    /// * `context` - 验证上下文
+    ///
+    /// # 返回值
+    ///
+    /// 如果验证通过返回Ok(())，否则返回验证函数的错误
+    fn validate_async(&self, context: &Self::Context) -> impl Future<Output = Result<(), Self::Error>> + Send;
+
+    /// 异步分组验证，只验证分组内的字段及配置了该分组的结构体级别验证函数
+    ///
+    /// # 参数
+    ///
+    /// * `context` - 验证上下文
+    ///
+    /// # 返回值
+    ///
+    /// 如果验证通过返回Ok(())，否则返回验证函数的错误
+    fn validate_async_with_group<G: ValidationGroup>(&self, context: &Self::Context) -> impl Future<Output = Result<(), Self::Error>> + Send
+    where
+        Self: GroupFields<G>;
 }
 
 /// 分组验证辅助方法
@@ -791,5 +609,13 @@ where
 }
 
 mod enums;
+#[cfg(any(feature = "axum_extractor", feature = "actix_extractor", feature = "rocket_extractor"))]
+pub mod extract;
+mod validation_errors;
+mod value;
 // ====================== 模块导出 ======================
 pub use enums::*;
+#[cfg(any(feature = "axum_extractor", feature = "actix_extractor", feature = "rocket_extractor"))]
+pub use extract::{ValidatedJson, ValidationRejection};
+pub use validation_errors::*;
+pub use value::{Number, Validate, ValidateFormat, ValidateLength, ValidateNumber, ValidateOneOf};
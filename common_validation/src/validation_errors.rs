@@ -0,0 +1,182 @@
+//! # 验证错误集合
+//!
+//! 收集模式下按字段路径汇总全部验证错误，序列化为以字段路径为键的结构，便于直接返回给客户端
+
+use std::fmt;
+
+use common_wrapper::i18n::Locale;
+use serde::Serialize;
+use serde::ser::{SerializeMap, Serializer};
+
+use crate::ValidationErrorEnum;
+
+/// 单个验证错误
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FieldError {
+    /// 未通过的验证规则，参见: [ValidationErrorEnum::rule]
+    pub rule: String,
+    /// 错误信息，默认为简体中文，参见: [ValidationErrors::localize]
+    pub message: String,
+    /// 原始验证错误，用于转换错误信息的语言
+    #[serde(skip)]
+    source: ValidationErrorEnum,
+}
+
+impl From<ValidationErrorEnum> for FieldError {
+    fn from(error: ValidationErrorEnum) -> Self {
+        FieldError {
+            rule: error.rule().to_string(),
+            message: error.to_string(),
+            source: error,
+        }
+    }
+}
+
+/// 验证错误集合
+///
+/// 按字段首次出错的顺序保存各字段的验证错误，字段路径使用 `.` 连接嵌套结构体的字段，
+/// 使用 `[下标]` 表示数组元素，如 `address.city`、`items[0].name`
+///
+/// 序列化结果以字段路径为键：
+///
+/// ```json
+/// {
+///     "name": [{ "rule": "length", "message": "部门名称 长度不符合要求: 必须在 1~30 个字符之间" }],
+///     "status": [{ "rule": "not_null", "message": "部门状态 不能为空" }]
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ValidationErrors {
+    /// 字段路径及该字段的验证错误
+    fields: Vec<(String, Vec<FieldError>)>,
+}
+
+impl ValidationErrors {
+    /// 创建空的验证错误集合
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 是否没有任何验证错误
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// 验证错误的总数
+    pub fn len(&self) -> usize {
+        self.fields.iter().map(|(_, errors)| errors.len()).sum()
+    }
+
+    /// 添加字段的验证错误
+    ///
+    /// # 参数
+    ///
+    /// * `field` - 字段路径
+    /// * `error` - 验证错误
+    pub fn add(&mut self, field: impl Into<String>, error: impl Into<FieldError>) {
+        let field = field.into();
+        let error = error.into();
+        match self.fields.iter_mut().find(|(name, _)| *name == field) {
+            Some((_, errors)) => errors.push(error),
+            None => self.fields.push((field, vec![error])),
+        }
+    }
+
+    /// 合并嵌套结构体的验证错误，字段路径加上前缀
+    ///
+    /// # 参数
+    ///
+    /// * `prefix` - 嵌套结构体所在的字段路径
+    /// * `other` - 嵌套结构体的验证错误
+    pub fn merge(&mut self, prefix: &str, other: ValidationErrors) {
+        for (field, errors) in other.fields {
+            let path = match (prefix.is_empty(), field.is_empty()) {
+                (true, _) => field,
+                (false, true) => prefix.to_string(),
+                (false, false) if field.starts_with('[') => format!("{prefix}{field}"),
+                (false, false) => format!("{prefix}.{field}"),
+            };
+            for error in errors {
+                self.add(path.clone(), error);
+            }
+        }
+    }
+
+    /// 获取字段的验证错误
+    ///
+    /// # 参数
+    ///
+    /// * `field` - 字段路径
+    ///
+    /// # 返回值
+    ///
+    /// 字段没有验证错误时返回 [None]
+    pub fn get(&self, field: &str) -> Option<&[FieldError]> {
+        self.fields
+            .iter()
+            .find(|(name, _)| name == field)
+            .map(|(_, errors)| errors.as_slice())
+    }
+
+    /// 按顺序遍历全部验证错误
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &FieldError)> {
+        self.fields
+            .iter()
+            .flat_map(|(field, errors)| errors.iter().map(move |error| (field.as_str(), error)))
+    }
+
+    /// 将全部错误信息转换为指定语言
+    ///
+    /// 简体中文以外的语言用字段路径代替字段描述，参见 [ValidationErrorEnum::localize_field]
+    ///
+    /// # 参数
+    ///
+    /// * `locale` - 语言
+    pub fn localize(&mut self, locale: Locale) {
+        for (field, errors) in &mut self.fields {
+            for error in errors {
+                error.message = error.source.localize_field(locale, field);
+            }
+        }
+    }
+
+    /// 没有验证错误时返回Ok(())，否则返回自身
+    pub fn into_result(self) -> Result<(), Self> {
+        if self.is_empty() { Ok(()) } else { Err(self) }
+    }
+}
+
+/// 快速失败模式的单个验证错误转换为错误集合，字段路径为空
+impl From<ValidationErrorEnum> for ValidationErrors {
+    fn from(error: ValidationErrorEnum) -> Self {
+        let mut errors = ValidationErrors::new();
+        errors.add("", error);
+        errors
+    }
+}
+
+/// 按顺序输出全部错误信息，以 `; ` 分隔
+impl fmt::Display for ValidationErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, (_, error)) in self.iter().enumerate() {
+            if index > 0 {
+                f.write_str("; ")?;
+            }
+            f.write_str(&error.message)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ValidationErrors {}
+
+/// 序列化为以字段路径为键的结构
+impl Serialize for ValidationErrors {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.fields.len()))?;
+        for (field, errors) in &self.fields {
+            map.serialize_entry(field, errors)?;
+        }
+        map.end()
+    }
+}
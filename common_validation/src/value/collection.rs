@@ -0,0 +1,66 @@
+//! # 集合类型验证
+//!
+//! 非空、长度规则作用于集合本身（按元素个数计算长度），其余规则作用于每个元素
+
+use std::collections::HashMap;
+
+use super::{Validate, ValidateLength};
+use crate::{LengthLimit, ValidationErrorEnum, ValidationRulesEnum};
+
+impl<T: Validate> Validate for [T] {
+    fn validate_rule(&self, rule: ValidationRulesEnum, desc: &str) -> Result<(), ValidationErrorEnum> {
+        validate_items(self.len(), self.iter(), rule, desc)
+    }
+}
+
+impl<T: Validate> Validate for Vec<T> {
+    fn validate_rule(&self, rule: ValidationRulesEnum, desc: &str) -> Result<(), ValidationErrorEnum> {
+        self.as_slice().validate_rule(rule, desc)
+    }
+}
+
+impl<K, V: Validate, S> Validate for HashMap<K, V, S> {
+    fn validate_rule(&self, rule: ValidationRulesEnum, desc: &str) -> Result<(), ValidationErrorEnum> {
+        validate_items(self.len(), self.values(), rule, desc)
+    }
+}
+
+impl<T: Validate> ValidateLength for [T] {}
+impl<T: Validate> ValidateLength for Vec<T> {}
+impl<K, V: Validate, S> ValidateLength for HashMap<K, V, S> {}
+
+/// 按单条规则验证集合
+///
+/// # 参数
+///
+/// * `len` - 集合的元素个数
+/// * `items` - 集合的元素，映射为值
+/// * `rule` - 验证规则
+/// * `desc` - 参数描述
+///
+/// # 返回值
+///
+/// Ok(()) 如果验证通过，否则返回集合或第一个未通过元素的验证错误
+fn validate_items<'a, T: Validate + 'a>(len: usize, mut items: impl Iterator<Item = &'a T>, rule: ValidationRulesEnum, desc: &str) -> Result<(), ValidationErrorEnum> {
+    match rule {
+        ValidationRulesEnum::NotNull if len == 0 => Err(ValidationErrorEnum::NotNull(desc.to_string())),
+        ValidationRulesEnum::NotNull => Ok(()),
+        ValidationRulesEnum::Length(expected_len) | ValidationRulesEnum::ExistLength(expected_len) => {
+            if len == expected_len || (len == 0 && matches!(rule, ValidationRulesEnum::ExistLength(_))) {
+                Ok(())
+            } else {
+                Err(ValidationErrorEnum::Length(desc.to_string(), LengthLimit::Items(expected_len)))
+            }
+        },
+        ValidationRulesEnum::LengthRange(min, max) | ValidationRulesEnum::ExistLengthRange(min, max) => {
+            if (min..=max).contains(&len) || (len == 0 && matches!(rule, ValidationRulesEnum::ExistLengthRange(..))) {
+                Ok(())
+            } else {
+                Err(ValidationErrorEnum::Length(desc.to_string(), LengthLimit::ItemsRange(min, max)))
+            }
+        },
+        // 嵌套验证由派生宏处理
+        ValidationRulesEnum::Nested => Ok(()),
+        _ => items.try_for_each(|item| item.validate_rule(rule, desc)),
+    }
+}
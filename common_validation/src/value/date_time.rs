@@ -0,0 +1,26 @@
+//! # 日期时间类型验证
+//!
+//! 日期时间类型已由反序列化按格式解析，日期格式规则总是通过；只支持非空、日期格式规则
+
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+
+use super::Validate;
+use crate::{ValidationErrorEnum, ValidationRulesEnum};
+
+/// 为日期时间类型实现 [Validate]
+macro_rules! impl_date_time {
+    ($($ty:ty),*) => {
+        $(
+            impl Validate for $ty {
+                fn validate_rule(&self, rule: ValidationRulesEnum, _desc: &str) -> Result<(), ValidationErrorEnum> {
+                    match rule {
+                        ValidationRulesEnum::NotNull | ValidationRulesEnum::DateFormat(_) | ValidationRulesEnum::Nested => Ok(()),
+                        _ => Err(ValidationErrorEnum::UnsupportedType(stringify!($ty).to_string(), rule.name().to_string())),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_date_time!(NaiveDateTime, NaiveDate, NaiveTime);
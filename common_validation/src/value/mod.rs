@@ -0,0 +1,97 @@
+//! # 按值类型验证
+//!
+//! 为各种值类型实现 [Validate]，验证规则直接作用于值的原生类型，不再先转换为字符串
+//!
+//! ## 模块组成
+//!
+//! - [text] - 字符串类型（`str`、`String`）
+//! - [number] - 数字类型（整数、浮点数）及数值边界 [Number]
+//! - [date_time] - 日期时间类型（`NaiveDateTime`、`NaiveDate`、`NaiveTime`）
+//! - [collection] - 集合类型（`Vec<T>`、切片、`HashMap<K, V>`）
+//!
+//! `Option<T>` 为 `None` 时只验证非空，其余规则只在有值时验证
+
+mod collection;
+mod date_time;
+mod number;
+mod text;
+
+pub use number::Number;
+
+use crate::{ValidationErrorEnum, ValidationRulesEnum};
+
+/// 可按验证规则验证的值
+///
+/// 类型不支持的规则返回 [ValidationErrorEnum::UnsupportedType]
+pub trait Validate {
+    /// 按单条规则验证值
+    ///
+    /// # 参数
+    ///
+    /// * `rule` - 验证规则
+    /// * `desc` - 参数描述
+    ///
+    /// # 返回值
+    ///
+    /// 如果验证通过返回Ok(())，否则返回相应的验证错误
+    fn validate_rule(&self, rule: ValidationRulesEnum, desc: &str) -> Result<(), ValidationErrorEnum>;
+}
+
+/// 支持长度规则的值，即字符串及集合类型
+///
+/// 派生宏对使用 `length`、`length_range`、`exist_length`、`exist_length_range` 以及非数字类型上 `min`、`max` 的字段要求该约束，
+/// 在编译期拒绝不支持长度规则的字段类型；自定义类型的 [Validate] 实现支持长度规则时可以实现该trait
+#[diagnostic::on_unimplemented(message = "`{Self}` 不支持长度验证规则", label = "长度规则只能用于字符串或集合类型的字段，数字类型的别名请使用 number_min、number_max")]
+pub trait ValidateLength: Validate {}
+
+/// 支持数值规则的值，即数字类型
+///
+/// 派生宏对使用 `number_min`、`number_max`、`positive_number` 等数值规则以及数字类型上 `min`、`max` 的字段要求该约束
+#[diagnostic::on_unimplemented(message = "`{Self}` 不支持数值验证规则", label = "数值规则只能用于数字类型的字段")]
+pub trait ValidateNumber: Validate {}
+
+/// 支持格式规则的值，即字符串类型
+///
+/// 派生宏对使用 `pattern`、`email`、`mobile_phone`、`url` 的字段要求该约束
+#[diagnostic::on_unimplemented(message = "`{Self}` 不支持格式验证规则", label = "pattern、email、mobile_phone、url 只能用于字符串类型的字段")]
+pub trait ValidateFormat: Validate {}
+
+/// 支持枚举值规则的值，即字符串及数字类型
+///
+/// 派生宏对使用 `one_of` 的字段要求该约束
+#[diagnostic::on_unimplemented(message = "`{Self}` 不支持枚举值验证规则", label = "one_of 只能用于字符串或数字类型的字段")]
+pub trait ValidateOneOf: Validate {}
+
+/// 为引用及 `Box<T>` 转发规则约束
+macro_rules! impl_rule_bound {
+    ($($bound:ident),*) => {
+        $(
+            impl<T: $bound + ?Sized> $bound for &T {}
+            impl<T: $bound + ?Sized> $bound for Box<T> {}
+        )*
+    };
+}
+
+impl_rule_bound!(ValidateLength, ValidateNumber, ValidateFormat, ValidateOneOf);
+
+impl<T: Validate + ?Sized> Validate for &T {
+    fn validate_rule(&self, rule: ValidationRulesEnum, desc: &str) -> Result<(), ValidationErrorEnum> {
+        (**self).validate_rule(rule, desc)
+    }
+}
+
+impl<T: Validate + ?Sized> Validate for Box<T> {
+    fn validate_rule(&self, rule: ValidationRulesEnum, desc: &str) -> Result<(), ValidationErrorEnum> {
+        (**self).validate_rule(rule, desc)
+    }
+}
+
+impl<T: Validate> Validate for Option<T> {
+    fn validate_rule(&self, rule: ValidationRulesEnum, desc: &str) -> Result<(), ValidationErrorEnum> {
+        match self {
+            Some(value) => value.validate_rule(rule, desc),
+            None if rule == ValidationRulesEnum::NotNull => Err(ValidationErrorEnum::NotNull(desc.to_string())),
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,195 @@
+//! # 数字类型验证
+//!
+//! 整数按 `i128` 比较，`u64` 超过 `i64::MAX` 的值也能正确验证（`u128` 超过 `i128::MAX` 的值按 `f64` 比较）；浮点数按 `f64` 比较
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+use super::{Validate, ValidateNumber, ValidateOneOf};
+use crate::{ValidationErrorEnum, ValidationRulesEnum};
+
+/// 数值，用于数字验证规则的边界及被验证的值
+///
+/// 整数之间精确比较，整数与浮点数之间按 `f64` 比较
+///
+/// # 示例
+///
+/// ```rust
+/// use common_validation::Number;
+///
+/// assert!(Number::from(u64::MAX) > Number::from(i64::MAX));
+/// assert!(Number::from(1) < Number::from(1.5));
+/// assert_eq!(Number::from(2), Number::from(2.0));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub enum Number {
+    /// 整数
+    Int(i128),
+    /// 浮点数
+    Float(f64),
+}
+
+impl Number {
+    /// 转换为浮点数
+    fn as_f64(self) -> f64 {
+        match self {
+            Number::Int(value) => value as f64,
+            Number::Float(value) => value,
+        }
+    }
+
+    /// 转换为整数，小数部分不为0的浮点数返回None
+    fn as_integer(self) -> Option<i128> {
+        match self {
+            Number::Int(value) => Some(value),
+            Number::Float(value) if value.fract() == 0.0 => Some(value as i128),
+            Number::Float(_) => None,
+        }
+    }
+}
+
+impl PartialEq for Number {
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for Number {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Number::Int(a), Number::Int(b)) => Some(a.cmp(b)),
+            _ => self.as_f64().partial_cmp(&other.as_f64()),
+        }
+    }
+}
+
+impl fmt::Display for Number {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Number::Int(value) => write!(f, "{value}"),
+            Number::Float(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+impl FromStr for Number {
+    type Err = ValidationErrorEnum;
+
+    /// 优先按整数解析，否则按浮点数解析，`NaN`、`inf` 等非有限值视为格式错误
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if let Ok(value) = value.parse::<i128>() {
+            return Ok(Number::Int(value));
+        }
+        match value.parse::<f64>() {
+            Ok(value) if value.is_finite() => Ok(Number::Float(value)),
+            _ => Err(ValidationErrorEnum::NumberFormatError),
+        }
+    }
+}
+
+/// 为数字类型实现与 [Number] 的转换、[Validate] 及数值、枚举值规则约束
+macro_rules! impl_number {
+    ($variant:ident, $target:ty: $($ty:ty),*) => {
+        $(
+            impl From<$ty> for Number {
+                fn from(value: $ty) -> Self {
+                    Number::$variant(value as $target)
+                }
+            }
+
+            impl Validate for $ty {
+                fn validate_rule(&self, rule: ValidationRulesEnum, desc: &str) -> Result<(), ValidationErrorEnum> {
+                    validate_number(Number::from(*self), stringify!($ty), rule, desc)
+                }
+            }
+
+            impl ValidateNumber for $ty {}
+            impl ValidateOneOf for $ty {}
+        )*
+    };
+}
+
+impl_number!(Int, i128: i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+impl_number!(Float, f64: f32, f64);
+
+/// `u128` 超过 `i128::MAX` 的值按浮点数比较
+impl From<u128> for Number {
+    fn from(value: u128) -> Self {
+        i128::try_from(value).map_or(Number::Float(value as f64), Number::Int)
+    }
+}
+
+impl Validate for u128 {
+    fn validate_rule(&self, rule: ValidationRulesEnum, desc: &str) -> Result<(), ValidationErrorEnum> {
+        validate_number(Number::from(*self), "u128", rule, desc)
+    }
+}
+
+impl ValidateNumber for u128 {}
+impl ValidateOneOf for u128 {}
+
+/// 按单条规则验证数值
+///
+/// 正则、邮箱、手机号、URL、枚举值规则按数值的字符串形式验证，长度、日期格式规则不支持数字类型
+///
+/// # 参数
+///
+/// * `value` - 要验证的值
+/// * `type_name` - 值的类型名称，用于不支持的规则的错误信息
+/// * `rule` - 验证规则
+/// * `desc` - 参数描述
+///
+/// # 返回值
+///
+/// Ok(()) 如果验证通过，否则返回 ValidationErrorEnum
+pub(crate) fn validate_number(value: Number, type_name: &str, rule: ValidationRulesEnum, desc: &str) -> Result<(), ValidationErrorEnum> {
+    if let Number::Float(value) = value
+        && !value.is_finite()
+        && !matches!(rule, ValidationRulesEnum::NotNull | ValidationRulesEnum::Nested)
+    {
+        return Err(ValidationErrorEnum::NumberFormatError);
+    }
+
+    match rule {
+        // 数字总是有值，嵌套验证由派生宏处理
+        ValidationRulesEnum::NotNull | ValidationRulesEnum::Nested => Ok(()),
+        ValidationRulesEnum::NumberMin(min) if value < min => Err(ValidationErrorEnum::NumberMin(desc.to_string(), min)),
+        ValidationRulesEnum::NumberMax(max) if value > max => Err(ValidationErrorEnum::NumberMax(desc.to_string(), max)),
+        ValidationRulesEnum::NumberMin(_) | ValidationRulesEnum::NumberMax(_) => Ok(()),
+        ValidationRulesEnum::PositiveNumber if value <= Number::Int(0) => Err(ValidationErrorEnum::PositiveNumber(desc.to_string())),
+        ValidationRulesEnum::NonNegativeNumber if value < Number::Int(0) => Err(ValidationErrorEnum::NonNegativeNumber(desc.to_string())),
+        ValidationRulesEnum::PositiveNumber | ValidationRulesEnum::NonNegativeNumber => Ok(()),
+        ValidationRulesEnum::Integer => value
+            .as_integer()
+            .map(|_| ())
+            .ok_or_else(|| ValidationErrorEnum::Integer(desc.to_string())),
+        ValidationRulesEnum::DecimalScale(scale) => {
+            // f64 的字符串形式为最短表示，不会使用科学计数法
+            if let Number::Float(value) = value
+                && let Some(decimal_part) = value.to_string().split('.').nth(1)
+                && decimal_part.len() > scale as usize
+            {
+                return Err(ValidationErrorEnum::DecimalScale(desc.to_string(), scale));
+            }
+            Ok(())
+        },
+        ValidationRulesEnum::OddNumber => match value.as_integer() {
+            Some(num) if num % 2 != 0 => Ok(()),
+            Some(_) => Err(ValidationErrorEnum::OddNumber(desc.to_string())),
+            None => Err(ValidationErrorEnum::NumberFormatError),
+        },
+        ValidationRulesEnum::EvenNumber => match value.as_integer() {
+            Some(num) if num % 2 == 0 => Ok(()),
+            Some(_) => Err(ValidationErrorEnum::EvenNumber(desc.to_string())),
+            None => Err(ValidationErrorEnum::NumberFormatError),
+        },
+        ValidationRulesEnum::MultipleOf(multiple) => match value.as_integer() {
+            Some(num) if num.checked_rem(i128::from(multiple)) == Some(0) => Ok(()),
+            Some(_) => Err(ValidationErrorEnum::MultipleOf(desc.to_string(), multiple)),
+            None => Err(ValidationErrorEnum::NumberFormatError),
+        },
+        ValidationRulesEnum::Pattern(_) | ValidationRulesEnum::Email | ValidationRulesEnum::MobilePhone | ValidationRulesEnum::Url | ValidationRulesEnum::OneOf(_) => value.to_string().validate_rule(rule, desc),
+        ValidationRulesEnum::Length(_) | ValidationRulesEnum::LengthRange(..) | ValidationRulesEnum::ExistLength(_) | ValidationRulesEnum::ExistLengthRange(..) | ValidationRulesEnum::DateFormat(_) => Err(ValidationErrorEnum::UnsupportedType(type_name.to_string(), rule.name().to_string())),
+    }
+}
@@ -0,0 +1,322 @@
+//! # 字符串类型验证
+//!
+//! 数字验证规则先将字符串解析为 [Number] 再按数值验证
+
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex, PoisonError};
+
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use regex::Regex;
+
+use super::number::{Number, validate_number};
+use super::{Validate, ValidateFormat, ValidateLength, ValidateOneOf};
+use crate::{DateTimeFormatEnum, LengthLimit, ValidationErrorEnum, ValidationRulesEnum};
+
+/// 邮箱地址格式
+static EMAIL_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^[A-Za-z0-9._%+-]+@[A-Za-z0-9-]+(\.[A-Za-z0-9-]+)*\.[A-Za-z]{2,}$").unwrap());
+/// 中国大陆11位手机号码格式
+static MOBILE_PHONE_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^1[3-9]\d{9}$").unwrap());
+/// http、https、ftp 协议的URL格式
+static URL_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?i)^(https?|ftp)://[^\s/?#]+[^\s]*$").unwrap());
+/// 已编译的自定义正则表达式，避免每次验证都重新编译
+static PATTERN_CACHE: LazyLock<Mutex<HashMap<&'static str, Regex>>> = LazyLock::new(Default::default);
+
+impl Validate for str {
+    fn validate_rule(&self, rule: ValidationRulesEnum, desc: &str) -> Result<(), ValidationErrorEnum> {
+        match rule {
+            ValidationRulesEnum::NotNull => validate_not_null(self, desc),
+            ValidationRulesEnum::Length(len) => validate_length(self, len, desc),
+            ValidationRulesEnum::LengthRange(min, max) => validate_length_range(self, min, max, desc),
+            ValidationRulesEnum::ExistLength(len) => validate_exist_length(self, len, desc),
+            ValidationRulesEnum::ExistLengthRange(min, max) => validate_exist_length_range(self, min, max, desc),
+            ValidationRulesEnum::DateFormat(format) => validate_datetime(self, format, desc),
+            // 按字符串中的小数位数验证，保留末尾的0，如 "1.50" 为2位小数
+            ValidationRulesEnum::DecimalScale(scale) => validate_decimal_scale(self, scale, desc),
+            ValidationRulesEnum::NumberMin(_) | ValidationRulesEnum::NumberMax(_) | ValidationRulesEnum::PositiveNumber | ValidationRulesEnum::NonNegativeNumber | ValidationRulesEnum::Integer | ValidationRulesEnum::OddNumber | ValidationRulesEnum::EvenNumber | ValidationRulesEnum::MultipleOf(_) => {
+                validate_number(self.parse()?, "str", rule, desc)
+            },
+            ValidationRulesEnum::Pattern(pattern) => validate_pattern(self, pattern, desc),
+            ValidationRulesEnum::Email => validate_regex(self, &EMAIL_REGEX, ValidationErrorEnum::Email, desc),
+            ValidationRulesEnum::MobilePhone => validate_regex(self, &MOBILE_PHONE_REGEX, ValidationErrorEnum::MobilePhone, desc),
+            ValidationRulesEnum::Url => validate_regex(self, &URL_REGEX, ValidationErrorEnum::Url, desc),
+            ValidationRulesEnum::OneOf(values) => validate_one_of(self, values, desc),
+            // Nested规则不在此处处理，它应该在宏生成的代码中通过直接调用validate()处理
+            ValidationRulesEnum::Nested => Ok(()),
+        }
+    }
+}
+
+impl Validate for String {
+    fn validate_rule(&self, rule: ValidationRulesEnum, desc: &str) -> Result<(), ValidationErrorEnum> {
+        self.as_str().validate_rule(rule, desc)
+    }
+}
+
+/// 为字符串类型实现长度、格式、枚举值规则约束
+macro_rules! impl_text_bound {
+    ($($ty:ty),*) => {
+        $(
+            impl ValidateLength for $ty {}
+            impl ValidateFormat for $ty {}
+            impl ValidateOneOf for $ty {}
+        )*
+    };
+}
+
+impl_text_bound!(str, String);
+
+/// 验证非空
+///
+/// # 参数
+///
+/// * `value` - 要验证的值
+/// * `desc` - 参数描述
+///
+/// # 返回值
+///
+/// Ok(()) 如果验证通过，否则返回 ValidationErrorEnum
+///
+/// # 错误
+///
+/// ValidationErrorEnum::NotNull(desc) 如果值为空或为 "null" 或 "undefined"
+///
+/// # 注意
+///
+/// 这里的 "null" 和 "undefined" 是字符串形式的检查
+/// 例如: validate_not_null("undefined", "用户名") 返回 Err(ValidationErrorEnum::NotNull("用户名".to_string()))
+fn validate_not_null(value: &str, desc: &str) -> Result<(), ValidationErrorEnum> {
+    if value.trim().is_empty() || value.eq_ignore_ascii_case("null") || value.eq_ignore_ascii_case("undefined") {
+        Err(ValidationErrorEnum::NotNull(desc.to_string()))
+    } else {
+        Ok(())
+    }
+}
+
+/// 验证存在时的固定长度（只有当值不为空时才验证长度）
+///
+/// # 参数
+///
+/// * `value` - 要验证的值
+/// * `expected_len` - 期望的长度
+/// * `desc` - 参数描述
+///
+/// # 返回值
+///
+/// Ok(()) 如果验证通过，否则返回 ValidationErrorEnum
+///
+/// # 错误
+///
+/// ValidationErrorEnum::Length(desc, LengthLimit::Chars(expected_len)) 如果长度不符合预期
+fn validate_exist_length(value: &str, expected_len: usize, desc: &str) -> Result<(), ValidationErrorEnum> {
+    if !value.is_empty() { validate_length(value, expected_len, desc) } else { Ok(()) }
+}
+
+/// 验证存在时的长度范围（只有当值不为空时才验证长度范围）
+///
+/// # 参数
+///
+/// * `value` - 要验证的值
+/// * `min` - 最小长度
+/// * `max` - 最大长度
+/// * `desc` - 参数描述
+///
+/// # 返回值
+///
+/// Ok(()) 如果验证通过，否则返回 ValidationErrorEnum
+///
+/// # 错误
+///
+/// ValidationErrorEnum::Length(desc, LengthLimit::CharsRange(min, max))
+fn validate_exist_length_range(value: &str, min: usize, max: usize, desc: &str) -> Result<(), ValidationErrorEnum> {
+    if !value.is_empty() { validate_length_range(value, min, max, desc) } else { Ok(()) }
+}
+
+/// 验证固定长度
+///
+/// # 参数
+///
+/// * `value` - 要验证的值
+/// * `expected_len` - 期望的长度
+/// * `desc` - 参数描述
+///
+/// # 返回值
+///
+/// Ok(()) 如果验证通过，否则返回 ValidationErrorEnum
+///
+/// # 错误
+///
+/// ValidationErrorEnum::Length(desc, LengthLimit::Chars(expected_len)) 如果长度不符合预期
+///
+/// # 示例
+///
+/// validate_length("abc", 3, "用户名") 返回 Ok(())
+///
+/// # 注意
+///
+/// 如果 value 的长度不等于 expected_len，则返回错误
+fn validate_length(value: &str, expected_len: usize, desc: &str) -> Result<(), ValidationErrorEnum> {
+    // 按字符数计算长度，与数据库 varchar(N) 的长度语义一致
+    let value_len = value.chars().count();
+    if value_len != expected_len {
+        return Err(ValidationErrorEnum::Length(desc.to_string(), LengthLimit::Chars(expected_len)));
+    }
+    Ok(())
+}
+
+/// 验证长度范围
+///
+/// # 参数
+///
+/// * `value` - 要验证的值
+/// * `min` - 最小长度
+/// * `max` - 最大长度
+/// * `desc` - 参数描述
+///
+/// # 返回值
+///
+/// Ok(()) 如果验证通过，否则返回 ValidationErrorEnum
+///
+/// # 错误
+///
+/// ValidationErrorEnum::Length(desc, LengthLimit::CharsRange(min, max))
+fn validate_length_range(value: &str, min: usize, max: usize, desc: &str) -> Result<(), ValidationErrorEnum> {
+    // 按字符数计算长度，与数据库 varchar(N) 的长度语义一致
+    let value_len = value.chars().count();
+    if value_len < min || value_len > max {
+        return Err(ValidationErrorEnum::Length(desc.to_string(), LengthLimit::CharsRange(min, max)));
+    }
+    Ok(())
+}
+
+/// 验证日期时间格式
+///
+/// # 参数
+///
+/// * `value` - 要验证的值
+/// * `format` - 日期时间格式枚举
+/// * `desc` - 参数描述
+///
+/// # 返回值
+///
+/// Ok(()) 如果验证通过，否则返回 ValidationErrorEnum
+///
+/// # 错误
+///
+/// ValidationErrorEnum::Format(desc) 如果格式不正确
+fn validate_datetime(value: &str, format: DateTimeFormatEnum, desc: &str) -> Result<(), ValidationErrorEnum> {
+    match format {
+        DateTimeFormatEnum::Time => {
+            NaiveTime::parse_from_str(value, format.pattern().unwrap()).map_err(|_| ValidationErrorEnum::Format(desc.to_string()))?;
+        },
+        DateTimeFormatEnum::Year | DateTimeFormatEnum::YearNoSplit => {
+            NaiveDate::parse_from_str(value, format.pattern().unwrap()).map_err(|_| ValidationErrorEnum::Format(desc.to_string()))?;
+        },
+        _ => {
+            NaiveDateTime::parse_from_str(value, format.pattern().unwrap()).map_err(|_| ValidationErrorEnum::Format(desc.to_string()))?;
+        },
+    }
+    Ok(())
+}
+
+/// 验证小数位数
+///
+/// # 参数
+///
+/// * `value` - 要验证的值
+/// * `scale` - 最大小数位数
+/// * `desc` - 参数描述
+///
+/// # 返回值
+///
+/// Ok(()) 如果验证通过，否则返回 ValidationErrorEnum
+///
+/// # 错误
+///
+/// ValidationErrorEnum::DecimalScale(desc, scale) 如果小数位数超过限制
+/// ValidationErrorEnum::NumberFormatError 如果不是有效数字
+fn validate_decimal_scale(value: &str, scale: u32, desc: &str) -> Result<(), ValidationErrorEnum> {
+    // 检查是否是有效数字
+    value.parse::<Number>()?;
+
+    // 检查小数位数
+    if let Some(decimal_part) = value.split('.').nth(1)
+        && decimal_part.len() > scale as usize
+    {
+        return Err(ValidationErrorEnum::DecimalScale(desc.to_string(), scale));
+    }
+    Ok(())
+}
+
+/// 验证正则表达式（只有当值不为空时才验证）
+///
+/// # 参数
+///
+/// * `value` - 要验证的值
+/// * `pattern` - 正则表达式，值必须完整匹配
+/// * `desc` - 参数描述
+///
+/// # 返回值
+///
+/// Ok(()) 如果验证通过，否则返回 ValidationErrorEnum
+///
+/// # 错误
+///
+/// ValidationErrorEnum::Pattern(desc) 如果值不匹配正则表达式
+/// ValidationErrorEnum::PatternError 如果正则表达式本身有误
+fn validate_pattern(value: &str, pattern: &'static str, desc: &str) -> Result<(), ValidationErrorEnum> {
+    if value.is_empty() {
+        return Ok(());
+    }
+
+    let regex = {
+        let mut cache = PATTERN_CACHE.lock().unwrap_or_else(PoisonError::into_inner);
+        match cache.get(pattern) {
+            Some(regex) => regex.clone(),
+            None => {
+                // 包裹为完整匹配，避免只匹配到值的一部分
+                let regex = Regex::new(&format!("^(?:{pattern})$")).map_err(|e| ValidationErrorEnum::PatternError(e.to_string()))?;
+                cache.insert(pattern, regex.clone());
+                regex
+            },
+        }
+    };
+    validate_regex(value, &regex, ValidationErrorEnum::Pattern, desc)
+}
+
+/// 按内置格式验证（只有当值不为空时才验证）
+///
+/// # 参数
+///
+/// * `value` - 要验证的值
+/// * `regex` - 格式对应的正则表达式
+/// * `error` - 构造验证错误的函数，如 ValidationErrorEnum::Email
+/// * `desc` - 参数描述
+///
+/// # 返回值
+///
+/// Ok(()) 如果验证通过，否则返回 error(desc)
+fn validate_regex(value: &str, regex: &Regex, error: fn(String) -> ValidationErrorEnum, desc: &str) -> Result<(), ValidationErrorEnum> {
+    if value.is_empty() || regex.is_match(value) { Ok(()) } else { Err(error(desc.to_string())) }
+}
+
+/// 验证枚举值（只有当值不为空时才验证）
+///
+/// # 参数
+///
+/// * `value` - 要验证的值
+/// * `values` - 可选值列表
+/// * `desc` - 参数描述
+///
+/// # 返回值
+///
+/// Ok(()) 如果验证通过，否则返回 ValidationErrorEnum
+///
+/// # 错误
+///
+/// ValidationErrorEnum::OneOf(desc, values) 如果值不是可选值之一
+fn validate_one_of(value: &str, values: &[&str], desc: &str) -> Result<(), ValidationErrorEnum> {
+    if value.is_empty() || values.contains(&value) {
+        return Ok(());
+    }
+    Err(ValidationErrorEnum::OneOf(desc.to_string(), values.join("、")))
+}
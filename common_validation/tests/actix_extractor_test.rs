@@ -0,0 +1,118 @@
+//! Actix Web 提取器测试
+//!
+//! 使用 Actix Web 提供的测试工具调用使用 [ValidatedJson] 的处理函数，验证请求体被按分组验证，需要启用 `actix_extractor` 特性：
+//! `cargo test -p common_validation --features actix_extractor --test actix_extractor_test`
+
+#![cfg(feature = "actix_extractor")]
+
+use actix_web::http::StatusCode;
+use actix_web::test::{self, TestRequest};
+use actix_web::{App, web};
+use common_validation::{CreateGroup, UpdateGroup, ValidatedJson};
+use common_validation_macros::ValidatableImpl;
+use common_wrapper::ResponseWrapper;
+use serde::Deserialize;
+use serde_json::{Value, json};
+
+/// 测试参数
+#[derive(Deserialize, ValidatableImpl)]
+#[group_fields(create = ["name"], update = ["id", "name"])]
+struct TestParam {
+    #[validate(not_null, desc = "ID")]
+    id: Option<String>,
+
+    #[validate(not_null, length_range(min = 1, max = 5), desc = "名称")]
+    name: Option<String>,
+}
+
+/// 按创建分组验证
+async fn add(param: ValidatedJson<TestParam, CreateGroup>) -> ResponseWrapper {
+    ResponseWrapper::new(1, param.into_inner().name.unwrap_or_default())
+}
+
+/// 按更新分组验证
+async fn edit(param: ValidatedJson<TestParam, UpdateGroup>) -> ResponseWrapper {
+    ResponseWrapper::new(1, param.into_inner().id.unwrap_or_default())
+}
+
+/// 发送POST请求，返回响应状态码和JSON响应体
+async fn post_json(uri: &str, body: String) -> (StatusCode, Value) {
+    post_json_with_language(uri, body, "zh-CN").await
+}
+
+/// 按指定的 `Accept-Language` 发送POST请求，返回响应状态码和JSON响应体
+async fn post_json_with_language(uri: &str, body: String, language: &str) -> (StatusCode, Value) {
+    let app = test::init_service(
+        App::new()
+            .route("/add", web::post().to(add))
+            .route("/edit", web::post().to(edit)),
+    )
+    .await;
+    let req = TestRequest::post()
+        .uri(uri)
+        .insert_header(("content-type", "application/json"))
+        .insert_header(("accept-language", language))
+        .set_payload(body)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    let status = resp.status();
+    (status, test::read_body_json(resp).await)
+}
+
+/// 测试验证通过
+#[actix_web::test]
+async fn test_valid() {
+    let (status, body) = post_json("/add", json!({ "name": "研发部" }).to_string()).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["message"], "研发部");
+
+    let (status, body) = post_json("/edit", json!({ "id": "1", "name": "研发部" }).to_string()).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["message"], "1");
+}
+
+/// 测试验证失败
+#[actix_web::test]
+async fn test_invalid() {
+    let (status, body) = post_json("/add", json!({ "name": "研发部研发部" }).to_string()).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["code"], -3);
+    assert_eq!(body["message"], "名称 长度不符合要求: 必须在 1~5 个字符之间");
+
+    // 创建分组不验证ID，更新分组要求ID不能为空
+    let (status, body) = post_json("/edit", json!({ "name": "研发部" }).to_string()).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["message"], "ID 不能为空");
+    assert_eq!(body["data"]["id"][0]["rule"], "not_null");
+
+    // 收集分组内全部字段的验证错误，以字段路径为键返回
+    let (status, body) = post_json("/edit", json!({ "name": "研发部研发部" }).to_string()).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["message"], "ID 不能为空; 名称 长度不符合要求: 必须在 1~5 个字符之间");
+    assert_eq!(body["data"]["id"][0]["message"], "ID 不能为空");
+    assert_eq!(body["data"]["name"][0]["rule"], "length");
+}
+
+/// 测试请求体格式错误
+#[actix_web::test]
+async fn test_malformed_body() {
+    let (status, body) = post_json("/add", "{".to_string()).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["code"], -3);
+    assert!(
+        body["message"]
+            .as_str()
+            .unwrap()
+            .starts_with("请求体格式错误")
+    );
+    assert!(body["data"].is_null());
+}
+
+/// 测试按 `Accept-Language` 返回英文错误信息
+#[actix_web::test]
+async fn test_invalid_en_us() {
+    let (status, body) = post_json_with_language("/edit", json!({ "name": "研发部" }).to_string(), "en-US").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["message"], "id must not be empty");
+    assert_eq!(body["data"]["id"][0]["message"], "id must not be empty");
+}
@@ -0,0 +1,118 @@
+//! Axum 提取器测试
+//!
+//! 通过Axum路由调用使用 [ValidatedJson] 的处理函数，验证请求体被按分组验证，需要启用 `axum_extractor` 特性：
+//! `cargo test -p common_validation --features axum_extractor --test axum_extractor_test`
+
+#![cfg(feature = "axum_extractor")]
+
+use axum::body::{Body, to_bytes};
+use axum::http::{Request, StatusCode, header};
+use axum::{Router, routing::post};
+use common_validation::{CreateGroup, UpdateGroup, ValidatedJson};
+use common_validation_macros::ValidatableImpl;
+use common_wrapper::ResponseWrapper;
+use serde::Deserialize;
+use serde_json::{Value, json};
+use tower::ServiceExt;
+
+/// 测试参数
+#[derive(Deserialize, ValidatableImpl)]
+#[group_fields(create = ["name"], update = ["id", "name"])]
+struct TestParam {
+    #[validate(not_null, desc = "ID")]
+    id: Option<String>,
+
+    #[validate(not_null, length_range(min = 1, max = 5), desc = "名称")]
+    name: Option<String>,
+}
+
+/// 创建测试路由，新增和编辑分别按创建分组和更新分组验证
+fn app() -> Router {
+    Router::new()
+        .route("/add", post(|ValidatedJson(param, _): ValidatedJson<TestParam, CreateGroup>| async move { ResponseWrapper::new(1, param.name.unwrap_or_default()) }))
+        .route("/edit", post(|ValidatedJson(param, _): ValidatedJson<TestParam, UpdateGroup>| async move { ResponseWrapper::new(1, param.id.unwrap_or_default()) }))
+}
+
+/// 发送POST请求，返回响应状态码和JSON响应体
+async fn post_json(uri: &str, body: String) -> (StatusCode, Value) {
+    post_json_with_language(uri, body, "zh-CN").await
+}
+
+/// 按指定的 `Accept-Language` 发送POST请求，返回响应状态码和JSON响应体
+async fn post_json_with_language(uri: &str, body: String, language: &str) -> (StatusCode, Value) {
+    let request = Request::post(uri)
+        .header(header::CONTENT_TYPE, "application/json")
+        .header(header::ACCEPT_LANGUAGE, language)
+        .body(Body::from(body))
+        .unwrap();
+    let response = app().oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&bytes).unwrap())
+}
+
+/// 测试验证通过
+#[tokio::test]
+async fn test_valid() {
+    let (status, body) = post_json("/add", json!({ "name": "研发部" }).to_string()).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["message"], "研发部");
+
+    let (status, body) = post_json("/edit", json!({ "id": "1", "name": "研发部" }).to_string()).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["message"], "1");
+}
+
+/// 测试验证失败
+#[tokio::test]
+async fn test_invalid() {
+    let (status, body) = post_json("/add", json!({ "name": "研发部研发部" }).to_string()).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["code"], -3);
+    assert_eq!(body["message"], "名称 长度不符合要求: 必须在 1~5 个字符之间");
+
+    // 创建分组不验证ID，更新分组要求ID不能为空
+    let (status, body) = post_json("/edit", json!({ "name": "研发部" }).to_string()).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["message"], "ID 不能为空");
+    assert_eq!(body["data"]["id"][0]["rule"], "not_null");
+
+    // 收集分组内全部字段的验证错误，以字段路径为键返回
+    let (status, body) = post_json("/edit", json!({ "name": "研发部研发部" }).to_string()).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["message"], "ID 不能为空; 名称 长度不符合要求: 必须在 1~5 个字符之间");
+    assert_eq!(body["data"]["id"][0]["message"], "ID 不能为空");
+    assert_eq!(body["data"]["name"][0]["rule"], "length");
+}
+
+/// 测试请求体格式错误
+#[tokio::test]
+async fn test_malformed_body() {
+    let (status, body) = post_json("/add", "{".to_string()).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["code"], -3);
+    assert!(
+        body["message"]
+            .as_str()
+            .unwrap()
+            .starts_with("请求体格式错误")
+    );
+    assert!(body["data"].is_null());
+}
+
+/// 测试按 `Accept-Language` 返回英文错误信息
+#[tokio::test]
+async fn test_invalid_en_us() {
+    let (status, body) = post_json_with_language("/edit", json!({ "name": "研发部研发部" }).to_string(), "en-US,en;q=0.9").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["message"], "id must not be empty; name has an invalid length: must be between 1 and 5 characters");
+    assert_eq!(body["data"]["id"][0]["message"], "id must not be empty");
+
+    let (_, body) = post_json_with_language("/add", "{".to_string(), "en").await;
+    assert!(
+        body["message"]
+            .as_str()
+            .unwrap()
+            .starts_with("Invalid request body")
+    );
+}
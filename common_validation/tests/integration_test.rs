@@ -1,4 +1,8 @@
-use common_validation::{DateTimeFormatEnum, ParameterValidator, Validatable, ValidationErrorEnum, ValidationRule};
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use common_validation::{DateTimeFormatEnum, LengthLimit, Number, ParameterValidator, Validatable, ValidationErrorEnum, ValidationErrors, ValidationRule};
+use common_wrapper::i18n::{Locale, Localize, MessageCatalog};
 
 #[derive(Debug)]
 struct User {
@@ -153,3 +157,174 @@ fn birthdate_wrong_format_should_fail() {
         println!("错误格式生日验证失败(符合预期): {}", e);
     }
 }
+
+/// 测试收集模式返回值的全部未通过规则
+#[test]
+fn validate_value_all_test() {
+    let rule = ValidationRule::new("用户名")
+        .not_null()
+        .length_range(3, 5)
+        .min(1);
+
+    // 长度和数值规则同时未通过
+    let errors = ParameterValidator::validate_value_all("abcdef", &rule);
+    assert_eq!(errors.len(), 2);
+    assert!(matches!(errors[0], ValidationErrorEnum::Length(_, _)));
+    assert_eq!(errors[1], ValidationErrorEnum::NumberFormatError);
+
+    // 非空验证未通过时不再验证其余规则
+    let errors = ParameterValidator::validate_value_all("", &rule);
+    assert_eq!(errors, vec![ValidationErrorEnum::NotNull("用户名".to_string())]);
+
+    assert!(ParameterValidator::validate_value_all("abc", &ValidationRule::new("用户名").length_range(3, 5)).is_empty());
+}
+
+/// 测试验证错误集合的合并与序列化
+#[test]
+fn validation_errors_test() {
+    let mut address_errors = ValidationErrors::new();
+    address_errors.add("city", ValidationErrorEnum::NotNull("城市".to_string()));
+
+    let mut errors = ValidationErrors::new();
+    errors.add("name", ValidationErrorEnum::NotNull("名称".to_string()));
+    errors.add("name", ValidationErrorEnum::Length("名称".to_string(), LengthLimit::CharsRange(1, 5)));
+    errors.merge("address", address_errors.clone());
+    errors.merge("items[0]", address_errors);
+    assert_eq!(errors.len(), 4);
+    assert_eq!(errors.get("name").map(<[_]>::len), Some(2));
+    assert_eq!(errors.get("address.city").unwrap()[0].rule, "not_null");
+    assert!(errors.get("items[0].city").is_some());
+    assert_eq!(errors.to_string(), "名称 不能为空; 名称 长度不符合要求: 必须在 1~5 个字符之间; 城市 不能为空; 城市 不能为空");
+
+    // 序列化为以字段路径为键的结构
+    let json = serde_json::to_value(&errors).unwrap();
+    assert_eq!(json["name"][0]["rule"], "not_null");
+    assert_eq!(json["name"][0]["message"], "名称 不能为空");
+    assert_eq!(json["name"][1]["rule"], "length");
+    assert_eq!(json["address.city"][0]["message"], "城市 不能为空");
+
+    // 转换错误信息的语言，简体中文以外的语言用字段路径代替字段描述
+    errors.localize(Locale::EnUs);
+    assert_eq!(errors.get("name").unwrap()[1].message, "name has an invalid length: must be between 1 and 5 characters");
+    assert_eq!(errors.get("address.city").unwrap()[0].message, "address.city must not be empty");
+    errors.localize(Locale::ZhCn);
+    assert_eq!(errors.get("name").unwrap()[1].message, "名称 长度不符合要求: 必须在 1~5 个字符之间");
+
+    // 没有错误时返回Ok(())
+    assert!(ValidationErrors::new().into_result().is_ok());
+    assert!(errors.into_result().is_err());
+}
+
+/// 测试正则、邮箱、手机号、URL、枚举值验证
+#[test]
+fn format_rules_test() {
+    let rule = ValidationRule::new("邮箱").email();
+    assert!(ParameterValidator::validate_value("dept@example.com", &rule).is_ok());
+    assert!(ParameterValidator::validate_value("first.last+tag@mail.example.com.cn", &rule).is_ok());
+    assert_eq!(ParameterValidator::validate_value("dept@example", &rule), Err(ValidationErrorEnum::Email("邮箱".to_string())));
+    // 空值不验证格式，由非空规则负责
+    assert!(ParameterValidator::validate_value("", &rule).is_ok());
+
+    let rule = ValidationRule::new("手机号").mobile_phone();
+    assert!(ParameterValidator::validate_value("13800000000", &rule).is_ok());
+    assert!(ParameterValidator::validate_value("12800000000", &rule).is_err());
+    assert!(ParameterValidator::validate_value("1380000000", &rule).is_err());
+
+    let rule = ValidationRule::new("地址").url();
+    assert!(ParameterValidator::validate_value("https://example.com/logo.png", &rule).is_ok());
+    assert!(ParameterValidator::validate_value("FTP://files.example.com", &rule).is_ok());
+    assert_eq!(ParameterValidator::validate_value("example.com/logo.png", &rule), Err(ValidationErrorEnum::Url("地址".to_string())));
+
+    let rule = ValidationRule::new("编码").pattern(r"[A-Z]{2}\d{4}");
+    assert!(ParameterValidator::validate_value("AB1234", &rule).is_ok());
+    assert_eq!(ParameterValidator::validate_value("xAB1234", &rule), Err(ValidationErrorEnum::Pattern("编码".to_string())));
+    assert!(matches!(ParameterValidator::validate_value("AB1234", &ValidationRule::new("编码").pattern("[A-Z")), Err(ValidationErrorEnum::PatternError(_))));
+
+    let rule = ValidationRule::new("状态").one_of(&["0", "1"]);
+    assert!(ParameterValidator::validate_value("1", &rule).is_ok());
+    assert_eq!(ParameterValidator::validate_value("2", &rule), Err(ValidationErrorEnum::OneOf("状态".to_string(), "0、1".to_string())));
+    assert_eq!(ValidationErrorEnum::OneOf("状态".to_string(), "0、1".to_string()).rule(), "one_of");
+}
+
+/// 测试按值的原生类型验证
+#[test]
+fn typed_validate_test() {
+    // 整数按原生类型比较，不再经过 i64 解析
+    let rule = ValidationRule::new("编号").min(1).max(u64::MAX);
+    assert!(ParameterValidator::validate(&u64::MAX, &rule).is_ok());
+    assert_eq!(ParameterValidator::validate(&0u64, &rule), Err(ValidationErrorEnum::NumberMin("编号".to_string(), 1.into())));
+
+    // 浮点数边界，整数与浮点数可以互相比较
+    let rule = ValidationRule::new("比例").number_range(0.5, 1);
+    assert!(ParameterValidator::validate(&0.75f64, &rule).is_ok());
+    assert!(ParameterValidator::validate(&1i32, &rule).is_ok());
+    assert!(ParameterValidator::validate(&0.4f32, &rule).is_err());
+    assert!(ParameterValidator::validate_value("0.5", &rule).is_ok());
+    assert_eq!(Number::from(2), Number::from(2.0));
+
+    // 浮点数的奇偶、倍数规则要求值为整数
+    assert!(ParameterValidator::validate(&3.0f64, &ValidationRule::new("数量").odd_number()).is_ok());
+    assert_eq!(ParameterValidator::validate(&3.5f64, &ValidationRule::new("数量").odd_number()), Err(ValidationErrorEnum::NumberFormatError));
+    assert_eq!(ParameterValidator::validate(&1.234f64, &ValidationRule::new("金额").decimal_scale(2)), Err(ValidationErrorEnum::DecimalScale("金额".to_string(), 2)));
+
+    // 数字按字符串形式验证格式规则，不支持长度规则
+    assert!(ParameterValidator::validate(&13800000000u64, &ValidationRule::new("手机号").mobile_phone()).is_ok());
+    assert_eq!(ParameterValidator::validate(&1i32, &ValidationRule::new("状态").length(1)), Err(ValidationErrorEnum::UnsupportedType("i32".to_string(), "length".to_string())));
+
+    // Option 为 None 时只验证非空
+    let rule = ValidationRule::new("排序").max(10);
+    assert!(ParameterValidator::validate(&None::<i32>, &rule).is_ok());
+    assert!(ParameterValidator::validate(&Some(11), &rule).is_err());
+    assert_eq!(ParameterValidator::validate_all(&None::<i32>, &rule.clone().not_null()), vec![ValidationErrorEnum::NotNull("排序".to_string())]);
+
+    // 集合的长度规则按元素个数验证，其余规则作用于每个元素
+    let rule = ValidationRule::new("标签")
+        .not_null()
+        .length_range(1, 2)
+        .email();
+    assert!(ParameterValidator::validate(&vec!["a@example.com"], &rule).is_ok());
+    assert_eq!(ParameterValidator::validate(&Vec::<String>::new(), &rule), Err(ValidationErrorEnum::NotNull("标签".to_string())));
+    assert!(matches!(ParameterValidator::validate(&vec!["a@example.com"; 3], &rule), Err(ValidationErrorEnum::Length(_, _))));
+    assert_eq!(ParameterValidator::validate(&vec!["a@example.com", "b"], &rule), Err(ValidationErrorEnum::Email("标签".to_string())));
+    let scores = HashMap::from([("语文", 90), ("数学", 101)]);
+    assert_eq!(ParameterValidator::validate(&scores, &ValidationRule::new("成绩").max(100)), Err(ValidationErrorEnum::NumberMax("成绩".to_string(), 100.into())));
+
+    // 日期时间类型已由反序列化解析，日期格式规则总是通过
+    let date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    assert!(
+        ParameterValidator::validate(
+            &date,
+            &ValidationRule::new("日期")
+                .not_null()
+                .date_format(DateTimeFormatEnum::Year)
+        )
+        .is_ok()
+    );
+    assert!(matches!(ParameterValidator::validate(&date, &ValidationRule::new("日期").min(1)), Err(ValidationErrorEnum::UnsupportedType(_, rule)) if rule == "min"));
+}
+
+/// 测试验证错误的多语言错误信息
+#[test]
+fn localize_test() {
+    let error = ValidationErrorEnum::NumberMin("age".to_string(), Number::Int(18));
+    assert_eq!(error.code(), "VALIDATION_NUMBER_MIN");
+    assert_eq!(error.localize(Locale::ZhCn), error.to_string());
+    assert_eq!(error.localize(Locale::EnUs), "age must not be less than 18");
+    assert_eq!(ValidationErrorEnum::Length("tags".to_string(), LengthLimit::Items(2)).localize(Locale::EnUs), "tags has an invalid length: must have 2 items");
+    // 自定义错误信息原样输出
+    assert_eq!(ValidationErrorEnum::Custom("编码已存在".to_string()).localize(Locale::EnUs), "编码已存在");
+
+    // 消息目录中的自定义错误信息按语言输出
+    static MESSAGES: MessageCatalog = MessageCatalog::new(&[("CODE_EXISTS", "编码已存在")], &[("CODE_EXISTS", "The code already exists")]);
+    let error = ValidationErrorEnum::Message(&MESSAGES, "CODE_EXISTS");
+    assert_eq!(error.rule(), "custom");
+    assert_eq!(error.to_string(), "编码已存在");
+    assert_eq!(error.localize(Locale::EnUs), "The code already exists");
+
+    // 字段的错误信息在简体中文以外的语言中使用字段路径，参数不是字段描述的错误不受影响
+    let error = ValidationErrorEnum::NotNull("部门名称".to_string());
+    assert_eq!(error.localize_field(Locale::ZhCn, "name"), "部门名称 不能为空");
+    assert_eq!(error.localize_field(Locale::EnUs, "name"), "name must not be empty");
+    assert_eq!(error.localize_field(Locale::EnUs, ""), "部门名称 must not be empty");
+    assert_eq!(ValidationErrorEnum::Custom("编码已存在".to_string()).localize_field(Locale::EnUs, "code"), "编码已存在");
+}
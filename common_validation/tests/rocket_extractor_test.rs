@@ -0,0 +1,117 @@
+//! Rocket 提取器测试
+//!
+//! 使用 Rocket 提供的本地客户端调用使用 [ValidatedJson] 的路由，验证请求体被按分组验证，需要启用 `rocket_extractor` 特性：
+//! `cargo test -p common_validation --features rocket_extractor --test rocket_extractor_test`
+
+#![cfg(feature = "rocket_extractor")]
+
+use common_validation::extract::rocket_extractor;
+use common_validation::{CreateGroup, UpdateGroup, ValidatedJson};
+use common_validation_macros::ValidatableImpl;
+use common_wrapper::ResponseWrapper;
+use rocket::http::{ContentType, Header, Status};
+use rocket::local::blocking::Client;
+use serde::Deserialize;
+use serde_json::{Value, json};
+
+/// 测试参数
+#[derive(Deserialize, ValidatableImpl)]
+#[group_fields(create = ["name"], update = ["id", "name"])]
+struct TestParam {
+    #[validate(not_null, desc = "ID")]
+    id: Option<String>,
+
+    #[validate(not_null, length_range(min = 1, max = 5), desc = "名称")]
+    name: Option<String>,
+}
+
+/// 按创建分组验证
+#[rocket::post("/add", data = "<param>")]
+fn add(param: ValidatedJson<TestParam, CreateGroup>) -> ResponseWrapper {
+    ResponseWrapper::new(1, param.into_inner().name.unwrap_or_default())
+}
+
+/// 按更新分组验证
+#[rocket::post("/edit", data = "<param>")]
+fn edit(param: ValidatedJson<TestParam, UpdateGroup>) -> ResponseWrapper {
+    ResponseWrapper::new(1, param.into_inner().id.unwrap_or_default())
+}
+
+/// 发送POST请求，返回响应状态码和JSON响应体
+fn post_json(uri: &str, body: String) -> (Status, Value) {
+    post_json_with_language(uri, body, "zh-CN")
+}
+
+/// 按指定的 `Accept-Language` 发送POST请求，返回响应状态码和JSON响应体
+fn post_json_with_language(uri: &str, body: String, language: &str) -> (Status, Value) {
+    let rocket = rocket::build()
+        .mount("/", rocket::routes![add, edit])
+        .register("/", rocket_extractor::catchers());
+    let client = Client::tracked(rocket).expect("valid rocket instance");
+    let response = client
+        .post(uri)
+        .header(ContentType::JSON)
+        .header(Header::new("Accept-Language", language.to_string()))
+        .body(body)
+        .dispatch();
+    let status = response.status();
+    (status, response.into_json().unwrap())
+}
+
+/// 测试验证通过
+#[test]
+fn test_valid() {
+    let (status, body) = post_json("/add", json!({ "name": "研发部" }).to_string());
+    assert_eq!(status, Status::Ok);
+    assert_eq!(body["message"], "研发部");
+
+    let (status, body) = post_json("/edit", json!({ "id": "1", "name": "研发部" }).to_string());
+    assert_eq!(status, Status::Ok);
+    assert_eq!(body["message"], "1");
+}
+
+/// 测试验证失败
+#[test]
+fn test_invalid() {
+    let (status, body) = post_json("/add", json!({ "name": "研发部研发部" }).to_string());
+    assert_eq!(status, Status::BadRequest);
+    assert_eq!(body["code"], -3);
+    assert_eq!(body["message"], "名称 长度不符合要求: 必须在 1~5 个字符之间");
+
+    // 创建分组不验证ID，更新分组要求ID不能为空
+    let (status, body) = post_json("/edit", json!({ "name": "研发部" }).to_string());
+    assert_eq!(status, Status::BadRequest);
+    assert_eq!(body["message"], "ID 不能为空");
+    assert_eq!(body["data"]["id"][0]["rule"], "not_null");
+
+    // 收集分组内全部字段的验证错误，以字段路径为键返回
+    let (status, body) = post_json("/edit", json!({ "name": "研发部研发部" }).to_string());
+    assert_eq!(status, Status::BadRequest);
+    assert_eq!(body["message"], "ID 不能为空; 名称 长度不符合要求: 必须在 1~5 个字符之间");
+    assert_eq!(body["data"]["id"][0]["message"], "ID 不能为空");
+    assert_eq!(body["data"]["name"][0]["rule"], "length");
+}
+
+/// 测试请求体格式错误
+#[test]
+fn test_malformed_body() {
+    let (status, body) = post_json("/add", "{".to_string());
+    assert_eq!(status, Status::BadRequest);
+    assert_eq!(body["code"], -3);
+    assert!(
+        body["message"]
+            .as_str()
+            .unwrap()
+            .starts_with("请求体格式错误")
+    );
+    assert!(body["data"].is_null());
+}
+
+/// 测试按 `Accept-Language` 返回英文错误信息
+#[test]
+fn test_invalid_en_us() {
+    let (status, body) = post_json_with_language("/edit", json!({ "name": "研发部" }).to_string(), "en-US");
+    assert_eq!(status, Status::BadRequest);
+    assert_eq!(body["message"], "id must not be empty");
+    assert_eq!(body["data"]["id"][0]["message"], "id must not be empty");
+}
@@ -8,7 +8,9 @@
 //! - 支持多种验证属性（not_null、length、date_format等）
 //! - 自动处理嵌套结构体的验证
 //! - 支持Option和Vec类型的验证
-//! - 支持集中管理分组验证
+//! - 支持集中管理分组验证，分组名称可以自定义，按未定义的分组验证时编译报错
+//! - 支持通过上下文访问外部服务的异步验证
+//! - 未知的验证属性、与字段类型不匹配的规则、分组中不存在的字段给出编译错误
 //!
 //! ## 使用示例
 //!
@@ -50,7 +52,8 @@
 //! ```
 
 use proc_macro::TokenStream;
-use quote::quote;
+use quote::{quote, quote_spanned};
+use syn::spanned::Spanned;
 use syn::{Data, DeriveInput, Fields, GenericArgument, LitInt, LitStr, PathArguments, Type, parse_macro_input};
 
 /// 判断给定类型是否匹配目标类型名称
@@ -104,7 +107,48 @@ fn is_number_type(ty: &Type) -> bool {
         return false;
     };
 
-    matches!(segment.ident.to_string().as_str(), "i8" | "i16" | "i32" | "i64" | "i128" | "isize" | "u8" | "u16" | "u32" | "u64" | "usize" | "f32" | "f64")
+    matches!(segment.ident.to_string().as_str(), "i8" | "i16" | "i32" | "i64" | "i128" | "isize" | "u8" | "u16" | "u32" | "u64" | "u128" | "usize" | "f32" | "f64")
+}
+
+/// 验证规则要求字段类型实现的约束
+///
+/// 派生宏为字段生成 `assert::<字段类型>()` 形式的约束检查，字段类型不支持规则时在编译期报错，
+/// 错误指向字段类型，错误信息由 `common_validation` 中各约束trait的 `on_unimplemented` 给出
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum RuleBound {
+    /// 长度规则，`common_validation::ValidateLength`
+    Length,
+    /// 数值规则，`common_validation::ValidateNumber`
+    Number,
+    /// 格式规则，`common_validation::ValidateFormat`
+    Format,
+    /// 枚举值规则，`common_validation::ValidateOneOf`
+    OneOf,
+}
+
+impl RuleBound {
+    /// 约束trait的路径
+    fn path(self) -> proc_macro2::TokenStream {
+        match self {
+            RuleBound::Length => quote! { common_validation::ValidateLength },
+            RuleBound::Number => quote! { common_validation::ValidateNumber },
+            RuleBound::Format => quote! { common_validation::ValidateFormat },
+            RuleBound::OneOf => quote! { common_validation::ValidateOneOf },
+        }
+    }
+}
+
+/// 判断字段值是否为数字类型，用于确定 `min`、`max` 是数值边界还是长度边界
+///
+/// 引用（`&T`、`&'a T`）及 `Box<T>` 按内部类型 `T` 判断，类型别名等其他写法按长度边界处理
+fn is_number_value(ty: &Type) -> bool {
+    match ty {
+        Type::Reference(reference) => is_number_value(&reference.elem),
+        Type::Paren(paren) => is_number_value(&paren.elem),
+        Type::Group(group) => is_number_value(&group.elem),
+        Type::Path(_) if is_type_of(ty, "Box") => extract_inner_type(ty).is_some_and(|inner| is_number_value(&inner)),
+        _ => is_number_type(ty),
+    }
 }
 
 /// 从泛型类型中提取内部类型参数
@@ -161,109 +205,318 @@ fn extract_inner_type(ty: &Type) -> Option<Type> {
     Some(inner_ty.clone())
 }
 
-/// 分组字段配置结构
-#[derive(Default)]
+/// 解析 `length_range(min = x, max = y)` 形式的最小值、最大值，`min`、`max` 必须同时指定
+fn parse_min_max(meta: &syn::meta::ParseNestedMeta) -> syn::Result<(usize, usize)> {
+    let mut min = None;
+    let mut max = None;
+    meta.parse_nested_meta(|meta2| {
+        if meta2.path.is_ident("min") {
+            min = Some(meta2.value()?.parse::<LitInt>()?.base10_parse::<usize>()?);
+        } else if meta2.path.is_ident("max") {
+            max = Some(meta2.value()?.parse::<LitInt>()?.base10_parse::<usize>()?);
+        } else {
+            return Err(meta2.error(format!("未知的长度范围属性 `{}`，只支持 min、max", path_to_string(&meta2.path))));
+        }
+        Ok(())
+    })?;
+    match (min, max) {
+        (Some(min), Some(max)) => Ok((min, max)),
+        _ => Err(meta.error("长度范围必须同时指定 min 和 max")),
+    }
+}
+
+/// 将属性路径转换为字符串，用于错误提示
+fn path_to_string(path: &syn::Path) -> String {
+    quote!(#path).to_string().replace(' ', "")
+}
+
+/// 解析数值规则的边界，如 `min = 10`、`max = 99.5`、`number_min = -1`，生成 `common_validation::Number`
+fn parse_number(value: syn::parse::ParseStream) -> syn::Result<proc_macro2::TokenStream> {
+    match value.parse::<syn::Lit>()? {
+        syn::Lit::Int(lit) => {
+            let num = lit.base10_parse::<i128>()?;
+            Ok(quote! { common_validation::Number::Int(#num) })
+        },
+        syn::Lit::Float(lit) => {
+            let num = lit.base10_parse::<f64>()?;
+            Ok(quote! { common_validation::Number::Float(#num) })
+        },
+        lit => Err(syn::Error::new_spanned(lit, "数值边界必须为整数或浮点数")),
+    }
+}
+
+/// 解析 `one_of = ["a", "b"]` 形式的可选值列表，数值可选值如 `one_of = [0, 1]` 按字符串形式比较
+fn parse_one_of(meta: &syn::meta::ParseNestedMeta) -> syn::Result<Vec<String>> {
+    let value = meta.value()?;
+    let content;
+    syn::bracketed!(content in value);
+    let values = content.parse_terminated(<syn::Lit as syn::parse::Parse>::parse, syn::Token![,])?;
+    values
+        .iter()
+        .map(|lit| match lit {
+            syn::Lit::Str(lit) => Ok(lit.value()),
+            syn::Lit::Int(lit) => Ok(lit.base10_digits().to_string()),
+            _ => Err(syn::Error::new_spanned(lit, "可选值必须为字符串或整数")),
+        })
+        .collect()
+}
+
+/// 跳过不关心的 serde 属性项，如 `default`、`skip_serializing_if = "..."`、`bound(...)`
+fn skip_serde_meta(meta: &syn::meta::ParseNestedMeta) -> syn::Result<()> {
+    if meta.input.peek(syn::Token![=]) {
+        meta.value()?.parse::<syn::Expr>()?;
+    } else if meta.input.peek(syn::token::Paren) {
+        let _content;
+        syn::parenthesized!(_content in meta.input);
+    }
+    Ok(())
+}
+
+/// 解析 serde 的命名属性，支持 `rename = "name"` 和 `rename(deserialize = "name")`，
+/// 请求体按反序列化的名称提交，因此只取 deserialize 的名称
+fn parse_serde_name(meta: &syn::meta::ParseNestedMeta) -> syn::Result<Option<String>> {
+    if meta.input.peek(syn::Token![=]) {
+        return Ok(Some(meta.value()?.parse::<LitStr>()?.value()));
+    }
+    let mut name = None;
+    meta.parse_nested_meta(|meta2| {
+        if meta2.path.is_ident("deserialize") {
+            name = Some(meta2.value()?.parse::<LitStr>()?.value());
+        } else {
+            skip_serde_meta(&meta2)?;
+        }
+        Ok(())
+    })?;
+    Ok(name)
+}
+
+/// 解析结构体上的 `#[serde(rename_all = "...")]`
+fn parse_serde_rename_all(attrs: &[syn::Attribute]) -> Option<String> {
+    let mut rename_all = None;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+        let _ = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename_all") {
+                rename_all = parse_serde_name(&meta)?;
+            } else {
+                skip_serde_meta(&meta)?;
+            }
+            Ok(())
+        });
+    }
+    rename_all
+}
+
+/// 按 serde 的 rename_all 规则转换 snake_case 字段名
+fn apply_rename_all(field: &str, rule: &str) -> String {
+    let pascal = || {
+        field
+            .split('_')
+            .map(|word| {
+                let mut chars = word.chars();
+                chars
+                    .next()
+                    .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                    .unwrap_or_default()
+            })
+            .collect::<String>()
+    };
+    match rule {
+        "UPPERCASE" | "SCREAMING_SNAKE_CASE" => field.to_ascii_uppercase(),
+        "PascalCase" => pascal(),
+        "camelCase" => {
+            let pascal = pascal();
+            let mut chars = pascal.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_lowercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        },
+        "kebab-case" => field.replace('_', "-"),
+        "SCREAMING-KEBAB-CASE" => field.to_ascii_uppercase().replace('_', "-"),
+        _ => field.to_string(),
+    }
+}
+
+/// 计算字段在验证错误中的路径
+///
+/// 与请求体的字段名保持一致：优先使用字段上的 `#[serde(rename)]`，其次按结构体的 `#[serde(rename_all)]` 转换；
+/// `#[serde(flatten)]` 的字段没有自己的层级，路径为空
+fn field_path(field: &syn::Field, field_name: &str, rename_all: Option<&str>) -> String {
+    let mut rename = None;
+    let mut flatten = false;
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("serde"))
+    {
+        let _ = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename") {
+                rename = parse_serde_name(&meta)?;
+            } else if meta.path.is_ident("flatten") {
+                flatten = true;
+            } else {
+                skip_serde_meta(&meta)?;
+            }
+            Ok(())
+        });
+    }
+
+    if flatten {
+        return String::new();
+    }
+    let field_name = field_name.trim_start_matches("r#");
+    rename.unwrap_or_else(|| match rename_all {
+        Some(rule) => apply_rename_all(field_name, rule),
+        None => field_name.to_string(),
+    })
+}
+
+/// 单个分组的字段配置
 struct GroupFieldsConfig {
-    create_fields: Vec<String>,
-    update_fields: Vec<String>,
-    query_fields: Vec<String>,
-    status_update_fields: Vec<String>,
-    page_query_fields: Vec<String>,
+    /// 分组名称，如 `create`、`batch_delete`
+    name: syn::Ident,
+    /// 分组需要验证的字段
+    fields: Vec<LitStr>,
+}
+
+impl GroupFieldsConfig {
+    /// 分组的标记类型
+    ///
+    /// 内置分组使用 `common_validation` 中的类型，其余分组按名称查找当前作用域中的 `{PascalCase}Group` 类型，
+    /// 如 `batch_delete` 对应 `BatchDeleteGroup`，类型不存在时编译报错
+    fn group_type(&self) -> proc_macro2::TokenStream {
+        let type_name = format!("{}Group", apply_rename_all(&self.name.to_string(), "PascalCase"));
+        let ident = syn::Ident::new(&type_name, self.name.span());
+        match type_name.as_str() {
+            "CreateGroup" | "UpdateGroup" | "QueryGroup" | "StatusUpdateGroup" | "PageQueryGroup" => quote! { common_validation::#ident },
+            _ => quote! { #ident },
+        }
+    }
+}
+
+/// 解析单个分组的字段列表
+///
+/// 支持数组写法 `create = ["name", "status"]`，也兼容字符串写法 `create = "[\"name\", \"status\"]"`
+fn parse_group_field_list(meta: &syn::meta::ParseNestedMeta) -> syn::Result<Vec<LitStr>> {
+    let value = meta.value()?;
+    if value.peek(syn::token::Bracket) {
+        let content;
+        syn::bracketed!(content in value);
+        let fields = content.parse_terminated(<LitStr as syn::parse::Parse>::parse, syn::Token![,])?;
+        return Ok(fields.into_iter().collect());
+    }
+
+    // 简单解析字符串数组格式："[\"field1\", \"field2\"]"
+    let lit = value.parse::<LitStr>()?;
+    let value = lit.value();
+    let Some(fields_str) = value.strip_prefix('[').and_then(|v| v.strip_suffix(']')) else {
+        return Err(syn::Error::new_spanned(&lit, "分组字段必须为字符串数组，如 [\"field1\", \"field2\"]"));
+    };
+    Ok(fields_str
+        .split(',')
+        .map(str::trim)
+        .filter_map(|field| field.strip_prefix('"').and_then(|f| f.strip_suffix('"')))
+        .map(|field| LitStr::new(field, lit.span()))
+        .collect())
 }
 
 /// 解析分组字段配置
 ///
-/// 从结构体的 #[group_fields] 属性中解析分组字段配置
-fn parse_group_fields(input: &DeriveInput) -> GroupFieldsConfig {
-    let mut config = GroupFieldsConfig::default();
+/// 从结构体的 #[group_fields] 属性中解析分组字段配置，分组名称可以任意定义，按声明顺序返回。
+/// 分组中的字段必须是结构体的字段，否则报错并指向对应的字段名称
+fn parse_group_fields(input: &DeriveInput, field_names: &[String]) -> syn::Result<Vec<GroupFieldsConfig>> {
+    let mut groups = Vec::<GroupFieldsConfig>::new();
 
     for attr in &input.attrs {
         if attr.path().is_ident("group_fields") {
-            let _ = attr.parse_nested_meta(|meta| {
-                if meta.path.is_ident("create") {
-                    let value = meta.value()?;
-                    // 直接解析为字符串数组
-                    if let Ok(lit) = value.parse::<syn::LitStr>() {
-                        let value = lit.value();
-                        // 简单解析字符串数组格式：["field1", "field2"]
-                        if value.starts_with('[') && value.ends_with(']') {
-                            let fields_str = &value[1..value.len() - 1];
-                            for field in fields_str.split(',') {
-                                let field = field.trim();
-                                if field.starts_with('"') && field.ends_with('"') {
-                                    let field = &field[1..field.len() - 1];
-                                    config.create_fields.push(field.to_string());
-                                }
-                            }
-                        }
-                    }
-                } else if meta.path.is_ident("update") {
-                    let value = meta.value()?;
-                    if let Ok(lit) = value.parse::<syn::LitStr>() {
-                        let value = lit.value();
-                        if value.starts_with('[') && value.ends_with(']') {
-                            let fields_str = &value[1..value.len() - 1];
-                            for field in fields_str.split(',') {
-                                let field = field.trim();
-                                if field.starts_with('"') && field.ends_with('"') {
-                                    let field = &field[1..field.len() - 1];
-                                    config.update_fields.push(field.to_string());
-                                }
-                            }
-                        }
-                    }
-                } else if meta.path.is_ident("query") {
-                    let value = meta.value()?;
-                    if let Ok(lit) = value.parse::<syn::LitStr>() {
-                        let value = lit.value();
-                        if value.starts_with('[') && value.ends_with(']') {
-                            let fields_str = &value[1..value.len() - 1];
-                            for field in fields_str.split(',') {
-                                let field = field.trim();
-                                if field.starts_with('"') && field.ends_with('"') {
-                                    let field = &field[1..field.len() - 1];
-                                    config.query_fields.push(field.to_string());
-                                }
-                            }
-                        }
-                    }
-                } else if meta.path.is_ident("status_update") {
-                    let value = meta.value()?;
-                    if let Ok(lit) = value.parse::<syn::LitStr>() {
-                        let value = lit.value();
-                        if value.starts_with('[') && value.ends_with(']') {
-                            let fields_str = &value[1..value.len() - 1];
-                            for field in fields_str.split(',') {
-                                let field = field.trim();
-                                if field.starts_with('"') && field.ends_with('"') {
-                                    let field = &field[1..field.len() - 1];
-                                    config.status_update_fields.push(field.to_string());
-                                }
-                            }
-                        }
-                    }
-                } else if meta.path.is_ident("page_query") {
-                    let value = meta.value()?;
-                    if let Ok(lit) = value.parse::<syn::LitStr>() {
-                        let value = lit.value();
-                        if value.starts_with('[') && value.ends_with(']') {
-                            let fields_str = &value[1..value.len() - 1];
-                            for field in fields_str.split(',') {
-                                let field = field.trim();
-                                if field.starts_with('"') && field.ends_with('"') {
-                                    let field = &field[1..field.len() - 1];
-                                    config.page_query_fields.push(field.to_string());
-                                }
-                            }
-                        }
-                    }
+            attr.parse_nested_meta(|meta| {
+                let name = meta.path.require_ident()?.clone();
+                let fields = parse_group_field_list(&meta)?;
+                if let Some(field) = fields
+                    .iter()
+                    .find(|field| !field_names.contains(&field.value()))
+                {
+                    return Err(syn::Error::new_spanned(field, format!("分组 `{name}` 中的字段 `{}` 不存在", field.value())));
                 }
+                // 重复声明的分组以最后一次为准
+                groups.retain(|group| group.name != name);
+                groups.push(GroupFieldsConfig { name, fields });
                 Ok(())
-            });
+            })?;
         }
     }
 
-    config
+    Ok(groups)
+}
+
+/// 结构体级别的验证函数配置
+struct SchemaValidation {
+    /// 验证函数路径，同步函数签名为 `fn(&Self) -> Result<(), ValidationErrorEnum>`，异步函数签名为 `async fn(&Self, &上下文) -> Result<(), 错误类型>`
+    function: syn::Path,
+    /// 执行验证函数的分组名称，为空时所有分组都执行
+    groups: Vec<LitStr>,
+}
+
+/// 结构体级别的验证配置
+#[derive(Default)]
+struct StructValidations {
+    /// 同步验证函数
+    schemas: Vec<SchemaValidation>,
+    /// 异步验证函数
+    async_schemas: Vec<SchemaValidation>,
+    /// 异步验证的上下文类型
+    context: Option<syn::Type>,
+    /// 异步验证的错误类型
+    error: Option<syn::Type>,
+}
+
+/// 解析结构体级别的验证配置
+///
+/// 从结构体的 #[validate(...)] 属性中解析，可以有多个属性：
+///
+/// - `schema = "function", groups = ["update"]`: 同步验证函数
+/// - `async_schema = "function", groups = ["update"]`: 异步验证函数
+/// - `context = "Type", error = "Type"`: 异步验证的上下文类型及错误类型
+///
+/// 其余属性报错
+fn parse_struct_validations(input: &DeriveInput) -> syn::Result<StructValidations> {
+    let mut validations = StructValidations::default();
+
+    for attr in &input.attrs {
+        if attr.path().is_ident("validate") {
+            let mut function = None;
+            let mut async_function = None;
+            let mut groups = Vec::new();
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("schema") {
+                    function = Some(meta.value()?.parse::<LitStr>()?.parse::<syn::Path>()?);
+                } else if meta.path.is_ident("async_schema") {
+                    async_function = Some(meta.value()?.parse::<LitStr>()?.parse::<syn::Path>()?);
+                } else if meta.path.is_ident("groups") {
+                    groups = parse_group_field_list(&meta)?;
+                } else if meta.path.is_ident("context") {
+                    validations.context = Some(meta.value()?.parse::<LitStr>()?.parse::<syn::Type>()?);
+                } else if meta.path.is_ident("error") {
+                    validations.error = Some(meta.value()?.parse::<LitStr>()?.parse::<syn::Type>()?);
+                } else {
+                    return Err(meta.error(format!("未知的结构体验证属性 `{}`，只支持 schema、async_schema、groups、context、error", path_to_string(&meta.path))));
+                }
+                Ok(())
+            })?;
+            if let Some(function) = function {
+                validations
+                    .schemas
+                    .push(SchemaValidation { function, groups: groups.clone() });
+            }
+            if let Some(function) = async_function {
+                validations
+                    .async_schemas
+                    .push(SchemaValidation { function, groups });
+            }
+        }
+    }
+
+    Ok(validations)
 }
 
 /// 实现 Validatable trait 的派生宏
@@ -289,8 +542,27 @@ fn parse_group_fields(input: &DeriveInput) -> GroupFieldsConfig {
 /// - `odd_number`: 奇数验证
 /// - `even_number`: 偶数验证
 /// - `multiple_of = N`: 倍数验证
+/// - `pattern = "正则"`: 正则表达式验证，值必须完整匹配
+/// - `email`: 邮箱验证
+/// - `mobile_phone`: 手机号验证（中国大陆11位）
+/// - `url`: URL验证
+/// - `one_of = ["a", "b"]`: 枚举值验证，数值字段可写为 `one_of = [0, 1]`
 /// - `desc = "描述"`: 字段描述
 /// - `nested`: 嵌套结构体验证（用于标记需要递归验证的结构体字段）
+/// - `custom = "path::to::function"`: 自定义验证函数，签名为 `fn(&字段类型) -> Result<(), ValidationErrorEnum>`
+/// - `async_custom = "path::to::function"`: 异步验证函数，签名为 `async fn(&字段类型, &上下文) -> Result<(), 错误类型>`
+///
+/// # 结构体级别验证
+///
+/// 可以使用 #[validate(schema = "function")] 属性配置跨字段的验证函数，签名为 `fn(&Self) -> Result<(), ValidationErrorEnum>`，
+/// 使用 `groups = ["update"]` 限定分组验证时执行的分组
+///
+/// # 异步验证
+///
+/// 使用 #[validate(context = "Type", error = "Type")] 属性配置异步验证的上下文类型及错误类型时，
+/// 生成 [`AsyncValidatable`](common_validation::AsyncValidatable) 的实现，错误类型默认为 `ValidationErrorEnum`。
+/// 可以使用 #[validate(async_schema = "function")] 属性配置结构体级别的异步验证函数，签名为 `async fn(&Self, &上下文) -> Result<(), 错误类型>`，
+/// 同样可以使用 `groups` 限定分组
 ///
 /// # 分组配置
 ///
@@ -303,6 +575,15 @@ fn parse_group_fields(input: &DeriveInput) -> GroupFieldsConfig {
 /// )]
 /// ```
 ///
+/// 分组名称可以任意定义，每个分组对应一个标记类型并生成 [`GroupFields`](common_validation::GroupFields) 的实现：
+/// `create`、`update`、`query`、`status_update`、`page_query` 使用 `common_validation` 中的内置分组，
+/// 其余分组使用当前作用域中的 `{PascalCase}Group` 类型，如 `batch_delete` 对应 `BatchDeleteGroup`，可以通过 `#[derive(ValidationGroup)]` 定义。
+/// 按结构体未定义的分组验证时编译报错
+///
+/// # 编译错误
+///
+/// 未知的验证属性、与字段类型不匹配的规则（如数值字段使用 `length`）、分组中不存在的字段编译报错，错误指向对应的属性或字段名称
+///
 /// # 示例
 ///
 /// ```rust
@@ -357,15 +638,91 @@ pub fn derive_validatable(input: TokenStream) -> TokenStream {
         },
     };
 
-    // 解析分组字段配置
-    let config = parse_group_fields(&input);
-    let create_fields = config.create_fields;
-    let update_fields = config.update_fields;
-    let query_fields = config.query_fields;
-    let status_update_fields = config.status_update_fields;
-    let page_query_fields = config.page_query_fields;
+    // 解析分组字段配置，分组中的字段必须是结构体的字段
+    let field_names = fields
+        .iter()
+        .filter_map(|f| f.ident.as_ref().map(ToString::to_string))
+        .collect::<Vec<_>>();
+    let groups = match parse_group_fields(&input, &field_names) {
+        Ok(groups) => groups,
+        Err(e) => return e.to_compile_error().into(),
+    };
+
+    // 结构体级别的验证函数
+    let struct_validations = match parse_struct_validations(&input) {
+        Ok(validations) => validations,
+        Err(e) => return e.to_compile_error().into(),
+    };
+    // 验证函数只能限定在结构体定义的分组中
+    if let Some(group) = struct_validations
+        .schemas
+        .iter()
+        .chain(&struct_validations.async_schemas)
+        .flat_map(|schema| &schema.groups)
+        .find(|group| !groups.iter().any(|config| config.name == group.value()))
+    {
+        return syn::Error::new_spanned(group, format!("验证函数的分组 `{}` 未在 #[group_fields(...)] 中定义", group.value()))
+            .to_compile_error()
+            .into();
+    }
+    let schemas = &struct_validations.schemas;
+    let schema_fns = schemas
+        .iter()
+        .map(|schema| &schema.function)
+        .collect::<Vec<_>>();
+    // 分组验证时，配置了分组的验证函数只在指定分组中执行
+    let group_schema_condition = |schema: &SchemaValidation| {
+        let groups = &schema.groups;
+        if groups.is_empty() {
+            quote! { true }
+        } else {
+            quote! { [#(#groups),*].contains(&G::group_name()) }
+        }
+    };
+    let group_schema_codes = schemas.iter().map(|schema| {
+        let function = &schema.function;
+        let condition = group_schema_condition(schema);
+        quote! {
+            if #condition {
+                #function(self)?;
+            }
+        }
+    });
+    let group_schema_collect_codes = schemas.iter().map(|schema| {
+        let function = &schema.function;
+        let condition = group_schema_condition(schema);
+        quote! {
+            if #condition
+                && let Err(e) = #function(self)
+            {
+                errors.add("", e);
+            }
+        }
+    });
+    let async_schema_fns = struct_validations
+        .async_schemas
+        .iter()
+        .map(|schema| &schema.function)
+        .collect::<Vec<_>>();
+    let group_async_schema_codes = struct_validations.async_schemas.iter().map(|schema| {
+        let function = &schema.function;
+        let condition = group_schema_condition(schema);
+        quote! {
+            if #condition {
+                #function(self, context).await?;
+            }
+        }
+    });
+
+    // 结构体上的 serde 命名规则，用于计算验证错误的字段路径
+    let rename_all = parse_serde_rename_all(&input.attrs);
 
-    // 为每个字段生成验证代码
+    // 字段验证属性的错误，合并所有字段的错误后一起报告
+    let mut field_errors = Option::<syn::Error>::None;
+    // 字段类型与验证规则的约束检查，生成在 `validate` 中，只参与类型检查
+    let mut type_checks = Vec::new();
+
+    // 为每个字段生成验证代码：快速失败模式和收集模式各一份
     let field_validations = fields
         .iter()
         .filter_map(|f| {
@@ -374,6 +731,8 @@ pub fn derive_validatable(input: TokenStream) -> TokenStream {
             // 获取字段类型
             let field_ty = &f.ty;
             let field_ident_str = field_name.to_string();
+            // 验证错误中的字段路径
+            let path = field_path(f, &field_ident_str, rename_all.as_deref());
 
             // 查找 validate 属性
             let validate_attr = f.attrs.iter().find(|attr| attr.path().is_ident("validate"));
@@ -385,77 +744,73 @@ pub fn derive_validatable(input: TokenStream) -> TokenStream {
             let mut date_format_rule = None;
             let mut length_rules = Vec::new();
             let mut number_rules = Vec::new();
+            let mut format_rules = Vec::new();
             let mut nested_rule = false;
+            let mut custom_fns = Vec::<syn::Path>::new();
+            let mut async_custom_fns = Vec::<syn::Path>::new();
             let mut length_range = Option::<(usize, usize)>::None;
-            let mut number_min = Option::<i64>::None;
-            let mut number_max = Option::<i64>::None;
+            let mut number_min = None;
+            let mut number_max = None;
 
-            // 类型辅助判断
-            let is_string = is_type_of(field_ty, "String") || is_type_of(field_ty, "str");
-            let is_number = is_number_type(field_ty);
-            let is_vec = is_type_of(field_ty, "Vec");
+            // 类型辅助判断，Option<T> 按内部类型 T 判断
+            let is_option = is_type_of(field_ty, "Option");
+            let value_ty = if is_option { extract_inner_type(field_ty).unwrap_or_else(|| field_ty.clone()) } else { field_ty.clone() };
+            // 非数字类型的 `min`、`max` 按长度处理，数值类型的别名等需要使用 `number_min`、`number_max`
+            let is_number = is_number_value(&value_ty);
+            // 各规则要求字段类型实现的约束
+            let mut bounds = Vec::new();
 
-            // 解析 validate 属性
+            // 解析 validate 属性，未知的属性报错，规则与字段类型是否匹配由生成的约束检查在编译期检查
             if let Some(validate_attr) = validate_attr {
-                let _ = validate_attr.parse_nested_meta(|meta| {
+                let result = validate_attr.parse_nested_meta(|meta| {
                     if meta.path.is_ident("not_null") {
                         not_null_rule = Some(quote! { common_validation::ValidationRulesEnum::NotNull });
-                    } else if meta.path.is_ident("length") && (is_string || is_vec) {
-                        length_rules.push(quote! { common_validation::ValidationRulesEnum::Length });
-                    } else if meta.path.is_ident("exist_length") && (is_string || is_vec) {
-                        length_rules.push(quote! { common_validation::ValidationRulesEnum::ExistLength });
-                    } else if meta.path.is_ident("length_range") && (is_string || is_vec) {
+                    } else if meta.path.is_ident("length") {
+                        bounds.push(RuleBound::Length);
+                        let len = meta.value()?.parse::<LitInt>()?.base10_parse::<usize>()?;
+                        length_rules.push(quote! { common_validation::ValidationRulesEnum::Length(#len) });
+                    } else if meta.path.is_ident("exist_length") {
+                        bounds.push(RuleBound::Length);
+                        let len = meta.value()?.parse::<LitInt>()?.base10_parse::<usize>()?;
+                        length_rules.push(quote! { common_validation::ValidationRulesEnum::ExistLength(#len) });
+                    } else if meta.path.is_ident("length_range") {
                         // 解析 length_range(min = x, max = y)
-                        let mut min = None;
-                        let mut max = None;
-                        meta.parse_nested_meta(|meta2| {
-                            if meta2.path.is_ident("min") {
-                                let value = meta2.value()?;
-                                let lit: LitInt = value.parse()?;
-                                min = Some(lit.base10_parse::<usize>().unwrap());
-                            } else if meta2.path.is_ident("max") {
-                                let value = meta2.value()?;
-                                let lit: LitInt = value.parse()?;
-                                max = Some(lit.base10_parse::<usize>().unwrap());
-                            }
-                            Ok(())
-                        })?;
-                        if let (Some(min), Some(max)) = (min, max) {
-                            length_range = Some((min, max));
-                        }
+                        bounds.push(RuleBound::Length);
+                        length_range = Some(parse_min_max(&meta)?);
+                    } else if meta.path.is_ident("exist_length_range") {
+                        // 解析 exist_length_range(min = x, max = y)
+                        bounds.push(RuleBound::Length);
+                        let (min, max) = parse_min_max(&meta)?;
+                        length_rules.push(quote! { common_validation::ValidationRulesEnum::ExistLengthRange(#min, #max) });
                     } else if meta.path.is_ident("desc") {
                         let value = meta.value()?;
                         let s: LitStr = value.parse()?;
                         desc = s.value();
                     } else if meta.path.is_ident("min") {
+                        bounds.push(if is_number { RuleBound::Number } else { RuleBound::Length });
                         let value = meta.value()?;
-                        if let Ok(val) = value.parse::<LitInt>() {
-                            if is_number {
-                                number_min = val.base10_parse().ok();
-                            } else if (is_string || is_vec)
-                                && let Ok(num) = val.base10_parse::<usize>()
-                            {
-                                let current_max = match length_range {
-                                    Some((_, max)) => max,
-                                    None => usize::MAX,
-                                };
-                                length_range = Some((num, current_max));
-                            }
+                        if is_number {
+                            number_min = Some(parse_number(value)?);
+                        } else {
+                            let num = value.parse::<LitInt>()?.base10_parse::<usize>()?;
+                            let current_max = match length_range {
+                                Some((_, max)) => max,
+                                None => usize::MAX,
+                            };
+                            length_range = Some((num, current_max));
                         }
                     } else if meta.path.is_ident("max") {
+                        bounds.push(if is_number { RuleBound::Number } else { RuleBound::Length });
                         let value = meta.value()?;
-                        if let Ok(val) = value.parse::<LitInt>() {
-                            if is_number {
-                                number_max = val.base10_parse().ok();
-                            } else if (is_string || is_vec)
-                                && let Ok(num) = val.base10_parse::<usize>()
-                            {
-                                let current_min = match length_range {
-                                    Some((min, _)) => min,
-                                    None => 0,
-                                };
-                                length_range = Some((current_min, num));
-                            }
+                        if is_number {
+                            number_max = Some(parse_number(value)?);
+                        } else {
+                            let num = value.parse::<LitInt>()?.base10_parse::<usize>()?;
+                            let current_min = match length_range {
+                                Some((min, _)) => min,
+                                None => 0,
+                            };
+                            length_range = Some((current_min, num));
                         }
                     } else if meta.path.is_ident("date_format") {
                         let ident = meta
@@ -473,98 +828,98 @@ pub fn derive_validatable(input: TokenStream) -> TokenStream {
                             _ => return Err(meta.error("无效的日期格式")),
                         };
                         date_format_rule = Some(quote! { common_validation::ValidationRulesEnum::DateFormat(#format) });
-                    } else if meta.path.is_ident("number_min") && is_number {
-                        let value = meta.value()?;
-                        if let Ok(val) = value.parse::<LitInt>() {
-                            number_min = val.base10_parse().ok();
-                        }
-                    } else if meta.path.is_ident("number_max") && is_number {
-                        let value = meta.value()?;
-                        if let Ok(val) = value.parse::<LitInt>() {
-                            number_max = val.base10_parse().ok();
-                        }
-                    } else if meta.path.is_ident("positive_number") && is_number {
+                    } else if meta.path.is_ident("number_min") {
+                        bounds.push(RuleBound::Number);
+                        number_min = Some(parse_number(meta.value()?)?);
+                    } else if meta.path.is_ident("number_max") {
+                        bounds.push(RuleBound::Number);
+                        number_max = Some(parse_number(meta.value()?)?);
+                    } else if meta.path.is_ident("positive_number") {
+                        bounds.push(RuleBound::Number);
                         number_rules.push(quote! { common_validation::ValidationRulesEnum::PositiveNumber });
-                    } else if meta.path.is_ident("non_negative_number") && is_number {
+                    } else if meta.path.is_ident("non_negative_number") {
+                        bounds.push(RuleBound::Number);
                         number_rules.push(quote! { common_validation::ValidationRulesEnum::NonNegativeNumber });
-                    } else if meta.path.is_ident("integer") && is_number {
+                    } else if meta.path.is_ident("integer") {
+                        bounds.push(RuleBound::Number);
                         number_rules.push(quote! { common_validation::ValidationRulesEnum::Integer });
-                    } else if meta.path.is_ident("decimal_scale") && is_number {
-                        let value = meta.value()?;
-                        if let Ok(val) = value.parse::<LitInt>() {
-                            let scale = val.base10_parse::<u32>().unwrap();
-                            number_rules.push(quote! { common_validation::ValidationRulesEnum::DecimalScale(#scale) });
-                        }
-                    } else if meta.path.is_ident("odd_number") && is_number {
+                    } else if meta.path.is_ident("decimal_scale") {
+                        bounds.push(RuleBound::Number);
+                        let scale = meta.value()?.parse::<LitInt>()?.base10_parse::<u32>()?;
+                        number_rules.push(quote! { common_validation::ValidationRulesEnum::DecimalScale(#scale) });
+                    } else if meta.path.is_ident("odd_number") {
+                        bounds.push(RuleBound::Number);
                         number_rules.push(quote! { common_validation::ValidationRulesEnum::OddNumber });
-                    } else if meta.path.is_ident("even_number") && is_number {
+                    } else if meta.path.is_ident("even_number") {
+                        bounds.push(RuleBound::Number);
                         number_rules.push(quote! { common_validation::ValidationRulesEnum::EvenNumber });
-                    } else if meta.path.is_ident("multiple_of") && is_number {
-                        let value = meta.value()?;
-                        if let Ok(val) = value.parse::<LitInt>() {
-                            let multiple = val.base10_parse::<i64>().unwrap();
-                            number_rules.push(quote! { common_validation::ValidationRulesEnum::MultipleOf(#multiple) });
-                        }
+                    } else if meta.path.is_ident("multiple_of") {
+                        bounds.push(RuleBound::Number);
+                        let multiple = meta.value()?.parse::<LitInt>()?.base10_parse::<i64>()?;
+                        number_rules.push(quote! { common_validation::ValidationRulesEnum::MultipleOf(#multiple) });
+                    } else if meta.path.is_ident("pattern") {
+                        bounds.push(RuleBound::Format);
+                        let pattern = meta.value()?.parse::<LitStr>()?;
+                        format_rules.push(quote! { common_validation::ValidationRulesEnum::Pattern(#pattern) });
+                    } else if meta.path.is_ident("email") {
+                        bounds.push(RuleBound::Format);
+                        format_rules.push(quote! { common_validation::ValidationRulesEnum::Email });
+                    } else if meta.path.is_ident("mobile_phone") {
+                        bounds.push(RuleBound::Format);
+                        format_rules.push(quote! { common_validation::ValidationRulesEnum::MobilePhone });
+                    } else if meta.path.is_ident("url") {
+                        bounds.push(RuleBound::Format);
+                        format_rules.push(quote! { common_validation::ValidationRulesEnum::Url });
+                    } else if meta.path.is_ident("one_of") {
+                        bounds.push(RuleBound::OneOf);
+                        let values = parse_one_of(&meta)?;
+                        format_rules.push(quote! { common_validation::ValidationRulesEnum::OneOf(&[#(#values),*]) });
                     } else if meta.path.is_ident("nested") {
                         nested_rule = true;
+                    } else if meta.path.is_ident("custom") {
+                        custom_fns.push(meta.value()?.parse::<LitStr>()?.parse::<syn::Path>()?);
+                    } else if meta.path.is_ident("async_custom") {
+                        async_custom_fns.push(meta.value()?.parse::<LitStr>()?.parse::<syn::Path>()?);
+                    } else {
+                        return Err(meta.error(format!("未知的验证属性 `{}`", path_to_string(&meta.path))));
                     }
                     Ok(())
                 });
+                if let Err(e) = result {
+                    match &mut field_errors {
+                        Some(errors) => errors.combine(e),
+                        None => field_errors = Some(e),
+                    }
+                }
             }
 
-            // 处理递归验证逻辑 - 如果有Nested规则
-            if nested_rule {
-                // 对于Option<T>类型，需要特殊处理
-                if is_type_of(field_ty, "Option") {
-                    return Some((
-                        field_ident_str,
-                        quote! {
-                            if let Some(ref val) = self.#field_name {
-                                val.validate()?;
-                            }
-                        },
-                    ));
-                }
-                // 对于Vec<T>类型，需要遍历每个元素进行验证
-                else if is_type_of(field_ty, "Vec") {
-                    return Some((
-                        field_ident_str,
-                        quote! {
-                            for item in &self.#field_name {
-                                item.validate()?;
-                            }
-                        },
-                    ));
-                }
-                // 对于普通类型
-                else {
-                    return Some((
-                        field_ident_str,
-                        quote! {
-                            self.#field_name.validate()?;
-                        },
-                    ));
-                }
+            // 字段类型不支持规则时编译报错并指向字段类型
+            bounds.sort();
+            bounds.dedup();
+            for bound in bounds {
+                let path = bound.path();
+                type_checks.push(quote_spanned! {value_ty.span()=>
+                    {
+                        fn assert<T: #path + ?Sized>() {}
+                        assert::<#value_ty>();
+                    }
+                });
             }
 
             // 处理 length_range
-            if let Some((min, max)) = length_range
-                && (is_string || is_vec)
-            {
+            if let Some((min, max)) = length_range {
                 length_rules.push(quote! { common_validation::ValidationRulesEnum::LengthRange(#min, #max) });
             }
 
-            if is_number {
-                if let Some(min) = number_min {
-                    number_rules.push(quote! { common_validation::ValidationRulesEnum::NumberMin(#min) });
-                }
-                if let Some(max) = number_max {
-                    number_rules.push(quote! { common_validation::ValidationRulesEnum::NumberMax(#max) });
-                }
+            if let Some(min) = number_min {
+                number_rules.push(quote! { common_validation::ValidationRulesEnum::NumberMin(#min) });
+            }
+            if let Some(max) = number_max {
+                number_rules.push(quote! { common_validation::ValidationRulesEnum::NumberMax(#max) });
             }
 
             // 统一按顺序 push 规则
-            let rules_builder = {
+            let all_rules = {
                 let mut all_rules = Vec::new();
                 if let Some(r) = not_null_rule {
                     all_rules.push(r);
@@ -577,136 +932,278 @@ pub fn derive_validatable(input: TokenStream) -> TokenStream {
                     all_rules.push(quote! { common_validation::ValidationRulesEnum::Nested });
                 }
                 all_rules.extend(length_rules);
+                all_rules.extend(format_rules);
                 all_rules.extend(number_rules);
-                quote! {
-                    #(rule = rule.with_rule(#all_rules);)*
-                }
+                all_rules
             };
 
-            // 处理 Option 类型的值访问
-            let value_access = if is_type_of(field_ty, "Option") {
-                // Option<String> 或 Option<Vec<T>>
-                let inner_ty = extract_inner_type(field_ty);
-                if let Some(inner_ty) = inner_ty {
-                    if is_type_of(&inner_ty, "Vec") {
-                        // Option<Vec<T>>
+            let (validation, collect) = if nested_rule {
+                // 处理递归验证逻辑 - 如果有Nested规则
+                if is_type_of(field_ty, "Option") {
+                    // 对于Option<T>类型，需要特殊处理
+                    (
                         quote! {
-                            self.#field_name.as_ref().map(|v| v.len().to_string()).unwrap_or_default()
-                        }
-                    } else if is_type_of(&inner_ty, "String") || is_type_of(&inner_ty, "str") || is_number_type(&inner_ty) {
-                        // Option<String> 或 Option<数字类型>
+                            if let Some(ref val) = self.#field_name {
+                                val.validate()?;
+                            }
+                        },
                         quote! {
-                            self.#field_name.as_ref().map(|v| v.to_string()).unwrap_or_default()
-                        }
-                    } else {
-                        // Option<结构体类型>
-                        quote! { String::new() }
-                    }
+                            if let Some(ref val) = self.#field_name
+                                && let Err(e) = val.validate_all()
+                            {
+                                errors.merge(#path, e);
+                            }
+                        },
+                    )
+                } else if is_type_of(field_ty, "Vec") {
+                    // 对于Vec<T>类型，需要遍历每个元素进行验证
+                    (
+                        quote! {
+                            for item in &self.#field_name {
+                                item.validate()?;
+                            }
+                        },
+                        quote! {
+                            for (index, item) in self.#field_name.iter().enumerate() {
+                                if let Err(e) = item.validate_all() {
+                                    errors.merge(&format!("{}[{}]", #path, index), e);
+                                }
+                            }
+                        },
+                    )
                 } else {
-                    quote! {
-                        self.#field_name.as_ref().map(|v| v.to_string()).unwrap_or_default()
-                    }
-                }
-            } else if is_type_of(field_ty, "Vec") {
-                // Vec<T>
-                quote! {
-                    self.#field_name.len().to_string()
+                    // 对于普通类型
+                    (
+                        quote! {
+                            self.#field_name.validate()?;
+                        },
+                        quote! {
+                            if let Err(e) = self.#field_name.validate_all() {
+                                errors.merge(#path, e);
+                            }
+                        },
+                    )
                 }
-            } else if nested_rule {
-                // 对于结构体类型，不需要获取值字符串进行验证
-                quote! { String::new() }
-            } else if is_string || is_number {
-                // String、数字等
-                quote! { self.#field_name.to_string() }
+            } else if !all_rules.is_empty() {
+                // 生成最终验证代码，保证规则顺序：not_null -> date_format -> nested -> length/length_range -> pattern/email/mobile_phone/url/one_of -> number_range
+                (
+                    quote! {
+                        {
+                            let mut rule = common_validation::ValidationRule::new(#desc);
+                            #(rule = rule.with_rule(#all_rules);)*
+                            common_validation::ParameterValidator::validate(&self.#field_name, &rule)?;
+                        }
+                    },
+                    quote! {
+                        {
+                            let mut rule = common_validation::ValidationRule::new(#desc);
+                            #(rule = rule.with_rule(#all_rules);)*
+                            for e in common_validation::ParameterValidator::validate_all(&self.#field_name, &rule) {
+                                errors.add(#path, e);
+                            }
+                        }
+                    },
+                )
+            } else if !custom_fns.is_empty() || !async_custom_fns.is_empty() {
+                (quote! {}, quote! {})
             } else {
-                // 对于其他类型（包括自定义结构体），使用空字符串
-                quote! { String::new() }
+                // 没有任何验证规则的字段不生成验证代码
+                return None;
             };
 
-            // 构建验证规则
-            let rule_builder = quote! {
-                let mut rule = common_validation::ValidationRule::new(#desc);
+            // 异步验证函数只在异步验证时执行
+            let async_validation = quote! {
+                #(#async_custom_fns(&self.#field_name, context).await?;)*
             };
 
-            // 生成最终验证代码，保证规则顺序：not_null -> date_format -> nested -> length/length_range -> number_range
+            if custom_fns.is_empty() {
+                return Some((field_ident_str, validation, collect, async_validation));
+            }
+
+            // 自定义验证函数在内置规则之后执行，收集模式下只在字段的内置规则全部通过时执行
             Some((
                 field_ident_str,
+                quote! {
+                    #validation
+                    #(#custom_fns(&self.#field_name)?;)*
+                },
                 quote! {
                     {
-                        #rule_builder
-                        #rules_builder
-                        let value = #value_access;
-                        common_validation::ParameterValidator::validate_value(&value, &rule)?;
+                        let error_count = errors.len();
+                        #collect
+                        if errors.len() == error_count {
+                            #(
+                                if let Err(e) = #custom_fns(&self.#field_name) {
+                                    errors.add(#path, e);
+                                }
+                            )*
+                        }
                     }
                 },
+                async_validation,
             ))
         })
         .collect::<Vec<_>>();
 
+    if let Some(errors) = field_errors {
+        return errors.to_compile_error().into();
+    }
+
     // 提取验证代码
-    let field_validation_codes = field_validations.iter().map(|(_, validation)| validation);
+    let field_validation_codes = field_validations
+        .iter()
+        .map(|(_, validation, _, _)| validation);
+    let field_collect_codes = field_validations.iter().map(|(_, _, collect, _)| collect);
 
     // 生成字段验证映射
-    let field_validation_mappings = field_validations.iter().map(|(field_name, validation)| {
-        let field_name_lit = field_name.clone();
+    let field_validation_mappings = field_validations
+        .iter()
+        .map(|(field_name, validation, _, _)| {
+            let field_name_lit = field_name.clone();
+            quote! {
+                #field_name_lit => { #validation },
+            }
+        });
+    let field_collect_mappings = field_validations.iter().map(|(field_name, _, collect, _)| {
         quote! {
-            #field_name_lit => { #validation },
+            #field_name => { #collect },
         }
     });
 
+    // 异步验证：只为配置了异步验证函数的字段生成代码
+    let field_async_validations = field_validations
+        .iter()
+        .filter(|(_, _, _, async_validation)| !async_validation.is_empty())
+        .collect::<Vec<_>>();
+    let field_async_codes = field_async_validations
+        .iter()
+        .map(|(_, _, _, async_validation)| async_validation);
+    let field_async_mappings = field_async_validations
+        .iter()
+        .map(|(field_name, _, _, async_validation)| {
+            quote! {
+                #field_name => { #async_validation },
+            }
+        });
+    let field_async_group_code = if field_async_validations.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            let fields = <Self as common_validation::GroupFields<G>>::FIELDS;
+            for field in fields {
+                match *field {
+                    #(#field_async_mappings)*
+                    _ => {},
+                }
+            }
+        }
+    };
+    let async_impl = match (&struct_validations.context, &struct_validations.error) {
+        (Some(context), error) => {
+            let error = match error {
+                Some(error) => quote! { #error },
+                None => quote! { common_validation::ValidationErrorEnum },
+            };
+            quote! {
+                impl #generics common_validation::AsyncValidatable for #struct_name #generics {
+                    type Context = #context;
+                    type Error = #error;
+
+                    #[allow(unused_variables)]
+                    fn validate_async(&self, context: &Self::Context) -> impl ::core::future::Future<Output = Result<(), Self::Error>> + Send {
+                        async move {
+                            #(#field_async_codes)*
+                            #(#async_schema_fns(self, context).await?;)*
+                            Ok(())
+                        }
+                    }
+
+                    #[allow(unused_variables)]
+                    fn validate_async_with_group<G: common_validation::ValidationGroup>(&self, context: &Self::Context) -> impl ::core::future::Future<Output = Result<(), Self::Error>> + Send
+                    where
+                        Self: common_validation::GroupFields<G>,
+                    {
+                        async move {
+                            #field_async_group_code
+                            #(#group_async_schema_codes)*
+                            Ok(())
+                        }
+                    }
+                }
+            }
+        },
+        (None, _) if !field_async_validations.is_empty() || !async_schema_fns.is_empty() => {
+            return syn::Error::new_spanned(&input, "使用 async_custom 或 async_schema 时必须通过 #[validate(context = \"类型\")] 配置异步验证的上下文类型")
+                .to_compile_error()
+                .into();
+        },
+        (None, _) => quote! {},
+    };
+
     // 生成分组字段数组
-    let create_fields_array = create_fields.iter().map(|field| quote! { #field });
-    let update_fields_array = update_fields.iter().map(|field| quote! { #field });
-    let query_fields_array = query_fields.iter().map(|field| quote! { #field });
-    let status_update_fields_array = status_update_fields.iter().map(|field| quote! { #field });
-    let page_query_fields_array = page_query_fields.iter().map(|field| quote! { #field });
+    let group_names = groups
+        .iter()
+        .map(|group| group.name.to_string())
+        .collect::<Vec<_>>();
+    let group_fields_arrays = groups
+        .iter()
+        .map(|group| {
+            let fields = &group.fields;
+            quote! { &[#(#fields),*] }
+        })
+        .collect::<Vec<_>>();
+    let group_types = groups.iter().map(GroupFieldsConfig::group_type);
 
     // 生成完整的 impl 块
     let expanded = quote! {
         impl #generics common_validation::Validatable for #struct_name #generics {
             fn validate(&self) -> Result<(), common_validation::ValidationErrorEnum> {
+                #(#type_checks)*
                 #(#field_validation_codes)*
+                #(#schema_fns(self)?;)*
                 Ok(())
             }
-        }
 
-        impl #generics common_validation::GroupFieldsProvider for #struct_name #generics {
-            fn get_create_group_fields() -> &'static [&'static str] {
-                &[#(#create_fields_array),*]
-            }
-
-            fn get_update_group_fields() -> &'static [&'static str] {
-                &[#(#update_fields_array),*]
-            }
-
-            fn get_query_group_fields() -> &'static [&'static str] {
-                &[#(#query_fields_array),*]
-            }
-
-            fn get_status_update_group_fields() -> &'static [&'static str] {
-                &[#(#status_update_fields_array),*]
+            fn validate_all(&self) -> Result<(), common_validation::ValidationErrors> {
+                let mut errors = common_validation::ValidationErrors::new();
+                #(#field_collect_codes)*
+                // 结构体级别的验证函数只在字段全部验证通过时执行
+                if errors.is_empty() {
+                    #(
+                        if let Err(e) = #schema_fns(self) {
+                            errors.add("", e);
+                        }
+                    )*
+                }
+                errors.into_result()
             }
+        }
 
-            fn get_page_query_group_fields() -> &'static [&'static str] {
-                &[#(#page_query_fields_array),*]
+        impl #generics common_validation::GroupFieldsProvider for #struct_name #generics {
+            fn get_group_names() -> &'static [&'static str] {
+                &[#(#group_names),*]
             }
 
             fn get_group_fields(group_name: &str) -> &'static [&'static str] {
                 match group_name {
-                    "create" => Self::get_create_group_fields(),
-                    "update" => Self::get_update_group_fields(),
-                    "query" => Self::get_query_group_fields(),
-                    "status_update" => Self::get_status_update_group_fields(),
-                    "page_query" => Self::get_page_query_group_fields(),
+                    #(#group_names => #group_fields_arrays,)*
                     _ => &[],
                 }
             }
         }
 
+        #(
+            impl #generics common_validation::GroupFields<#group_types> for #struct_name #generics {
+                const FIELDS: &'static [&'static str] = #group_fields_arrays;
+            }
+        )*
+
         impl #generics common_validation::GroupValidatable for #struct_name #generics {
-            fn validate_with_group<G: common_validation::ValidationGroup>(&self) -> Result<(), common_validation::ValidationErrorEnum> {
-                let group_name = G::group_name();
-                let fields = <Self as common_validation::GroupFieldsProvider>::get_group_fields(group_name);
+            fn validate_with_group<G: common_validation::ValidationGroup>(&self) -> Result<(), common_validation::ValidationErrorEnum>
+            where
+                Self: common_validation::GroupFields<G>,
+            {
+                let fields = <Self as common_validation::GroupFields<G>>::FIELDS;
 
                 for field in fields {
                     match *field {
@@ -715,10 +1212,94 @@ pub fn derive_validatable(input: TokenStream) -> TokenStream {
                     }
                 }
 
+                #(#group_schema_codes)*
                 Ok(())
             }
+
+            fn validate_all_with_group<G: common_validation::ValidationGroup>(&self) -> Result<(), common_validation::ValidationErrors>
+            where
+                Self: common_validation::GroupFields<G>,
+            {
+                let fields = <Self as common_validation::GroupFields<G>>::FIELDS;
+                let mut errors = common_validation::ValidationErrors::new();
+
+                for field in fields {
+                    match *field {
+                        #(#field_collect_mappings)*
+                        _ => {},
+                    }
+                }
+
+                if errors.is_empty() {
+                    #(#group_schema_collect_codes)*
+                }
+                errors.into_result()
+            }
         }
+
+        #async_impl
     };
 
     expanded.into()
 }
+
+/// 实现 ValidationGroup trait 的派生宏
+///
+/// 为自定义分组的标记类型生成 [`ValidationGroup`](common_validation::ValidationGroup) 的实现，
+/// 分组名称为去掉 `Group` 后缀的类型名称转换为蛇形命名，与 #[group_fields(...)] 中的分组名称对应
+///
+/// # 示例
+///
+/// ```rust
+/// use common_validation::ValidationGroup;
+/// use common_validation_macros::{ValidatableImpl, ValidationGroup};
+///
+/// /// 批量删除分组
+/// #[derive(ValidationGroup)]
+/// struct BatchDeleteGroup;
+///
+/// #[derive(ValidatableImpl)]
+/// #[group_fields(batch_delete = ["ids"])]
+/// struct DeptIds {
+///     #[validate(not_null, desc = "部门ID列表")]
+///     ids: Vec<String>,
+/// }
+///
+/// assert_eq!(BatchDeleteGroup::group_name(), "batch_delete");
+/// ```
+#[proc_macro_derive(ValidationGroup)]
+pub fn derive_validation_group(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let group_type = &input.ident;
+
+    if !matches!(&input.data, Data::Struct(data) if matches!(data.fields, Fields::Unit)) {
+        return syn::Error::new_spanned(&input, "验证分组的标记类型必须为单元结构体")
+            .to_compile_error()
+            .into();
+    }
+
+    // BatchDeleteGroup -> batch_delete
+    let type_name = group_type.to_string();
+    let group_name = type_name
+        .strip_suffix("Group")
+        .filter(|name| !name.is_empty())
+        .unwrap_or(&type_name)
+        .chars()
+        .enumerate()
+        .fold(String::new(), |mut name, (index, ch)| {
+            if ch.is_ascii_uppercase() && index > 0 {
+                name.push('_');
+            }
+            name.push(ch.to_ascii_lowercase());
+            name
+        });
+
+    quote! {
+        impl common_validation::ValidationGroup for #group_type {
+            fn group_name() -> &'static str {
+                #group_name
+            }
+        }
+    }
+    .into()
+}
@@ -1,5 +1,9 @@
-use common_validation::{CreateGroup, DateTimeFormatEnum, PageQueryGroup, ParameterValidator, QueryGroup, StatusUpdateGroup, UpdateGroup, Validatable, ValidationErrorEnum, ValidationRule, ValidationRulesEnum};
-use common_validation_macros::ValidatableImpl;
+use std::collections::HashMap;
+
+use chrono::NaiveDateTime;
+use common_validation::{AsyncValidatable, CreateGroup, DateTimeFormatEnum, GroupFieldsProvider, GroupValidatable, PageQueryGroup, ParameterValidator, QueryGroup, StatusUpdateGroup, UpdateGroup, Validatable, ValidationErrorEnum, ValidationGroup, ValidationRule, ValidationRulesEnum};
+use common_validation_macros::{ValidatableImpl, ValidationGroup};
+use serde::Deserialize;
 
 // ====================== 基本结构体验证 ======================
 #[derive(Debug, ValidatableImpl)]
@@ -32,7 +36,7 @@ fn test_basic_struct_validation() {
     };
     assert!(matches!(
         invalid_username.validate(),
-        Err(ValidationErrorEnum::Length(_, msg)) if msg.contains("3~20")
+        Err(ValidationErrorEnum::Length(_, msg)) if msg.to_string().contains("3~20")
     ));
 
     // 年龄超出范围
@@ -41,7 +45,7 @@ fn test_basic_struct_validation() {
         age: 150, // 超出最大值
         birthdate: "1990-01-01".to_string(),
     };
-    assert_eq!(invalid_age.validate(), Err(ValidationErrorEnum::NumberMax("年龄".to_string(), 120)));
+    assert_eq!(invalid_age.validate(), Err(ValidationErrorEnum::NumberMax("年龄".to_string(), 120.into())));
 
     // 日期格式错误
     let invalid_date = BasicUser {
@@ -115,7 +119,7 @@ fn test_nested_struct_validation() {
     };
     assert!(matches!(
         invalid_basic.validate(),
-        Err(ValidationErrorEnum::Length(_, msg)) if msg.contains("3~20")
+        Err(ValidationErrorEnum::Length(_, msg)) if msg.to_string().contains("3~20")
     ));
 
     // 嵌套结构体中的错误 - 地址错误
@@ -134,7 +138,7 @@ fn test_nested_struct_validation() {
     };
     assert!(matches!(
         invalid_address.validate(),
-        Err(ValidationErrorEnum::Length(_, msg)) if msg.contains("5~50")
+        Err(ValidationErrorEnum::Length(_, msg)) if msg.to_string().contains("5~50")
     ));
 
     // 可选嵌套结构体中的错误
@@ -189,7 +193,7 @@ fn test_edge_cases() {
     }
 
     let min_test = MinValueTest { value: 9 };
-    assert_eq!(min_test.validate(), Err(ValidationErrorEnum::NumberMin("最小值测试".to_string(), 10)));
+    assert_eq!(min_test.validate(), Err(ValidationErrorEnum::NumberMin("最小值测试".to_string(), 10.into())));
 }
 
 // ====================== 自定义错误消息测试 ======================
@@ -212,7 +216,7 @@ fn test_custom_error_messages() {
     let length_test = CustomMessageTest { field1: "valid".to_string(), field2: "123".to_string() };
     assert!(matches!(
         length_test.validate(),
-        Err(ValidationErrorEnum::Length(_, msg)) if msg.contains("5~10")
+        Err(ValidationErrorEnum::Length(_, msg)) if msg.to_string().contains("5~10")
     ));
 }
 
@@ -279,7 +283,7 @@ fn test_complex_types() {
     };
     assert!(matches!(
         invalid_nested.validate(),
-        Err(ValidationErrorEnum::Length(_, msg)) if msg.contains("3~20")
+        Err(ValidationErrorEnum::Length(_, msg)) if msg.to_string().contains("3~20")
     ));
 
     // 空Vec测试
@@ -387,3 +391,490 @@ fn test_new_validation_rules() {
     let multiple_invalid = MultipleOfTest { value: 16 };
     assert_eq!(multiple_invalid.validate(), Err(ValidationErrorEnum::MultipleOf("倍数测试".to_string(), 5)));
 }
+
+// ====================== 格式验证规则测试 ======================
+#[derive(Debug, ValidatableImpl)]
+struct FormatTest {
+    #[validate(pattern = "[A-Z]{2}\\d{4}", desc = "编码")]
+    code: String,
+
+    #[validate(email, desc = "邮箱")]
+    email: Option<String>,
+
+    #[validate(mobile_phone, desc = "手机号")]
+    telephone: Option<String>,
+
+    #[validate(url, desc = "主页")]
+    homepage: Option<String>,
+
+    #[validate(one_of = ["male", "female"], desc = "性别")]
+    gender: String,
+
+    #[validate(one_of = [0, 1], desc = "状态")]
+    status: i32,
+}
+
+#[test]
+fn test_format_rules() {
+    let valid = FormatTest {
+        code: "AB1234".to_string(),
+        email: Some("dept@example.com".to_string()),
+        telephone: Some("13800000000".to_string()),
+        homepage: Some("https://example.com/logo.png".to_string()),
+        gender: "male".to_string(),
+        status: 1,
+    };
+    assert!(valid.validate().is_ok());
+
+    // 没有值时不验证格式
+    let empty = FormatTest {
+        code: "".to_string(),
+        email: None,
+        telephone: None,
+        homepage: None,
+        gender: "".to_string(),
+        status: 0,
+    };
+    assert!(empty.validate().is_ok());
+
+    let invalid = FormatTest {
+        code: "AB12345".to_string(),
+        email: Some("dept@".to_string()),
+        telephone: Some("12345678901".to_string()),
+        homepage: Some("example.com".to_string()),
+        gender: "unknown".to_string(),
+        status: 2,
+    };
+    // 正则表达式需要完整匹配
+    assert_eq!(invalid.validate(), Err(ValidationErrorEnum::Pattern("编码".to_string())));
+
+    let errors = invalid.validate_all().unwrap_err();
+    assert_eq!(errors.get("email").unwrap()[0].message, "邮箱 不是有效的邮箱地址");
+    assert_eq!(errors.get("telephone").unwrap()[0].message, "手机号 不是有效的手机号码");
+    assert_eq!(errors.get("homepage").unwrap()[0].message, "主页 不是有效的URL");
+    assert_eq!(errors.get("gender").unwrap()[0].message, "性别 必须为以下值之一: male、female");
+    assert_eq!(errors.get("status").unwrap()[0].message, "状态 必须为以下值之一: 0、1");
+}
+
+// ====================== 分组验证测试 ======================
+#[derive(Debug, ValidatableImpl)]
+#[group_fields(
+    create = ["name", "telephone", "status"],
+    update = ["id", "name", "telephone", "status"],
+    status_update = ["id", "status"],
+    query = [],
+    import = ["name", "telephone"]
+)]
+struct GroupTest {
+    #[validate(not_null, exist_length_range(min = 1, max = 32), desc = "ID")]
+    id: Option<String>,
+
+    #[validate(not_null, length_range(min = 1, max = 30), desc = "名称")]
+    name: Option<String>,
+
+    #[validate(exist_length_range(min = 1, max = 11), desc = "电话")]
+    telephone: Option<String>,
+
+    #[validate(not_null, min = 0, max = 1, desc = "状态")]
+    status: Option<i32>,
+}
+
+/// 导入分组
+#[derive(ValidationGroup)]
+struct ImportGroup;
+
+#[test]
+fn test_group_validation() {
+    let create = GroupTest {
+        id: None,
+        name: Some("研发部".to_string()),
+        telephone: None,
+        status: Some(1),
+    };
+    // 创建分组不验证ID
+    assert!(create.validate_with_group::<CreateGroup>().is_ok());
+    // 更新分组要求ID不能为空
+    assert_eq!(create.validate_with_group::<UpdateGroup>(), Err(ValidationErrorEnum::NotNull("ID".to_string())));
+
+    // 没有字段的分组不做验证
+    assert!(create.validate_with_group::<QueryGroup>().is_ok());
+
+    // 自定义分组
+    assert_eq!(ImportGroup::group_name(), "import");
+    assert!(create.validate_with_group::<ImportGroup>().is_ok());
+    assert_eq!(GroupTest { name: None, ..create }.validate_with_group::<ImportGroup>(), Err(ValidationErrorEnum::NotNull("名称".to_string())));
+    assert_eq!(<GroupTest as GroupFieldsProvider>::get_group_names(), ["create", "update", "status_update", "query", "import"]);
+    assert_eq!(<GroupTest as GroupFieldsProvider>::get_group_fields("import"), ["name", "telephone"]);
+    assert!(<GroupTest as GroupFieldsProvider>::get_group_fields("export").is_empty());
+
+    // 长度按字符数计算，30个汉字不超过 varchar(30)
+    let long_name = GroupTest {
+        id: None,
+        name: Some("部".repeat(30)),
+        telephone: Some("13800000000".to_string()),
+        status: Some(0),
+    };
+    assert!(long_name.validate_with_group::<CreateGroup>().is_ok());
+
+    let too_long_name = GroupTest {
+        id: None,
+        name: Some("部".repeat(31)),
+        telephone: None,
+        status: Some(0),
+    };
+    assert!(matches!(
+        too_long_name.validate_with_group::<CreateGroup>(),
+        Err(ValidationErrorEnum::Length(desc, msg)) if desc == "名称" && msg.to_string().contains("1~30")
+    ));
+
+    let too_long_telephone = GroupTest {
+        id: None,
+        name: Some("研发部".to_string()),
+        telephone: Some("138000000000".to_string()),
+        status: Some(0),
+    };
+    assert!(matches!(
+        too_long_telephone.validate_with_group::<CreateGroup>(),
+        Err(ValidationErrorEnum::Length(desc, msg)) if desc == "电话" && msg.to_string().contains("1~11")
+    ));
+
+    // 状态更新分组只验证ID和状态
+    let status_update = GroupTest {
+        id: Some("1".to_string()),
+        name: None,
+        telephone: None,
+        status: Some(2),
+    };
+    assert_eq!(status_update.validate_with_group::<StatusUpdateGroup>(), Err(ValidationErrorEnum::NumberMax("状态".to_string(), 1.into())));
+}
+
+// ====================== 收集模式测试 ======================
+#[derive(Debug, Deserialize, ValidatableImpl)]
+#[serde(rename_all = "camelCase")]
+struct Contact {
+    #[validate(not_null, desc = "联系人")]
+    contact_name: String,
+
+    #[validate(exist_length_range(min = 1, max = 11), desc = "电话")]
+    telephone: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ValidatableImpl)]
+#[serde(rename_all = "camelCase")]
+#[group_fields(
+    create = ["dept_name", "order_num", "contact", "members"],
+    update = ["id", "dept_name"]
+)]
+struct CollectTest {
+    #[validate(not_null, desc = "ID")]
+    id: Option<String>,
+
+    #[validate(not_null, length_range(min = 1, max = 5), desc = "名称")]
+    dept_name: String,
+
+    #[serde(rename = "sort", default)]
+    #[validate(min = 0, max = 99, desc = "排序")]
+    order_num: i32,
+
+    #[validate(nested, desc = "联系人")]
+    contact: Contact,
+
+    #[validate(nested, desc = "成员")]
+    members: Vec<Contact>,
+}
+
+#[test]
+fn test_validate_all() {
+    let contact = |name: &str, telephone: Option<&str>| Contact {
+        contact_name: name.to_string(),
+        telephone: telephone.map(str::to_string),
+    };
+
+    let valid = CollectTest {
+        id: Some("1".to_string()),
+        dept_name: "研发部".to_string(),
+        order_num: 1,
+        contact: contact("张三", Some("13800000000")),
+        members: vec![contact("李四", None)],
+    };
+    assert!(valid.validate_all().is_ok());
+    assert!(valid.validate_all_with_group::<CreateGroup>().is_ok());
+
+    let invalid = CollectTest {
+        id: None,
+        dept_name: "".to_string(),
+        order_num: 100,
+        contact: contact("", Some("138000000000")),
+        members: vec![contact("李四", None), contact("", None)],
+    };
+    // 快速失败模式只返回第一个错误
+    assert_eq!(invalid.validate(), Err(ValidationErrorEnum::NotNull("ID".to_string())));
+
+    // 收集模式返回全部错误，字段路径遵循 serde 重命名，嵌套字段以 `.` 连接，数组元素带下标
+    let errors = invalid.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 6);
+    assert_eq!(errors.get("id").unwrap()[0].rule, "not_null");
+    // 非空验证未通过时不再验证同一字段的其余规则
+    assert_eq!(errors.get("deptName").unwrap().len(), 1);
+    assert_eq!(errors.get("sort").unwrap()[0].rule, "max");
+    assert_eq!(errors.get("contact.contactName").unwrap()[0].message, "联系人 不能为空");
+    assert_eq!(errors.get("contact.telephone").unwrap()[0].rule, "length");
+    assert!(errors.get("members[0].contactName").is_none());
+    assert_eq!(errors.get("members[1].contactName").unwrap()[0].rule, "not_null");
+
+    // 分组收集模式只收集分组内的字段
+    let errors = invalid
+        .validate_all_with_group::<UpdateGroup>()
+        .unwrap_err();
+    assert_eq!(errors.iter().map(|(field, _)| field).collect::<Vec<_>>(), vec!["id", "deptName"]);
+    let errors = invalid
+        .validate_all_with_group::<CreateGroup>()
+        .unwrap_err();
+    assert_eq!(errors.len(), 5);
+    assert!(errors.get("id").is_none());
+}
+
+// ====================== 按原生类型验证测试 ======================
+#[test]
+fn test_typed_validation() {
+    #[derive(Debug, ValidatableImpl)]
+    struct TypedTest {
+        #[validate(min = 10000000000000000000, desc = "大整数")]
+        big: u64,
+
+        #[validate(min = 0.5, max = 99.5, desc = "比例")]
+        ratio: f64,
+
+        #[validate(number_min = -10, number_max = 10, desc = "偏移量")]
+        offset: Option<i64>,
+
+        #[validate(not_null, length_range(min = 1, max = 3), desc = "标签")]
+        tags: Vec<String>,
+
+        #[validate(exist_length_range(min = 1, max = 2), desc = "扩展属性")]
+        extra: HashMap<String, String>,
+
+        #[validate(not_null, desc = "创建时间")]
+        created_at: Option<NaiveDateTime>,
+    }
+
+    let valid = || TypedTest {
+        big: u64::MAX,
+        ratio: 0.5,
+        offset: None,
+        tags: vec!["a".to_string(); 3],
+        extra: HashMap::new(),
+        created_at: NaiveDateTime::parse_from_str("2024-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").ok(),
+    };
+    assert!(valid().validate().is_ok());
+
+    // 超过 i64::MAX 的 u64 值按原生类型比较
+    let big = TypedTest { big: 9_999_999_999_999_999_999, ..valid() };
+    assert_eq!(big.validate(), Err(ValidationErrorEnum::NumberMin("大整数".to_string(), 10_000_000_000_000_000_000u64.into())));
+
+    // 浮点数边界
+    let ratio = TypedTest { ratio: 99.51, ..valid() };
+    assert_eq!(ratio.validate(), Err(ValidationErrorEnum::NumberMax("比例".to_string(), 99.5.into())));
+    assert_eq!(ratio.validate().unwrap_err().to_string(), "比例 值不能大于 99.5");
+
+    // 负数边界，Option 有值时才验证
+    let offset = TypedTest { offset: Some(-11), ..valid() };
+    assert_eq!(offset.validate(), Err(ValidationErrorEnum::NumberMin("偏移量".to_string(), (-10).into())));
+
+    // 集合的非空、长度规则按元素个数验证
+    let tags = TypedTest { tags: Vec::new(), ..valid() };
+    assert_eq!(tags.validate(), Err(ValidationErrorEnum::NotNull("标签".to_string())));
+    let tags = TypedTest { tags: vec!["a".to_string(); 4], ..valid() };
+    assert!(matches!(tags.validate(), Err(ValidationErrorEnum::Length(_, msg)) if msg.to_string() == "必须在 1~3 个元素之间"));
+    let extra = TypedTest {
+        extra: HashMap::from([("a".to_string(), "1".to_string()), ("b".to_string(), "2".to_string()), ("c".to_string(), "3".to_string())]),
+        ..valid()
+    };
+    assert!(matches!(extra.validate(), Err(ValidationErrorEnum::Length(desc, _)) if desc == "扩展属性"));
+
+    let created_at = TypedTest { created_at: None, ..valid() };
+    assert_eq!(created_at.validate(), Err(ValidationErrorEnum::NotNull("创建时间".to_string())));
+}
+
+// ====================== 自定义验证函数测试 ======================
+mod rules {
+    use common_validation::ValidationErrorEnum;
+
+    /// 编码必须以 D 开头
+    pub fn dept_code(code: &str) -> Result<(), ValidationErrorEnum> {
+        if code.starts_with('D') { Ok(()) } else { Err(ValidationErrorEnum::Custom("编码必须以 D 开头".to_string())) }
+    }
+}
+
+/// 结束时间必须晚于开始时间
+fn time_range(param: &CustomTest) -> Result<(), ValidationErrorEnum> {
+    if param.end_time > param.start_time { Ok(()) } else { Err(ValidationErrorEnum::Custom("结束时间必须晚于开始时间".to_string())) }
+}
+
+/// 父部门不能为部门本身
+fn parent_id(param: &CustomTest) -> Result<(), ValidationErrorEnum> {
+    if param.parent_id.as_ref() == Some(&param.id) { Err(ValidationErrorEnum::Custom("父部门不能为部门本身".to_string())) } else { Ok(()) }
+}
+
+#[derive(Debug, Clone, ValidatableImpl)]
+#[group_fields(create = ["code"], update = ["id", "code"])]
+#[validate(schema = "time_range")]
+#[validate(schema = "parent_id", groups = ["update"])]
+struct CustomTest {
+    #[validate(not_null, desc = "ID")]
+    id: String,
+
+    parent_id: Option<String>,
+
+    #[validate(not_null, length = 4, custom = "rules::dept_code", desc = "编码")]
+    code: String,
+
+    start_time: i64,
+
+    end_time: i64,
+}
+
+#[test]
+fn test_custom_validation() {
+    let valid = CustomTest {
+        id: "1".to_string(),
+        parent_id: Some("0".to_string()),
+        code: "D001".to_string(),
+        start_time: 1,
+        end_time: 2,
+    };
+    assert!(valid.validate().is_ok());
+    assert!(valid.validate_all().is_ok());
+
+    // 字段的自定义验证函数在内置规则之后执行
+    let code = CustomTest { code: "A001".to_string(), ..valid.clone() };
+    assert_eq!(code.validate(), Err(ValidationErrorEnum::Custom("编码必须以 D 开头".to_string())));
+    assert_eq!(code.validate_with_group::<CreateGroup>(), Err(ValidationErrorEnum::Custom("编码必须以 D 开头".to_string())));
+    let errors = code.validate_all().unwrap_err();
+    assert_eq!(errors.get("code").unwrap()[0].rule, "custom");
+
+    // 内置规则未通过时收集模式不再执行自定义验证函数，结构体级别的验证函数也不执行
+    let errors = CustomTest {
+        code: "A0001".to_string(),
+        end_time: 0,
+        ..valid.clone()
+    }
+    .validate_all()
+    .unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors.get("code").unwrap()[0].rule, "length");
+
+    // 结构体级别的验证函数在字段验证之后执行，错误的字段路径为空
+    let time = CustomTest { end_time: 0, ..valid.clone() };
+    assert_eq!(time.validate(), Err(ValidationErrorEnum::Custom("结束时间必须晚于开始时间".to_string())));
+    assert_eq!(time.validate_all().unwrap_err().get("").unwrap()[0].message, "结束时间必须晚于开始时间");
+    assert!(time.validate_with_group::<CreateGroup>().is_err());
+
+    // 指定分组的验证函数只在分组验证时的对应分组中执行
+    let parent = CustomTest { parent_id: Some("1".to_string()), ..valid };
+    assert!(parent.validate().is_err());
+    assert!(parent.validate_with_group::<CreateGroup>().is_ok());
+    assert!(parent.validate_all_with_group::<CreateGroup>().is_ok());
+    assert_eq!(parent.validate_with_group::<UpdateGroup>(), Err(ValidationErrorEnum::Custom("父部门不能为部门本身".to_string())));
+    assert_eq!(
+        parent
+            .validate_all_with_group::<UpdateGroup>()
+            .unwrap_err()
+            .get("")
+            .unwrap()[0]
+            .rule,
+        "custom"
+    );
+}
+
+// ====================== 异步验证测试 ======================
+/// 异步验证的上下文，模拟数据访问层的仓储
+trait CodeRepository: Sync {
+    /// 编码是否已存在
+    fn exists(&self, code: &str) -> bool;
+}
+
+struct MemoryCodeRepository(Vec<&'static str>);
+
+impl CodeRepository for MemoryCodeRepository {
+    fn exists(&self, code: &str) -> bool {
+        self.0.contains(&code)
+    }
+}
+
+/// 编码不能已存在
+async fn code_unique(code: &str, repository: &dyn CodeRepository) -> Result<(), ValidationErrorEnum> {
+    if repository.exists(code) { Err(ValidationErrorEnum::Custom("编码已存在".to_string())) } else { Ok(()) }
+}
+
+/// 父编码必须已存在
+async fn parent_exists(param: &AsyncTest, repository: &dyn CodeRepository) -> Result<(), ValidationErrorEnum> {
+    match &param.parent_code {
+        Some(parent_code) if !repository.exists(parent_code) => Err(ValidationErrorEnum::Custom("父编码不存在".to_string())),
+        _ => Ok(()),
+    }
+}
+
+#[derive(Debug, Clone, ValidatableImpl)]
+#[group_fields(create = ["code"], update = ["parent_code"])]
+#[validate(context = "dyn CodeRepository")]
+#[validate(async_schema = "parent_exists", groups = ["update"])]
+struct AsyncTest {
+    #[validate(not_null, length = 4, async_custom = "code_unique", desc = "编码")]
+    code: String,
+
+    parent_code: Option<String>,
+}
+
+#[tokio::test]
+async fn test_async_validation() {
+    let repository = MemoryCodeRepository(vec!["D001"]);
+    let valid = AsyncTest {
+        code: "D002".to_string(),
+        parent_code: Some("D001".to_string()),
+    };
+    assert!(valid.validate_async(&repository).await.is_ok());
+    assert!(
+        valid
+            .validate_async_with_group::<CreateGroup>(&repository)
+            .await
+            .is_ok()
+    );
+    assert!(
+        valid
+            .validate_async_with_group::<UpdateGroup>(&repository)
+            .await
+            .is_ok()
+    );
+
+    // 字段的异步验证函数只在异步验证时执行
+    let code = AsyncTest { code: "D001".to_string(), ..valid.clone() };
+    assert!(code.validate().is_ok());
+    assert_eq!(code.validate_async(&repository).await, Err(ValidationErrorEnum::Custom("编码已存在".to_string())));
+    assert!(
+        code.validate_async_with_group::<CreateGroup>(&repository)
+            .await
+            .is_err()
+    );
+    assert!(
+        code.validate_async_with_group::<UpdateGroup>(&repository)
+            .await
+            .is_ok()
+    );
+
+    // 指定分组的异步验证函数只在对应分组中执行
+    let parent = AsyncTest { parent_code: Some("D009".to_string()), ..valid };
+    assert_eq!(parent.validate_async(&repository).await, Err(ValidationErrorEnum::Custom("父编码不存在".to_string())));
+    assert!(
+        parent
+            .validate_async_with_group::<CreateGroup>(&repository)
+            .await
+            .is_ok()
+    );
+    assert_eq!(
+        parent
+            .validate_async_with_group::<UpdateGroup>(&repository)
+            .await,
+        Err(ValidationErrorEnum::Custom("父编码不存在".to_string()))
+    );
+}
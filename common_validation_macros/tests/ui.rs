@@ -0,0 +1,12 @@
+//! 编译失败测试：错误的验证属性必须给出指向对应位置的编译错误，而不是被静默忽略
+//!
+//! `ui/pass` 中的用例必须编译通过，确保宏不会拒绝引用、`Box<T>`、类型别名等能够验证的字段类型
+//!
+//! 修改宏的错误提示后，使用 `TRYBUILD=overwrite cargo test --test ui` 重新生成 `ui/*.stderr`
+
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+    t.pass("tests/ui/pass/*.rs");
+}
@@ -0,0 +1,9 @@
+use common_validation_macros::ValidatableImpl;
+
+#[derive(ValidatableImpl)]
+struct User {
+    #[validate(not_null, length = 5, desc = "年龄")]
+    age: i32,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use common_validation_macros::ValidatableImpl;
+
+#[derive(ValidatableImpl)]
+struct User {
+    #[validate(emial, desc = "邮箱")]
+    email: String,
+
+    #[validate(not_nul, desc = "用户名")]
+    username: String,
+}
+
+fn main() {}
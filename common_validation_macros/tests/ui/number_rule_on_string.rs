@@ -0,0 +1,9 @@
+use common_validation_macros::ValidatableImpl;
+
+#[derive(ValidatableImpl)]
+struct User {
+    #[validate(positive_number, desc = "用户名")]
+    username: Option<String>,
+}
+
+fn main() {}
@@ -0,0 +1,57 @@
+use common_validation::{Validatable, Validate, ValidateLength, ValidationErrorEnum, ValidationRulesEnum};
+use common_validation_macros::ValidatableImpl;
+
+/// 数值类型的别名，宏按写法无法确定 `min`、`max` 是数值边界，使用 `number_min`、`number_max`
+type Age = u8;
+
+/// 实现了 `ValidateLength` 的自定义类型，可以使用长度规则
+struct Code(String);
+
+impl Validate for Code {
+    fn validate_rule(&self, rule: ValidationRulesEnum, desc: &str) -> Result<(), ValidationErrorEnum> {
+        self.0.validate_rule(rule, desc)
+    }
+}
+
+impl ValidateLength for Code {}
+
+#[derive(ValidatableImpl)]
+struct User<'a> {
+    #[validate(not_null, length = 5, desc = "用户名")]
+    username: &'a str,
+
+    #[validate(email, desc = "邮箱")]
+    email: Box<str>,
+
+    #[validate(length_range(min = 1, max = 10), desc = "昵称")]
+    nickname: Box<String>,
+
+    #[validate(min = 1, max = 3, desc = "标签")]
+    tags: &'a [String],
+
+    #[validate(min = 1, desc = "编号")]
+    serial: u128,
+
+    #[validate(number_min = 18, number_max = 60, desc = "年龄")]
+    age: Age,
+
+    #[validate(max = 6, desc = "编码")]
+    code: Code,
+}
+
+fn main() {
+    let tags = ["rust".to_string()];
+    let user = User {
+        username: "alice",
+        email: "alice@example.com".into(),
+        nickname: Box::new("Alice".to_string()),
+        tags: &tags,
+        serial: u128::MAX,
+        age: 20,
+        code: Code("A001".to_string()),
+    };
+    assert!(user.validate().is_ok());
+
+    let user = User { age: 10, ..user };
+    assert!(user.validate().is_err());
+}
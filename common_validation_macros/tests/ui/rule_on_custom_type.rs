@@ -0,0 +1,22 @@
+use common_validation::{Validate, ValidationErrorEnum, ValidationRulesEnum};
+use common_validation_macros::ValidatableImpl;
+
+/// 只实现了 `Validate` 的自定义类型，不支持长度及数值规则
+struct Age(u8);
+
+impl Validate for Age {
+    fn validate_rule(&self, rule: ValidationRulesEnum, desc: &str) -> Result<(), ValidationErrorEnum> {
+        self.0.validate_rule(rule, desc)
+    }
+}
+
+#[derive(ValidatableImpl)]
+struct User {
+    #[validate(min = 18, desc = "年龄")]
+    age: Age,
+
+    #[validate(email, desc = "编号")]
+    serial: u8,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use common_validation_macros::ValidatableImpl;
+
+#[derive(ValidatableImpl)]
+#[group_fields(create = ["username", "emial"])]
+struct User {
+    #[validate(not_null, desc = "用户名")]
+    username: String,
+
+    #[validate(not_null, email, desc = "邮箱")]
+    email: String,
+}
+
+fn main() {}
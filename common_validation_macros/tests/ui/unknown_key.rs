@@ -0,0 +1,9 @@
+use common_validation_macros::ValidatableImpl;
+
+#[derive(ValidatableImpl)]
+struct User {
+    #[validate(not_null, lenght = 5, desc = "用户名")]
+    username: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use common_validation_macros::ValidatableImpl;
+
+#[derive(ValidatableImpl)]
+struct User {
+    #[validate(length_range(min = 3, maxx = 20), desc = "用户名")]
+    username: String,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use common_validation::ValidationErrorEnum;
+use common_validation_macros::ValidatableImpl;
+
+fn check_user(_user: &User) -> Result<(), ValidationErrorEnum> {
+    Ok(())
+}
+
+#[derive(ValidatableImpl)]
+#[group_fields(create = ["username"])]
+#[validate(schema = "check_user", groups = ["update"])]
+struct User {
+    #[validate(not_null, desc = "用户名")]
+    username: String,
+}
+
+fn main() {}
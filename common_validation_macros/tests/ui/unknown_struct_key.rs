@@ -0,0 +1,10 @@
+use common_validation_macros::ValidatableImpl;
+
+#[derive(ValidatableImpl)]
+#[validate(shcema = "check_user")]
+struct User {
+    #[validate(not_null, desc = "用户名")]
+    username: String,
+}
+
+fn main() {}
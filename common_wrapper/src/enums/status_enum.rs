@@ -5,6 +5,9 @@
 //! 该枚举用于表示系统中各种实体的启用/禁用状态，如部门状态、用户状态等。
 //! 包含启用和禁用两种状态，每种状态都有对应的数值、描述和布尔值。
 
+use crate::i18n::catalog::MESSAGES;
+use crate::i18n::{Locale, Localize};
+
 /// 状态枚举
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum StatusEnum {
@@ -90,6 +93,26 @@ impl StatusEnum {
     }
 }
 
+/// 实现StatusEnum的多语言描述
+impl Localize for StatusEnum {
+    /// 获取指定语言的状态描述
+    ///
+    /// # 参数
+    ///
+    /// * `locale` - 语言
+    ///
+    /// # 返回值
+    ///
+    /// [String] - 指定语言的状态描述，简体中文与 [StatusEnum::desc] 相同
+    fn localize(&self, locale: Locale) -> String {
+        let code = match self {
+            Self::Enable => "STATUS_ENABLE",
+            Self::Disable => "STATUS_DISABLE",
+        };
+        MESSAGES.format(locale, code, &[])
+    }
+}
+
 /// 实现从i32到StatusEnum的转换
 impl From<i32> for StatusEnum {
     /// 从i32转换为StatusEnum
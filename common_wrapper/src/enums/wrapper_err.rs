@@ -5,6 +5,9 @@
 //! 该枚举定义了系统中常用的响应状态码及其对应的消息，
 //! 用于统一API响应格式。
 
+use crate::i18n::catalog::MESSAGES;
+use crate::i18n::{Locale, Localize};
+
 /// 错误枚举
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum WrapperErrEnum {
@@ -23,6 +26,31 @@ pub enum WrapperErrEnum {
     /// 状态码: -2
     /// 消息: "Unknown Error"
     UnknownError = -2,
+    /// 参数错误，请求参数缺失或取值不合法
+    ///
+    /// 状态码: -3
+    /// 消息: "Invalid Param"
+    InvalidParam = -3,
+    /// 资源不存在
+    ///
+    /// 状态码: -4
+    /// 消息: "Not Found"
+    NotFound = -4,
+    /// 与现有数据冲突，例如名称重复、存在关联数据
+    ///
+    /// 状态码: -5
+    /// 消息: "Conflict"
+    Conflict = -5,
+    /// 依赖的服务不可用，例如无法连接数据库
+    ///
+    /// 状态码: -6
+    /// 消息: "Service Unavailable"
+    ServiceUnavailable = -6,
+    /// 数据库操作失败
+    ///
+    /// 状态码: -7
+    /// 消息: "Database Error"
+    DatabaseError = -7,
 }
 
 impl WrapperErrEnum {
@@ -43,10 +71,66 @@ impl WrapperErrEnum {
             Self::Success => "Success",
             Self::Fail => "Fail",
             Self::UnknownError => "Unknown Error",
+            Self::InvalidParam => "Invalid Param",
+            Self::NotFound => "Not Found",
+            Self::Conflict => "Conflict",
+            Self::ServiceUnavailable => "Service Unavailable",
+            Self::DatabaseError => "Database Error",
+        }
+    }
+
+    /// 获取对应的HTTP状态码
+    ///
+    /// 各Web框架的响应实现据此设置HTTP状态码，保证同一响应码在所有框架中返回相同的HTTP状态：
+    ///
+    /// - 成功: 200
+    /// - 失败、参数错误: 400
+    /// - 资源不存在: 404
+    /// - 数据冲突: 409
+    /// - 服务不可用: 503
+    /// - 数据库错误、未知错误: 500
+    ///
+    /// # 返回值
+    ///
+    /// [u16] - HTTP状态码
+    pub const fn http_status(&self) -> u16 {
+        match self {
+            Self::Success => 200,
+            Self::Fail | Self::InvalidParam => 400,
+            Self::NotFound => 404,
+            Self::Conflict => 409,
+            Self::ServiceUnavailable => 503,
+            Self::DatabaseError | Self::UnknownError => 500,
         }
     }
 }
 
+/// 实现WrapperErrEnum的多语言消息
+impl Localize for WrapperErrEnum {
+    /// 获取指定语言的默认消息
+    ///
+    /// # 参数
+    ///
+    /// * `locale` - 语言
+    ///
+    /// # 返回值
+    ///
+    /// [String] - 指定语言的默认消息，英文与 [WrapperErrEnum::message] 相同
+    fn localize(&self, locale: Locale) -> String {
+        let code = match self {
+            Self::Success => "SUCCESS",
+            Self::Fail => "FAIL",
+            Self::UnknownError => "UNKNOWN_ERROR",
+            Self::InvalidParam => "INVALID_PARAM",
+            Self::NotFound => "NOT_FOUND",
+            Self::Conflict => "CONFLICT",
+            Self::ServiceUnavailable => "SERVICE_UNAVAILABLE",
+            Self::DatabaseError => "DATABASE_ERROR",
+        };
+        MESSAGES.format(locale, code, &[])
+    }
+}
+
 /// 实现从i32到WrapperErrEnum的转换
 impl From<i32> for WrapperErrEnum {
     /// 从i32转换为WrapperErrEnum
@@ -63,6 +147,11 @@ impl From<i32> for WrapperErrEnum {
             1 => Self::Success,
             -1 => Self::Fail,
             -2 => Self::UnknownError,
+            -3 => Self::InvalidParam,
+            -4 => Self::NotFound,
+            -5 => Self::Conflict,
+            -6 => Self::ServiceUnavailable,
+            -7 => Self::DatabaseError,
             // 处理非法值
             _ => Self::UnknownError,
         }
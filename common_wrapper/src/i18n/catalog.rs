@@ -0,0 +1,112 @@
+//! # 消息目录
+//!
+//! 按消息编码保存各语言的消息模板，模板中的 `{0}`、`{1}` 等占位符按顺序替换为参数
+
+use std::fmt::Display;
+
+use super::Locale;
+
+/// 消息目录
+///
+/// 每种语言的消息为 `(消息编码, 消息模板)` 的列表，英文缺少的消息使用简体中文，都缺少时输出消息编码本身
+///
+/// # 示例
+///
+/// ```rust
+/// use common_wrapper::i18n::{Locale, MessageCatalog};
+///
+/// static MESSAGES: MessageCatalog = MessageCatalog::new(&[("NOT_NULL", "{0} 不能为空")], &[("NOT_NULL", "{0} must not be empty")]);
+///
+/// assert_eq!(MESSAGES.format(Locale::EnUs, "NOT_NULL", &[&"name"]), "name must not be empty");
+/// assert_eq!(MESSAGES.format(Locale::ZhCn, "UNKNOWN", &[]), "UNKNOWN");
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct MessageCatalog {
+    /// 简体中文消息
+    zh_cn: &'static [(&'static str, &'static str)],
+    /// 英文消息
+    en_us: &'static [(&'static str, &'static str)],
+}
+
+impl MessageCatalog {
+    /// 创建消息目录
+    ///
+    /// # 参数
+    ///
+    /// * `zh_cn` - 简体中文消息
+    /// * `en_us` - 英文消息
+    pub const fn new(zh_cn: &'static [(&'static str, &'static str)], en_us: &'static [(&'static str, &'static str)]) -> Self {
+        Self { zh_cn, en_us }
+    }
+
+    /// 获取消息模板
+    ///
+    /// # 参数
+    ///
+    /// * `locale` - 语言
+    /// * `code` - 消息编码
+    ///
+    /// # 返回值
+    ///
+    /// [Option]<&'static str> - 消息模板，指定语言缺少时使用简体中文，都缺少时返回None
+    pub fn get(&self, locale: Locale, code: &str) -> Option<&'static str> {
+        let find = |messages: &'static [(&'static str, &'static str)]| {
+            messages
+                .iter()
+                .find(|(key, _)| *key == code)
+                .map(|(_, message)| *message)
+        };
+        match locale {
+            Locale::ZhCn => find(self.zh_cn),
+            Locale::EnUs => find(self.en_us).or_else(|| find(self.zh_cn)),
+        }
+    }
+
+    /// 获取消息并替换占位符
+    ///
+    /// # 参数
+    ///
+    /// * `locale` - 语言
+    /// * `code` - 消息编码
+    /// * `args` - 按顺序替换 `{0}`、`{1}` 等占位符的参数
+    ///
+    /// # 返回值
+    ///
+    /// [String] - 消息，消息编码不存在时返回消息编码本身
+    pub fn format(&self, locale: Locale, code: &str, args: &[&dyn Display]) -> String {
+        let Some(template) = self.get(locale, code) else {
+            return code.to_string();
+        };
+        args.iter()
+            .enumerate()
+            .fold(template.to_string(), |message, (index, arg)| message.replace(&format!("{{{index}}}"), &arg.to_string()))
+    }
+}
+
+/// 响应码及状态的消息，参见: [WrapperErrEnum](crate::enums::wrapper_err::WrapperErrEnum)、[StatusEnum](crate::enums::status_enum::StatusEnum)
+pub(crate) static MESSAGES: MessageCatalog = MessageCatalog::new(
+    &[
+        ("SUCCESS", "成功"),
+        ("FAIL", "失败"),
+        ("UNKNOWN_ERROR", "未知错误"),
+        ("INVALID_PARAM", "参数错误"),
+        ("NOT_FOUND", "资源不存在"),
+        ("CONFLICT", "数据冲突"),
+        ("SERVICE_UNAVAILABLE", "服务不可用"),
+        ("DATABASE_ERROR", "数据库错误"),
+        ("STATUS_ENABLE", "启用"),
+        ("STATUS_DISABLE", "禁用"),
+    ],
+    &[
+        ("SUCCESS", "Success"),
+        ("FAIL", "Fail"),
+        ("UNKNOWN_ERROR", "Unknown Error"),
+        ("INVALID_PARAM", "Invalid Param"),
+        ("NOT_FOUND", "Not Found"),
+        ("CONFLICT", "Conflict"),
+        ("SERVICE_UNAVAILABLE", "Service Unavailable"),
+        ("DATABASE_ERROR", "Database Error"),
+        ("STATUS_ENABLE", "Enabled"),
+        ("STATUS_DISABLE", "Disabled"),
+    ],
+);
@@ -0,0 +1,58 @@
+//! # 语言提取器
+//!
+//! 为 [Locale] 实现各Web框架的请求提取器，控制器可以直接声明 [Locale] 参数获取请求使用的语言
+//! 这个模块是可选的，只有在启用 axum_response、actix_responder 或 rocket_responder 特性时才会编译
+//!
+//! 语言从 `Accept-Language` 请求头解析，请求头缺失或无法解析时使用默认语言，提取总是成功，参见: [Locale::from_accept_language]
+
+use super::Locale;
+
+/// 请求头名称
+const ACCEPT_LANGUAGE: &str = "accept-language";
+
+/// 根据请求头的值选择语言，请求头缺失时使用默认语言
+fn locale_from_header(value: Option<&str>) -> Locale {
+    value.map(Locale::from_accept_language).unwrap_or_default()
+}
+
+/// 为 Locale 实现 Axum 请求提取器
+#[cfg(feature = "axum_response")]
+#[axum::async_trait]
+impl<S: Send + Sync> axum::extract::FromRequestParts<S> for Locale {
+    type Rejection = std::convert::Infallible;
+
+    async fn from_request_parts(parts: &mut axum::http::request::Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Ok(locale_from_header(
+            parts
+                .headers
+                .get(ACCEPT_LANGUAGE)
+                .and_then(|value| value.to_str().ok()),
+        ))
+    }
+}
+
+/// 为 Locale 实现 Actix Web 请求提取器
+#[cfg(feature = "actix_responder")]
+impl actix_web::FromRequest for Locale {
+    type Error = std::convert::Infallible;
+    type Future = std::future::Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &actix_web::HttpRequest, _payload: &mut actix_web::dev::Payload) -> Self::Future {
+        std::future::ready(Ok(locale_from_header(
+            req.headers()
+                .get(ACCEPT_LANGUAGE)
+                .and_then(|value| value.to_str().ok()),
+        )))
+    }
+}
+
+/// 为 Locale 实现 Rocket 请求守卫
+#[cfg(feature = "rocket_responder")]
+#[rocket::async_trait]
+impl<'r> rocket::request::FromRequest<'r> for Locale {
+    type Error = std::convert::Infallible;
+
+    async fn from_request(request: &'r rocket::Request<'_>) -> rocket::request::Outcome<Self, Self::Error> {
+        rocket::request::Outcome::Success(locale_from_header(request.headers().get_one(ACCEPT_LANGUAGE)))
+    }
+}
@@ -0,0 +1,80 @@
+//! # 语言
+//!
+//! 定义支持的语言，并按 `Accept-Language` 请求头选择请求使用的语言
+
+/// 语言
+///
+/// 未指定或不支持请求的语言时使用简体中文
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Locale {
+    /// 简体中文
+    #[default]
+    ZhCn,
+    /// 英文
+    EnUs,
+}
+
+impl Locale {
+    /// 获取语言标签
+    ///
+    /// # 返回值
+    ///
+    /// [&'static str] - 语言标签，如 `zh-CN`、`en-US`
+    pub const fn code(&self) -> &'static str {
+        match self {
+            Self::ZhCn => "zh-CN",
+            Self::EnUs => "en-US",
+        }
+    }
+
+    /// 根据语言标签获取语言
+    ///
+    /// 只比较主语言，不区分大小写，如 `zh`、`zh-Hans`、`zh-TW` 都视为简体中文，`en`、`en-GB` 都视为英文
+    ///
+    /// # 参数
+    ///
+    /// * `tag` - 语言标签，类型: [&str]
+    ///
+    /// # 返回值
+    ///
+    /// [Option]<[Locale]> - 对应的语言，不支持时返回None
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        let language = tag.trim().split(['-', '_']).next().unwrap_or_default();
+        if language.eq_ignore_ascii_case("zh") {
+            Some(Self::ZhCn)
+        } else if language.eq_ignore_ascii_case("en") {
+            Some(Self::EnUs)
+        } else {
+            None
+        }
+    }
+
+    /// 根据 `Accept-Language` 请求头选择语言
+    ///
+    /// 按权重 `q` 从高到低选择第一个支持的语言，权重相同时按出现顺序，没有支持的语言时使用默认语言
+    ///
+    /// # 参数
+    ///
+    /// * `header` - `Accept-Language` 请求头的值，如 `en-US,en;q=0.9,zh-CN;q=0.8`
+    ///
+    /// # 返回值
+    ///
+    /// [Locale] - 请求使用的语言
+    pub fn from_accept_language(header: &str) -> Self {
+        let mut selected = None;
+        for item in header.split(',') {
+            let mut parts = item.split(';');
+            let Some(locale) = parts.next().and_then(Self::from_tag) else {
+                continue;
+            };
+            let quality = parts
+                .find_map(|param| param.trim().strip_prefix("q="))
+                .map_or(Some(1.0), |quality| quality.trim().parse::<f32>().ok())
+                .unwrap_or(0.0);
+            if quality > 0.0 && selected.is_none_or(|(_, selected_quality)| quality > selected_quality) {
+                selected = Some((locale, quality));
+            }
+        }
+        selected.map(|(locale, _)| locale).unwrap_or_default()
+    }
+}
@@ -0,0 +1,45 @@
+//! # 国际化模块
+//!
+//! 按请求的语言输出响应消息，目前支持简体中文（zh-CN，默认）和英文（en-US）
+//!
+//! ## 模块组成
+//!
+//! - [locale]: 语言类型 [Locale]，可以从 `Accept-Language` 请求头解析
+//! - [catalog]: 消息目录 [MessageCatalog]，按消息编码保存各语言的消息模板
+//! - [extract]: 各Web框架中从请求头提取 [Locale] 的实现（可选）
+//!
+//! 响应码、状态等枚举通过 [Localize] 输出指定语言的消息，各业务模块可以定义自己的消息目录
+//!
+//! ## 使用示例
+//!
+//! ```rust
+//! use common_wrapper::enums::wrapper_err::WrapperErrEnum;
+//! use common_wrapper::i18n::{Locale, Localize};
+//!
+//! let locale = Locale::from_accept_language("en-US,en;q=0.9,zh-CN;q=0.8");
+//! assert_eq!(locale, Locale::EnUs);
+//! assert_eq!(WrapperErrEnum::NotFound.localize(locale), "Not Found");
+//! assert_eq!(WrapperErrEnum::NotFound.localize(Locale::ZhCn), "资源不存在");
+//! ```
+
+pub mod catalog;
+#[cfg(any(feature = "axum_response", feature = "actix_responder", feature = "rocket_responder"))]
+pub mod extract;
+pub mod locale;
+
+pub use catalog::MessageCatalog;
+pub use locale::Locale;
+
+/// 可以按语言输出消息的类型
+pub trait Localize {
+    /// 获取指定语言的消息
+    ///
+    /// # 参数
+    ///
+    /// * `locale` - 语言
+    ///
+    /// # 返回值
+    ///
+    /// [String] - 指定语言的消息
+    fn localize(&self, locale: Locale) -> String;
+}
@@ -8,12 +8,14 @@
 //! - 支持多种数据类型（单数据、列表、分页）
 //! - 可自定义响应码和消息
 //! - 与serde集成，支持序列化和反序列化
+//! - 按请求的 `Accept-Language` 输出简体中文或英文消息
 //!
 //! ## 模块组织
 //!
-//! 该库分为两个主要模块：
+//! 该库分为三个主要模块：
 //! - [enums][]: 包含各种枚举类型，如错误码枚举等
 //! - [wrapper][]: 包含各种响应包装器实现
+//! - [i18n][]: 包含语言类型和消息目录
 //!
 //! ## 使用示例
 //!
@@ -52,6 +54,14 @@ pub mod enums;
 /// - [wrapper::response_trait::ResponseTrait]: 响应trait接口
 pub mod wrapper;
 
+/// 国际化模块
+///
+/// 包含多语言消息相关的类型，如：
+/// - [i18n::Locale]: 语言类型，可以作为请求提取器使用
+/// - [i18n::MessageCatalog]: 消息目录
+/// - [i18n::Localize]: 按语言输出消息的trait
+pub mod i18n;
+
 // 重新导出常用的类型，方便外部使用
 pub use wrapper::list_wrapper::ListWrapper;
 pub use wrapper::page_info::PageInfo;
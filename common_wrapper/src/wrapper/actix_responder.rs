@@ -0,0 +1,55 @@
+//! Actix Web Responder 实现模块
+//!
+//! 为 common_wrapper 中的包装器类型提供 Actix Web 框架的 Responder 实现，控制器可以直接返回包装器
+//! 这个模块是可选的，只有在启用 actix_responder 特性时才会编译
+//!
+//! 响应体为包装器的JSON，HTTP状态码由包装器的响应码决定，参见: [ResponseTrait::http_status]
+
+use actix_web::body::BoxBody;
+use actix_web::http::StatusCode;
+use actix_web::{HttpRequest, HttpResponse, Responder};
+use serde::Serialize;
+
+use crate::wrapper::{ListWrapper, PageWrapper, ResponseTrait, ResponseWrapper, SingleWrapper};
+
+/// 以包装器的响应码对应的HTTP状态码返回JSON响应
+fn respond_with_status<W: ResponseTrait + Serialize>(wrapper: W) -> HttpResponse {
+    let status = StatusCode::from_u16(wrapper.http_status()).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+    HttpResponse::build(status).json(wrapper)
+}
+
+/// 为 SingleWrapper 实现 Actix Web Responder
+impl<T: Serialize> Responder for SingleWrapper<T> {
+    type Body = BoxBody;
+
+    fn respond_to(self, _req: &HttpRequest) -> HttpResponse<Self::Body> {
+        respond_with_status(self)
+    }
+}
+
+/// 为 ListWrapper 实现 Actix Web Responder
+impl<T: Serialize> Responder for ListWrapper<T> {
+    type Body = BoxBody;
+
+    fn respond_to(self, _req: &HttpRequest) -> HttpResponse<Self::Body> {
+        respond_with_status(self)
+    }
+}
+
+/// 为 PageWrapper 实现 Actix Web Responder
+impl<T: Serialize> Responder for PageWrapper<T> {
+    type Body = BoxBody;
+
+    fn respond_to(self, _req: &HttpRequest) -> HttpResponse<Self::Body> {
+        respond_with_status(self)
+    }
+}
+
+/// 为 ResponseWrapper 实现 Actix Web Responder
+impl Responder for ResponseWrapper {
+    type Body = BoxBody;
+
+    fn respond_to(self, _req: &HttpRequest) -> HttpResponse<Self::Body> {
+        respond_with_status(self)
+    }
+}
@@ -0,0 +1,47 @@
+//! Axum IntoResponse 实现模块
+//!
+//! 为 common_wrapper 中的包装器类型提供 Axum Web 框架的 IntoResponse 实现，控制器可以直接返回包装器
+//! 这个模块是可选的，只有在启用 axum_response 特性时才会编译
+//!
+//! 响应体为包装器的JSON，HTTP状态码由包装器的响应码决定，参见: [ResponseTrait::http_status]
+
+use axum::Json;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use serde::Serialize;
+
+use crate::wrapper::{ListWrapper, PageWrapper, ResponseTrait, ResponseWrapper, SingleWrapper};
+
+/// 以包装器的响应码对应的HTTP状态码返回JSON响应
+fn respond_with_status<W: ResponseTrait + Serialize>(wrapper: W) -> Response {
+    let status = StatusCode::from_u16(wrapper.http_status()).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+    (status, Json(wrapper)).into_response()
+}
+
+/// 为 SingleWrapper 实现 Axum IntoResponse
+impl<T: Serialize> IntoResponse for SingleWrapper<T> {
+    fn into_response(self) -> Response {
+        respond_with_status(self)
+    }
+}
+
+/// 为 ListWrapper 实现 Axum IntoResponse
+impl<T: Serialize> IntoResponse for ListWrapper<T> {
+    fn into_response(self) -> Response {
+        respond_with_status(self)
+    }
+}
+
+/// 为 PageWrapper 实现 Axum IntoResponse
+impl<T: Serialize> IntoResponse for PageWrapper<T> {
+    fn into_response(self) -> Response {
+        respond_with_status(self)
+    }
+}
+
+/// 为 ResponseWrapper 实现 Axum IntoResponse
+impl IntoResponse for ResponseWrapper {
+    fn into_response(self) -> Response {
+        respond_with_status(self)
+    }
+}
@@ -8,6 +8,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::enums::wrapper_err::WrapperErrEnum;
+use crate::i18n::Locale;
 use crate::wrapper::response_trait::ResponseTrait;
 use crate::wrapper::response_wrapper::ResponseWrapper;
 
@@ -126,6 +128,35 @@ impl<T> ListWrapper<T> {
         self.data = None;
     }
 
+    /// 设置为指定的错误状态并附带消息
+    ///
+    /// 将当前实例设置为指定的错误状态，并用指定的消息更新响应消息，同时清空数据部分
+    ///
+    /// # 参数
+    ///
+    /// * `err` - 错误类型，类型: [WrapperErrEnum]
+    /// * `msg` - 错误消息，类型: impl [Into]<[String]>，可以接受 &str 或 String
+    pub fn set_error(&mut self, err: WrapperErrEnum, msg: impl Into<String>) {
+        self.base.set_error(err, msg);
+        self.data = None;
+    }
+
+    /// 将默认消息转换为指定语言
+    ///
+    /// 只转换与响应码对应的默认消息，自定义消息保持不变，参见: [ResponseWrapper::localize]
+    ///
+    /// # 参数
+    ///
+    /// * `locale` - 语言
+    ///
+    /// # 返回值
+    ///
+    /// [ListWrapper]<T> - 转换消息后的ListWrapper实例
+    pub fn localize(mut self, locale: Locale) -> Self {
+        self.base.localize_message(locale);
+        self
+    }
+
     /// 获取基础响应包装器的引用
     ///
     /// # 返回值
@@ -259,4 +290,15 @@ impl<T> ResponseTrait for ListWrapper<T> {
         self.base.set_unknown_error(msg);
         self.data = None;
     }
+
+    /// 设置为指定的错误响应，并自定义消息，数据清空
+    ///
+    /// # 参数
+    ///
+    /// * `err` - 错误类型，类型: [WrapperErrEnum]
+    /// * `msg` - 自定义的错误消息，类型: impl [Into]<[String]>
+    fn set_error(&mut self, err: WrapperErrEnum, msg: impl Into<String>) {
+        self.base.set_error(err, msg);
+        self.data = None;
+    }
 }
@@ -11,7 +11,13 @@
 //! - [response_wrapper]: 基础响应包装器，包含响应码和消息
 //! - [response_trait]: 响应trait接口，定义响应包装的公共行为
 //! - [rocket_responder]: Rocket框架的响应实现（可选）
+//! - [axum_response]: Axum框架的响应实现（可选）
+//! - [actix_responder]: Actix Web框架的响应实现（可选）
 
+#[cfg(feature = "actix_responder")]
+pub mod actix_responder;
+#[cfg(feature = "axum_response")]
+pub mod axum_response;
 pub mod list_wrapper;
 pub mod page_info;
 pub mod page_wrapper;
@@ -5,6 +5,8 @@
 //! 所有响应包装类型都应实现此trait以提供统一的接口，
 //! 使得上层代码可以以统一的方式处理不同类型的响应。
 
+use crate::enums::wrapper_err::WrapperErrEnum;
+
 /// 定义响应包装的公共行为
 ///
 /// 所有响应包装类型都应实现此trait以提供统一的接口
@@ -53,4 +55,25 @@ pub trait ResponseTrait {
     ///
     /// * `msg` - 未知错误消息，类型: impl [Into]<[String]>，可以接受 &str 或 String
     fn set_unknown_error(&mut self, msg: impl Into<String>);
+
+    /// 设置指定的错误状态和消息
+    ///
+    /// 将响应设置为 [WrapperErrEnum] 中的任一错误状态，用于区分参数错误、资源不存在、数据冲突等失败原因
+    ///
+    /// # 参数
+    ///
+    /// * `err` - 错误类型，类型: [WrapperErrEnum]
+    /// * `msg` - 错误消息，类型: impl [Into]<[String]>，可以接受 &str 或 String
+    fn set_error(&mut self, err: WrapperErrEnum, msg: impl Into<String>);
+
+    /// 获取HTTP状态码
+    ///
+    /// 根据响应码获取对应的HTTP状态码，未定义的响应码按未知错误处理，参见: [WrapperErrEnum::http_status]
+    ///
+    /// # 返回值
+    ///
+    /// [u16] - HTTP状态码
+    fn http_status(&self) -> u16 {
+        WrapperErrEnum::from(self.get_code()).http_status()
+    }
 }
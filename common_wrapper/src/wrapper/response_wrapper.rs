@@ -7,6 +7,7 @@
 //! 也可以作为其他复杂响应包装器的基础。
 
 use crate::enums::wrapper_err::WrapperErrEnum;
+use crate::i18n::{Locale, Localize};
 use crate::wrapper::response_trait::ResponseTrait;
 use serde::{Deserialize, Serialize};
 
@@ -41,6 +42,7 @@ pub struct ResponseWrapper {
     /// - 1: 成功 (WrapperErrEnum::Success)
     /// - -1: 失败 (WrapperErrEnum::Fail)
     /// - -2: 未知错误 (WrapperErrEnum::UnknownError)
+    /// - -3 ~ -7: 参数错误、资源不存在、数据冲突、服务不可用、数据库错误，参见 [WrapperErrEnum]
     code: i32,
     /// 响应消息，描述请求结果的详细信息
     ///
@@ -101,6 +103,44 @@ impl ResponseWrapper {
     pub fn unknown_error_default() -> Self {
         ResponseWrapper::from(WrapperErrEnum::UnknownError)
     }
+
+    /// 将默认消息转换为指定语言
+    ///
+    /// 只转换与响应码对应的默认消息（[WrapperErrEnum::message]），自定义消息保持不变
+    ///
+    /// # 参数
+    ///
+    /// * `locale` - 语言
+    ///
+    /// # 返回值
+    ///
+    /// [ResponseWrapper] - 转换消息后的ResponseWrapper实例
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// use common_wrapper::{ResponseTrait, ResponseWrapper};
+    /// use common_wrapper::i18n::Locale;
+    ///
+    /// assert_eq!(ResponseWrapper::success_default().localize(Locale::ZhCn).get_message(), "成功");
+    /// assert_eq!(ResponseWrapper::new(-1, "名称重复").localize(Locale::EnUs).get_message(), "名称重复");
+    /// ```
+    pub fn localize(mut self, locale: Locale) -> Self {
+        self.localize_message(locale);
+        self
+    }
+
+    /// 将默认消息转换为指定语言，供其他包装器使用
+    ///
+    /// # 参数
+    ///
+    /// * `locale` - 语言
+    pub(crate) fn localize_message(&mut self, locale: Locale) {
+        let err = WrapperErrEnum::from(self.code);
+        if err as i32 == self.code && self.message == err.message() {
+            self.message = err.localize(locale);
+        }
+    }
 }
 
 /// 实现 WrapperErrEnum 到 ResponseWrapper 的转换
@@ -167,4 +207,15 @@ impl ResponseTrait for ResponseWrapper {
         self.code = WrapperErrEnum::UnknownError as i32;
         self.message = msg.into();
     }
+
+    /// 设置为指定的错误响应，并自定义消息
+    ///
+    /// # 参数
+    ///
+    /// * `err` - 错误类型，类型: [WrapperErrEnum]
+    /// * `msg` - 自定义的错误消息，类型: impl [Into]<[String]>
+    fn set_error(&mut self, err: WrapperErrEnum, msg: impl Into<String>) {
+        self.code = err as i32;
+        self.message = msg.into();
+    }
 }
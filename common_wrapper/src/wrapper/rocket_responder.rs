@@ -2,7 +2,11 @@
 //!
 //! 为 common_wrapper 中的包装器类型提供 Rocket Web 框架的 Responder 实现
 //! 这个模块是可选的，只有在启用 rocket_responder 特性时才会编译
+//!
+//! 响应体为包装器的JSON，HTTP状态码由包装器的响应码决定，参见: [ResponseTrait::http_status]
 
+#[cfg(feature = "rocket_responder")]
+use rocket::http::Status;
 #[cfg(feature = "rocket_responder")]
 use rocket::response::Responder;
 #[cfg(feature = "rocket_responder")]
@@ -10,14 +14,20 @@ use rocket::serde::json::Json;
 #[cfg(feature = "rocket_responder")]
 use serde::Serialize;
 
-use crate::wrapper::{ListWrapper, PageWrapper, SingleWrapper};
+use crate::wrapper::{ListWrapper, PageWrapper, ResponseTrait, ResponseWrapper, SingleWrapper};
+
+/// 以包装器的响应码对应的HTTP状态码返回JSON响应
+#[cfg(feature = "rocket_responder")]
+fn respond_with_status<W: ResponseTrait + Serialize>(wrapper: W, request: &rocket::Request<'_>) -> rocket::response::Result<'static> {
+    let status = Status::from_code(wrapper.http_status()).unwrap_or(Status::InternalServerError);
+    (status, Json(wrapper)).respond_to(request)
+}
 
 /// 为 SingleWrapper 实现 Rocket Responder
 #[cfg(feature = "rocket_responder")]
 impl<'r, T: Serialize> Responder<'r, 'static> for SingleWrapper<T> {
     fn respond_to(self, request: &'r rocket::Request<'_>) -> rocket::response::Result<'static> {
-        let json = Json(self);
-        json.respond_to(request)
+        respond_with_status(self, request)
     }
 }
 
@@ -25,8 +35,7 @@ impl<'r, T: Serialize> Responder<'r, 'static> for SingleWrapper<T> {
 #[cfg(feature = "rocket_responder")]
 impl<'r, T: Serialize> Responder<'r, 'static> for ListWrapper<T> {
     fn respond_to(self, request: &'r rocket::Request<'_>) -> rocket::response::Result<'static> {
-        let json = Json(self);
-        json.respond_to(request)
+        respond_with_status(self, request)
     }
 }
 
@@ -34,7 +43,14 @@ impl<'r, T: Serialize> Responder<'r, 'static> for ListWrapper<T> {
 #[cfg(feature = "rocket_responder")]
 impl<'r, T: Serialize> Responder<'r, 'static> for PageWrapper<T> {
     fn respond_to(self, request: &'r rocket::Request<'_>) -> rocket::response::Result<'static> {
-        let json = Json(self);
-        json.respond_to(request)
+        respond_with_status(self, request)
+    }
+}
+
+/// 为 ResponseWrapper 实现 Rocket Responder
+#[cfg(feature = "rocket_responder")]
+impl<'r> Responder<'r, 'static> for ResponseWrapper {
+    fn respond_to(self, request: &'r rocket::Request<'_>) -> rocket::response::Result<'static> {
+        respond_with_status(self, request)
     }
 }
@@ -8,6 +8,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::enums::wrapper_err::WrapperErrEnum;
+use crate::i18n::Locale;
 use crate::wrapper::response_trait::ResponseTrait;
 use crate::wrapper::response_wrapper::ResponseWrapper;
 
@@ -24,6 +26,7 @@ pub struct SingleWrapper<T> {
     /// 类型: [ResponseWrapper]
     ///
     /// 包含响应的状态码和消息，用于表示操作结果
+    #[serde(flatten)]
     base: ResponseWrapper,
     /// 数据对象
     ///
@@ -122,6 +125,49 @@ impl<T> SingleWrapper<T> {
         self.data = None;
     }
 
+    /// 设置为指定的错误状态并附带消息
+    ///
+    /// 将当前实例设置为指定的错误状态，并用指定的消息更新响应消息，同时清空数据部分
+    ///
+    /// # 参数
+    ///
+    /// * `err` - 错误类型，类型: [WrapperErrEnum]
+    /// * `msg` - 错误消息，类型: impl [Into]<[String]>，可以接受 &str 或 String
+    pub fn set_error(&mut self, err: WrapperErrEnum, msg: impl Into<String>) {
+        self.base.set_error(err, msg);
+        self.data = None;
+    }
+
+    /// 设置为指定的错误状态并附带消息和数据
+    ///
+    /// 与 [SingleWrapper::set_error] 不同，数据部分保留为错误的详细信息，如按字段汇总的参数验证错误
+    ///
+    /// # 参数
+    ///
+    /// * `err` - 错误类型，类型: [WrapperErrEnum]
+    /// * `msg` - 错误消息，类型: impl [Into]<[String]>，可以接受 &str 或 String
+    /// * `data` - 错误详情，类型: T
+    pub fn set_error_with_data(&mut self, err: WrapperErrEnum, msg: impl Into<String>, data: T) {
+        self.base.set_error(err, msg);
+        self.data = Some(data);
+    }
+
+    /// 将默认消息转换为指定语言
+    ///
+    /// 只转换与响应码对应的默认消息，自定义消息保持不变，参见: [ResponseWrapper::localize]
+    ///
+    /// # 参数
+    ///
+    /// * `locale` - 语言
+    ///
+    /// # 返回值
+    ///
+    /// [SingleWrapper]<T> - 转换消息后的SingleWrapper实例
+    pub fn localize(mut self, locale: Locale) -> Self {
+        self.base.localize_message(locale);
+        self
+    }
+
     /// 获取基础响应包装器的引用
     ///
     /// # 返回值
@@ -258,4 +304,15 @@ impl<T> ResponseTrait for SingleWrapper<T> {
         self.base.set_unknown_error(msg);
         self.data = None;
     }
+
+    /// 设置为指定的错误响应，并自定义消息，数据清空
+    ///
+    /// # 参数
+    ///
+    /// * `err` - 错误类型，类型: [WrapperErrEnum]
+    /// * `msg` - 自定义的错误消息，类型: impl [Into]<[String]>
+    fn set_error(&mut self, err: WrapperErrEnum, msg: impl Into<String>) {
+        self.base.set_error(err, msg);
+        self.data = None;
+    }
 }
@@ -0,0 +1,98 @@
+//! Actix Web 响应测试
+//!
+//! 使用 Actix Web 提供的测试工具调用返回包装器的处理函数，验证响应状态码和响应体，需要启用 `actix_responder` 特性：
+//! `cargo test -p common_wrapper --features actix_responder --test actix_responder_test`
+
+#![cfg(feature = "actix_responder")]
+
+use actix_web::http::{StatusCode, header};
+use actix_web::test::{self, TestRequest};
+use actix_web::{App, web};
+use common_wrapper::enums::wrapper_err::WrapperErrEnum;
+use common_wrapper::{ListWrapper, PageWrapper, ResponseWrapper, SingleWrapper};
+use serde_json::Value;
+
+/// 返回单对象包装器
+async fn single() -> SingleWrapper<&'static str> {
+    let mut wrapper = SingleWrapper::new();
+    wrapper.set_success("data");
+    wrapper
+}
+
+/// 返回列表包装器
+async fn list() -> ListWrapper<i32> {
+    let mut wrapper = ListWrapper::new();
+    wrapper.set_fail("查询失败");
+    wrapper
+}
+
+/// 返回分页包装器
+async fn page() -> PageWrapper<i32> {
+    let mut wrapper = PageWrapper::new();
+    wrapper.set_error(WrapperErrEnum::ServiceUnavailable, "数据库连接失败");
+    wrapper
+}
+
+/// 返回基础响应包装器
+async fn response() -> ResponseWrapper {
+    ResponseWrapper::from(WrapperErrEnum::NotFound)
+}
+
+/// 发送GET请求，返回响应状态码、Content-Type和JSON响应体
+async fn get_json(uri: &str) -> (StatusCode, String, Value) {
+    let app = test::init_service(
+        App::new()
+            .route("/single", web::get().to(single))
+            .route("/list", web::get().to(list))
+            .route("/page", web::get().to(page))
+            .route("/response", web::get().to(response)),
+    )
+    .await;
+    let resp = test::call_service(&app, TestRequest::get().uri(uri).to_request()).await;
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .unwrap()
+        .to_str()
+        .unwrap()
+        .to_string();
+    (status, content_type, test::read_body_json(resp).await)
+}
+
+/// 测试单对象包装器
+#[actix_web::test]
+async fn test_single_wrapper() {
+    let (status, content_type, body) = get_json("/single").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(content_type, "application/json");
+    assert_eq!(body["code"], 1);
+    assert_eq!(body["data"], "data");
+}
+
+/// 测试列表包装器
+#[actix_web::test]
+async fn test_list_wrapper() {
+    let (status, _, body) = get_json("/list").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["code"], -1);
+    assert_eq!(body["message"], "查询失败");
+}
+
+/// 测试分页包装器
+#[actix_web::test]
+async fn test_page_wrapper() {
+    let (status, _, body) = get_json("/page").await;
+    assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(body["code"], -6);
+    assert_eq!(body["total"], 0);
+}
+
+/// 测试基础响应包装器
+#[actix_web::test]
+async fn test_response_wrapper() {
+    let (status, _, body) = get_json("/response").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["code"], -4);
+    assert_eq!(body["message"], "Not Found");
+}
@@ -0,0 +1,119 @@
+//! Axum 响应测试
+//!
+//! 通过Axum路由调用返回包装器的处理函数，验证响应状态码和响应体，需要启用 `axum_response` 特性：
+//! `cargo test -p common_wrapper --features axum_response --test axum_response_test`
+
+#![cfg(feature = "axum_response")]
+
+use axum::body::{Body, to_bytes};
+use axum::http::{Request, StatusCode, header};
+use axum::{Router, routing::get};
+use common_wrapper::enums::wrapper_err::WrapperErrEnum;
+use common_wrapper::i18n::Locale;
+use common_wrapper::{ListWrapper, PageWrapper, ResponseWrapper, SingleWrapper};
+use serde_json::Value;
+use tower::ServiceExt;
+
+/// 创建测试路由，每个路由返回一种包装器
+fn app() -> Router {
+    Router::new()
+        .route(
+            "/single",
+            get(|| async {
+                let mut wrapper = SingleWrapper::new();
+                wrapper.set_success("data");
+                wrapper
+            }),
+        )
+        .route(
+            "/list",
+            get(|| async {
+                let mut wrapper: ListWrapper<i32> = ListWrapper::new();
+                wrapper.set_fail("查询失败");
+                wrapper
+            }),
+        )
+        .route(
+            "/page",
+            get(|| async {
+                let mut wrapper: PageWrapper<i32> = PageWrapper::new();
+                wrapper.set_error(WrapperErrEnum::ServiceUnavailable, "数据库连接失败");
+                wrapper
+            }),
+        )
+        .route("/response", get(|| async { ResponseWrapper::from(WrapperErrEnum::NotFound) }))
+        .route("/localized", get(|locale: Locale| async move { ResponseWrapper::from(WrapperErrEnum::NotFound).localize(locale) }))
+}
+
+/// 发送GET请求，返回响应状态码、Content-Type和JSON响应体
+async fn get_json(uri: &str) -> (StatusCode, String, Value) {
+    let response = app()
+        .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let status = response.status();
+    let content_type = response.headers()[header::CONTENT_TYPE]
+        .to_str()
+        .unwrap()
+        .to_string();
+    let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, content_type, serde_json::from_slice(&bytes).unwrap())
+}
+
+/// 测试单对象包装器
+#[tokio::test]
+async fn test_single_wrapper() {
+    let (status, content_type, body) = get_json("/single").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(content_type, "application/json");
+    assert_eq!(body["code"], 1);
+    assert_eq!(body["data"], "data");
+}
+
+/// 测试列表包装器
+#[tokio::test]
+async fn test_list_wrapper() {
+    let (status, _, body) = get_json("/list").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["code"], -1);
+    assert_eq!(body["message"], "查询失败");
+}
+
+/// 测试分页包装器
+#[tokio::test]
+async fn test_page_wrapper() {
+    let (status, _, body) = get_json("/page").await;
+    assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(body["code"], -6);
+    assert_eq!(body["total"], 0);
+}
+
+/// 测试基础响应包装器
+#[tokio::test]
+async fn test_response_wrapper() {
+    let (status, _, body) = get_json("/response").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["code"], -4);
+    assert_eq!(body["message"], "Not Found");
+}
+
+/// 测试按请求头提取语言并转换默认消息
+#[tokio::test]
+async fn test_locale_extractor() {
+    let (_, _, body) = get_json("/localized").await;
+    assert_eq!(body["message"], "资源不存在");
+
+    let response = app()
+        .oneshot(
+            Request::get("/localized")
+                .header(header::ACCEPT_LANGUAGE, "en-US,en;q=0.9")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let body: Value = serde_json::from_slice(&bytes).unwrap();
+    assert_eq!(body["message"], "Not Found");
+}
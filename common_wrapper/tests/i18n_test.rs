@@ -0,0 +1,63 @@
+//! 国际化测试
+//!
+//! 验证 `Accept-Language` 请求头的解析、消息目录的查找和默认消息的转换
+
+use common_wrapper::enums::status_enum::StatusEnum;
+use common_wrapper::enums::wrapper_err::WrapperErrEnum;
+use common_wrapper::i18n::{Locale, Localize, MessageCatalog};
+use common_wrapper::{PageWrapper, ResponseTrait, SingleWrapper};
+
+/// 测试解析 `Accept-Language` 请求头
+#[test]
+fn test_locale_from_accept_language() {
+    assert_eq!(Locale::from_accept_language("en-US"), Locale::EnUs);
+    assert_eq!(Locale::from_accept_language("EN"), Locale::EnUs);
+    assert_eq!(Locale::from_accept_language("zh-TW,zh;q=0.9"), Locale::ZhCn);
+    // 按权重选择
+    assert_eq!(Locale::from_accept_language("zh-CN;q=0.5, en-GB;q=0.8"), Locale::EnUs);
+    // 权重相同时按出现顺序
+    assert_eq!(Locale::from_accept_language("en, zh"), Locale::EnUs);
+    // 跳过不支持的语言和权重为0的语言
+    assert_eq!(Locale::from_accept_language("fr-FR, en;q=0, zh;q=0.1"), Locale::ZhCn);
+    // 没有支持的语言时使用默认语言
+    assert_eq!(Locale::from_accept_language("fr-FR, *"), Locale::ZhCn);
+    assert_eq!(Locale::from_accept_language(""), Locale::default());
+    assert_eq!(Locale::EnUs.code(), "en-US");
+}
+
+/// 测试消息目录的查找和占位符替换
+#[test]
+fn test_message_catalog() {
+    static MESSAGES: MessageCatalog = MessageCatalog::new(&[("RANGE", "{0} 必须在 {1} 到 {2} 之间"), ("ONLY_ZH", "只有中文")], &[("RANGE", "{0} must be between {1} and {2}")]);
+
+    assert_eq!(MESSAGES.format(Locale::ZhCn, "RANGE", &[&"年龄", &1, &120]), "年龄 必须在 1 到 120 之间");
+    assert_eq!(MESSAGES.format(Locale::EnUs, "RANGE", &[&"age", &1, &120]), "age must be between 1 and 120");
+    // 英文缺少时使用简体中文
+    assert_eq!(MESSAGES.get(Locale::EnUs, "ONLY_ZH"), Some("只有中文"));
+    // 消息编码不存在时输出消息编码本身
+    assert_eq!(MESSAGES.get(Locale::ZhCn, "MISSING"), None);
+    assert_eq!(MESSAGES.format(Locale::EnUs, "MISSING", &[]), "MISSING");
+}
+
+/// 测试枚举的多语言消息
+#[test]
+fn test_localize_enums() {
+    assert_eq!(WrapperErrEnum::Conflict.localize(Locale::ZhCn), "数据冲突");
+    assert_eq!(WrapperErrEnum::Conflict.localize(Locale::EnUs), WrapperErrEnum::Conflict.message());
+    assert_eq!(StatusEnum::Enable.localize(Locale::ZhCn), StatusEnum::Enable.desc());
+    assert_eq!(StatusEnum::Disable.localize(Locale::EnUs), "Disabled");
+}
+
+/// 测试包装器只转换默认消息
+#[test]
+fn test_localize_wrapper() {
+    let mut wrapper = SingleWrapper::new();
+    wrapper.set_success(1);
+    let wrapper = wrapper.localize(Locale::ZhCn);
+    assert_eq!(wrapper.get_message(), "成功");
+    assert_eq!(wrapper.get_data(), &Some(1));
+
+    let mut wrapper: PageWrapper<i32> = PageWrapper::new();
+    wrapper.set_error(WrapperErrEnum::Conflict, "已存在相同部门名称!");
+    assert_eq!(wrapper.localize(Locale::EnUs).get_message(), "已存在相同部门名称!");
+}
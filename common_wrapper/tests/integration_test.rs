@@ -152,3 +152,77 @@ fn test_response_wrapper() {
     assert_eq!(response2.get_message(), "Custom unknown error message");
     assert!(!response2.is_success());
 }
+
+/// 测试设置指定错误类型
+#[test]
+fn test_set_error() {
+    // 基础响应使用错误类型对应的响应码
+    let mut response = ResponseWrapper::success_default();
+    response.set_error(WrapperErrEnum::NotFound, "部门不存在");
+    assert_eq!(response.get_code(), -4);
+    assert_eq!(response.get_message(), "部门不存在");
+    assert!(!response.is_success());
+
+    // 列表、单对象包装器同时清空数据
+    let mut list_wrapper = ListWrapper::new();
+    list_wrapper.set_success(vec![1, 2, 3]);
+    list_wrapper.set_error(WrapperErrEnum::ServiceUnavailable, "数据库连接失败");
+    assert_eq!(list_wrapper.get_code(), WrapperErrEnum::ServiceUnavailable as i32);
+    assert!(list_wrapper.get_data().is_none());
+
+    let mut single_wrapper = SingleWrapper::new();
+    single_wrapper.set_success("data");
+    single_wrapper.set_error(WrapperErrEnum::InvalidParam, "参数错误");
+    assert_eq!(single_wrapper.get_code(), WrapperErrEnum::InvalidParam as i32);
+    assert!(single_wrapper.get_data().is_none());
+
+    // 附带错误详情时保留数据，响应码、消息与数据平铺在同一层
+    let mut single_wrapper = SingleWrapper::new();
+    single_wrapper.set_error_with_data(WrapperErrEnum::InvalidParam, "部门名称 不能为空", vec!["name"]);
+    assert_eq!(single_wrapper.get_code(), WrapperErrEnum::InvalidParam as i32);
+    assert_eq!(single_wrapper.get_data(), &Some(vec!["name"]));
+    let json = serde_json::to_value(&single_wrapper).unwrap();
+    assert_eq!(json["code"], WrapperErrEnum::InvalidParam as i32);
+    assert_eq!(json["message"], "部门名称 不能为空");
+    assert_eq!(json["data"][0], "name");
+
+    // 分页包装器同时重置分页信息
+    let mut page_wrapper = PageWrapper::new();
+    page_wrapper.set_success(vec![1, 2, 3], 30, 2, 3);
+    page_wrapper.set_error(WrapperErrEnum::DatabaseError, "查询失败");
+    assert_eq!(page_wrapper.get_code(), WrapperErrEnum::DatabaseError as i32);
+    assert!(page_wrapper.get_data().is_none());
+    assert_eq!(page_wrapper.get_total(), 0);
+
+    // 响应码与错误类型可以互相转换
+    for err in [WrapperErrEnum::InvalidParam, WrapperErrEnum::NotFound, WrapperErrEnum::Conflict, WrapperErrEnum::ServiceUnavailable, WrapperErrEnum::DatabaseError] {
+        assert_eq!(WrapperErrEnum::from(err as i32), err);
+    }
+}
+
+/// 测试响应码到HTTP状态码的映射
+#[test]
+fn test_http_status() {
+    assert_eq!(WrapperErrEnum::Success.http_status(), 200);
+    assert_eq!(WrapperErrEnum::Fail.http_status(), 400);
+    assert_eq!(WrapperErrEnum::InvalidParam.http_status(), 400);
+    assert_eq!(WrapperErrEnum::NotFound.http_status(), 404);
+    assert_eq!(WrapperErrEnum::Conflict.http_status(), 409);
+    assert_eq!(WrapperErrEnum::ServiceUnavailable.http_status(), 503);
+    assert_eq!(WrapperErrEnum::DatabaseError.http_status(), 500);
+    assert_eq!(WrapperErrEnum::UnknownError.http_status(), 500);
+
+    // 包装器按当前响应码获取HTTP状态码
+    let mut list_wrapper = ListWrapper::new();
+    list_wrapper.set_success(vec![1, 2, 3]);
+    assert_eq!(list_wrapper.http_status(), 200);
+    list_wrapper.set_fail("查询失败");
+    assert_eq!(list_wrapper.http_status(), 400);
+
+    let mut page_wrapper: PageWrapper<i32> = PageWrapper::new();
+    page_wrapper.set_error(WrapperErrEnum::Conflict, "名称重复");
+    assert_eq!(page_wrapper.http_status(), 409);
+
+    // 未定义的响应码按未知错误处理
+    assert_eq!(ResponseWrapper::new(100, "未定义").http_status(), 500);
+}
@@ -0,0 +1,89 @@
+//! Rocket 响应测试
+//!
+//! 使用 Rocket 提供的本地客户端调用返回包装器的路由，验证响应状态码和响应体，需要启用 `rocket_responder` 特性：
+//! `cargo test -p common_wrapper --features rocket_responder --test rocket_responder_test`
+
+#![cfg(feature = "rocket_responder")]
+
+use common_wrapper::enums::wrapper_err::WrapperErrEnum;
+use common_wrapper::{ListWrapper, PageWrapper, ResponseWrapper, SingleWrapper};
+use rocket::http::{ContentType, Status};
+use rocket::local::blocking::Client;
+use serde_json::Value;
+
+/// 返回单对象包装器
+#[rocket::get("/single")]
+fn single() -> SingleWrapper<&'static str> {
+    let mut wrapper = SingleWrapper::new();
+    wrapper.set_success("data");
+    wrapper
+}
+
+/// 返回列表包装器
+#[rocket::get("/list")]
+fn list() -> ListWrapper<i32> {
+    let mut wrapper = ListWrapper::new();
+    wrapper.set_fail("查询失败");
+    wrapper
+}
+
+/// 返回分页包装器
+#[rocket::get("/page")]
+fn page() -> PageWrapper<i32> {
+    let mut wrapper = PageWrapper::new();
+    wrapper.set_error(WrapperErrEnum::ServiceUnavailable, "数据库连接失败");
+    wrapper
+}
+
+/// 返回基础响应包装器
+#[rocket::get("/response")]
+fn response() -> ResponseWrapper {
+    ResponseWrapper::from(WrapperErrEnum::NotFound)
+}
+
+/// 发送GET请求，返回响应状态码、Content-Type和JSON响应体
+fn get_json(uri: &str) -> (Status, Option<ContentType>, Value) {
+    let rocket = rocket::build().mount("/", rocket::routes![single, list, page, response]);
+    let client = Client::tracked(rocket).expect("valid rocket instance");
+    let response = client.get(uri).dispatch();
+    let status = response.status();
+    let content_type = response.content_type();
+    (status, content_type, response.into_json().unwrap())
+}
+
+/// 测试单对象包装器
+#[test]
+fn test_single_wrapper() {
+    let (status, content_type, body) = get_json("/single");
+    assert_eq!(status, Status::Ok);
+    assert_eq!(content_type, Some(ContentType::JSON));
+    assert_eq!(body["code"], 1);
+    assert_eq!(body["data"], "data");
+}
+
+/// 测试列表包装器
+#[test]
+fn test_list_wrapper() {
+    let (status, _, body) = get_json("/list");
+    assert_eq!(status, Status::BadRequest);
+    assert_eq!(body["code"], -1);
+    assert_eq!(body["message"], "查询失败");
+}
+
+/// 测试分页包装器
+#[test]
+fn test_page_wrapper() {
+    let (status, _, body) = get_json("/page");
+    assert_eq!(status, Status::ServiceUnavailable);
+    assert_eq!(body["code"], -6);
+    assert_eq!(body["total"], 0);
+}
+
+/// 测试基础响应包装器
+#[test]
+fn test_response_wrapper() {
+    let (status, _, body) = get_json("/response");
+    assert_eq!(status, Status::NotFound);
+    assert_eq!(body["code"], -4);
+    assert_eq!(body["message"], "Not Found");
+}
@@ -20,6 +20,8 @@ pub struct Model {
     pub update_by: Option<String>,
     pub update_time: Option<DateTime>,
     pub remark: Option<String>,
+    pub ancestors: Option<String>,
+    pub deleted_at: Option<DateTime>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -0,0 +1,168 @@
+//! 部门业务错误
+//!
+//! 部门业务层的所有失败都以 [DeptError] 表示，参数错误、部门不存在、名称重复等业务规则错误各自对应一个变体，
+//! 数据访问层的错误通过 [DeptError::Repository] 原样传递。
+//! 通过 [WrapperErrEnum] 和 [ResponseWrapper] 的转换实现，业务错误被映射为响应中不同的响应码。
+//!
+//! 错误描述均来自消息目录 [MESSAGES]：Display 按错误码输出简体中文的错误描述，[Localize] 输出指定语言的错误描述。
+
+use crate::errors::RepositoryError;
+use common_wrapper::ResponseWrapper;
+use common_wrapper::enums::wrapper_err::WrapperErrEnum;
+use common_wrapper::i18n::{Locale, Localize, MessageCatalog};
+use thiserror::Error;
+
+/// 部门业务的消息，包括部门业务错误、数据访问层错误及查询失败的消息
+pub static MESSAGES: MessageCatalog = MessageCatalog::new(
+    &[
+        ("DEPT_ID_REQUIRED", "部门ID不能为空!"),
+        ("DEPT_PARENT_ID_REQUIRED", "父级部门ID不能为空!"),
+        ("DEPT_PARENT_IS_SELF", "父部门不能为部门本身!"),
+        ("DEPT_STATUS_REQUIRED", "部门状态不能为空!"),
+        ("DEPT_STATUS_INVALID", "传入的部门状态错误!"),
+        ("DEPT_NOT_FOUND", "传入的部门信息不存在!"),
+        ("DEPT_PARENT_NOT_FOUND", "传入的父级部门信息不存在!"),
+        ("DEPT_PARENT_DISABLED", "传入的父级部门已停用!"),
+        ("DEPT_DUPLICATE_NAME", "已存在相同部门名称!"),
+        ("DEPT_HAS_CHILDREN", "该部门下存在子部门，无法删除!"),
+        ("DEPT_CYCLIC_PARENT", "父级部门不能为部门本身或其下级部门!"),
+        ("DEPT_ANCESTOR_DISABLED", "上级部门中存在已停用的部门，无法启用!"),
+        ("DEPT_NOT_DELETED", "该部门未删除，无法恢复或彻底删除!"),
+        ("DEPT_TREE_QUERY_FAILED", "查询部门树失败: {0}"),
+        ("DEPT_LIST_QUERY_FAILED", "查询部门列表失败: {0}"),
+        ("DEPT_PAGE_QUERY_FAILED", "分页查询部门列表失败: {0}"),
+        ("REPOSITORY_CONNECTION", "数据库连接失败: {0}"),
+        ("REPOSITORY_DUPLICATE", "数据已存在: {0}"),
+        ("REPOSITORY_TRANSACTION", "事务操作失败: {0}"),
+        ("REPOSITORY_DATABASE", "数据库操作失败: {0}"),
+    ],
+    &[
+        ("DEPT_ID_REQUIRED", "Department ID must not be empty!"),
+        ("DEPT_PARENT_ID_REQUIRED", "Parent department ID must not be empty!"),
+        ("DEPT_PARENT_IS_SELF", "The parent department cannot be the department itself!"),
+        ("DEPT_STATUS_REQUIRED", "Department status must not be empty!"),
+        ("DEPT_STATUS_INVALID", "Invalid department status!"),
+        ("DEPT_NOT_FOUND", "The department does not exist!"),
+        ("DEPT_PARENT_NOT_FOUND", "The parent department does not exist!"),
+        ("DEPT_PARENT_DISABLED", "The parent department is disabled!"),
+        ("DEPT_DUPLICATE_NAME", "A department with the same name already exists!"),
+        ("DEPT_HAS_CHILDREN", "The department has sub-departments and cannot be deleted!"),
+        ("DEPT_CYCLIC_PARENT", "The parent department cannot be the department itself or one of its sub-departments!"),
+        ("DEPT_ANCESTOR_DISABLED", "A superior department is disabled, so the department cannot be enabled!"),
+        ("DEPT_NOT_DELETED", "The department has not been deleted, so it cannot be restored or purged!"),
+        ("DEPT_TREE_QUERY_FAILED", "Failed to query the department tree: {0}"),
+        ("DEPT_LIST_QUERY_FAILED", "Failed to query the department list: {0}"),
+        ("DEPT_PAGE_QUERY_FAILED", "Failed to query the department page: {0}"),
+        ("REPOSITORY_CONNECTION", "Database connection failed: {0}"),
+        ("REPOSITORY_DUPLICATE", "Data already exists: {0}"),
+        ("REPOSITORY_TRANSACTION", "Transaction failed: {0}"),
+        ("REPOSITORY_DATABASE", "Database operation failed: {0}"),
+    ],
+);
+
+/// 部门业务错误
+#[derive(Debug, Error)]
+pub enum DeptError {
+    /// 请求参数缺失或取值不合法，参数为消息目录中的消息编码，如 `DEPT_ID_REQUIRED`，不是消息编码时原样作为错误描述
+    #[error("{}", MESSAGES.format(Locale::ZhCn, .0, &[]))]
+    InvalidParam(String),
+    /// 部门不存在
+    #[error("{}", MESSAGES.format(Locale::ZhCn, self.code(), &[]))]
+    NotFound,
+    /// 父级部门不存在
+    #[error("{}", MESSAGES.format(Locale::ZhCn, self.code(), &[]))]
+    ParentNotFound,
+    /// 父级部门已停用
+    #[error("{}", MESSAGES.format(Locale::ZhCn, self.code(), &[]))]
+    ParentDisabled,
+    /// 同一父级部门下已存在相同名称的部门
+    #[error("{}", MESSAGES.format(Locale::ZhCn, self.code(), &[]))]
+    DuplicateName,
+    /// 部门下存在子部门，无法删除
+    #[error("{}", MESSAGES.format(Locale::ZhCn, self.code(), &[]))]
+    HasChildren,
+    /// 父级部门为部门本身或其下级部门，移动后部门层级会出现环
+    #[error("{}", MESSAGES.format(Locale::ZhCn, self.code(), &[]))]
+    CyclicParent,
+    /// 上级部门中存在已停用的部门，无法启用部门
+    #[error("{}", MESSAGES.format(Locale::ZhCn, self.code(), &[]))]
+    AncestorDisabled,
+    /// 部门未删除，无法恢复或彻底删除
+    #[error("{}", MESSAGES.format(Locale::ZhCn, self.code(), &[]))]
+    NotDeleted,
+    /// 数据访问层错误
+    #[error(transparent)]
+    Repository(#[from] RepositoryError),
+}
+
+impl DeptError {
+    /// 获取错误码
+    ///
+    /// 错误码在各版本间保持不变，数据访问层错误返回 [RepositoryError::code]
+    ///
+    /// # 返回值
+    ///
+    /// [&'static str] - 与错误类型对应的错误码
+    pub const fn code(&self) -> &'static str {
+        match self {
+            Self::InvalidParam(_) => "DEPT_INVALID_PARAM",
+            Self::NotFound => "DEPT_NOT_FOUND",
+            Self::ParentNotFound => "DEPT_PARENT_NOT_FOUND",
+            Self::ParentDisabled => "DEPT_PARENT_DISABLED",
+            Self::DuplicateName => "DEPT_DUPLICATE_NAME",
+            Self::HasChildren => "DEPT_HAS_CHILDREN",
+            Self::CyclicParent => "DEPT_CYCLIC_PARENT",
+            Self::AncestorDisabled => "DEPT_ANCESTOR_DISABLED",
+            Self::NotDeleted => "DEPT_NOT_DELETED",
+            Self::Repository(e) => e.code(),
+        }
+    }
+
+    /// 转换为指定语言的响应，响应码由错误类型决定，响应消息为指定语言的错误描述
+    ///
+    /// # 参数
+    ///
+    /// * `locale` - 语言
+    ///
+    /// # 返回值
+    ///
+    /// [ResponseWrapper] - 错误响应
+    pub fn to_response(&self, locale: Locale) -> ResponseWrapper {
+        ResponseWrapper::new(WrapperErrEnum::from(self) as i32, self.localize(locale))
+    }
+}
+
+/// 按错误码输出指定语言的错误描述，数据访问层错误参见 [RepositoryError] 的实现
+impl Localize for DeptError {
+    fn localize(&self, locale: Locale) -> String {
+        match self {
+            Self::InvalidParam(code) => MESSAGES.format(locale, code, &[]),
+            Self::Repository(e) => e.localize(locale),
+            _ => MESSAGES.format(locale, self.code(), &[]),
+        }
+    }
+}
+
+/// 实现从部门业务错误到响应错误类型的转换
+///
+/// - 参数错误、父级部门不存在: [WrapperErrEnum::InvalidParam]
+/// - 部门不存在: [WrapperErrEnum::NotFound]
+/// - 父级部门已停用、名称重复、存在子部门、父级部门为部门本身或其下级部门、上级部门已停用、部门未删除: [WrapperErrEnum::Conflict]
+/// - 数据访问层错误: 参见 [RepositoryError] 到 [WrapperErrEnum] 的转换
+impl From<&DeptError> for WrapperErrEnum {
+    fn from(e: &DeptError) -> Self {
+        match e {
+            DeptError::InvalidParam(_) | DeptError::ParentNotFound => Self::InvalidParam,
+            DeptError::NotFound => Self::NotFound,
+            DeptError::ParentDisabled | DeptError::DuplicateName | DeptError::HasChildren | DeptError::CyclicParent | DeptError::AncestorDisabled | DeptError::NotDeleted => Self::Conflict,
+            DeptError::Repository(e) => Self::from(e),
+        }
+    }
+}
+
+/// 实现从部门业务错误到响应包装的转换，响应码由错误类型决定，响应消息为简体中文的错误描述
+impl From<DeptError> for ResponseWrapper {
+    fn from(e: DeptError) -> Self {
+        ResponseWrapper::new(WrapperErrEnum::from(&e) as i32, e.to_string())
+    }
+}
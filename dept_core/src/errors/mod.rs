@@ -0,0 +1,15 @@
+//! 错误类型模块
+//!
+//! 该模块定义了数据访问层和业务层的错误类型。
+//! 每个错误都带有稳定的错误码，调用方据此区分失败原因，而不是匹配错误消息。
+//!
+//! # 模块组织
+//!
+//! - [repository_error] - 数据访问层错误，参见: [crate::repositories]
+//! - [dept_error] - 部门业务错误，参见: [crate::services::dept]
+
+pub mod dept_error;
+pub mod repository_error;
+
+pub use dept_error::DeptError;
+pub use repository_error::RepositoryError;
@@ -0,0 +1,124 @@
+//! 数据访问层错误
+//!
+//! 各ORM实现的错误统一转换为 [RepositoryError]，按数据库不可用、数据重复、事务使用不当和其他数据库错误分类，
+//! 业务层据此决定返回给调用方的错误类型。
+
+use crate::errors::dept_error::MESSAGES;
+use common_wrapper::enums::wrapper_err::WrapperErrEnum;
+use common_wrapper::i18n::{Locale, Localize};
+use std::error::Error as StdError;
+use thiserror::Error;
+
+/// 数据访问层错误
+#[derive(Debug, Error)]
+pub enum RepositoryError {
+    /// 无法连接数据库，例如数据库未启动、连接池已关闭或获取连接超时
+    #[error("{}", MESSAGES.format(Locale::ZhCn, self.code(), &[.0]))]
+    Connection(#[source] Box<dyn StdError + Send + Sync>),
+    /// 违反唯一约束，例如主键重复
+    #[error("{}", MESSAGES.format(Locale::ZhCn, self.code(), &[.0]))]
+    Duplicate(String),
+    /// 事务使用不当，例如在不支持的实现中嵌套事务、在未开启事务的仓储上提交
+    #[error("{}", MESSAGES.format(Locale::ZhCn, self.code(), &[.0]))]
+    Transaction(String),
+    /// 其他数据库错误，例如SQL执行失败、结果映射失败
+    #[error("{}", MESSAGES.format(Locale::ZhCn, self.code(), &[.0]))]
+    Database(#[source] Box<dyn StdError + Send + Sync>),
+}
+
+impl RepositoryError {
+    /// 获取错误码
+    ///
+    /// 错误码在各版本间保持不变，可用于日志检索和调用方的错误判断
+    ///
+    /// # 返回值
+    ///
+    /// [&'static str] - 与错误类型对应的错误码
+    pub const fn code(&self) -> &'static str {
+        match self {
+            Self::Connection(_) => "REPOSITORY_CONNECTION",
+            Self::Duplicate(_) => "REPOSITORY_DUPLICATE",
+            Self::Transaction(_) => "REPOSITORY_TRANSACTION",
+            Self::Database(_) => "REPOSITORY_DATABASE",
+        }
+    }
+}
+
+/// 按错误码输出指定语言的错误描述，数据库返回的具体说明原样输出
+impl Localize for RepositoryError {
+    fn localize(&self, locale: Locale) -> String {
+        match self {
+            Self::Connection(e) | Self::Database(e) => MESSAGES.format(locale, self.code(), &[e]),
+            Self::Duplicate(detail) | Self::Transaction(detail) => MESSAGES.format(locale, self.code(), &[detail]),
+        }
+    }
+}
+
+/// 实现从数据访问层错误到响应错误类型的转换
+///
+/// - 数据重复: [WrapperErrEnum::Conflict]
+/// - 无法连接数据库: [WrapperErrEnum::ServiceUnavailable]
+/// - 事务使用不当及其他数据库错误: [WrapperErrEnum::DatabaseError]
+impl From<&RepositoryError> for WrapperErrEnum {
+    fn from(e: &RepositoryError) -> Self {
+        match e {
+            RepositoryError::Duplicate(_) => Self::Conflict,
+            RepositoryError::Connection(_) => Self::ServiceUnavailable,
+            RepositoryError::Transaction(_) | RepositoryError::Database(_) => Self::DatabaseError,
+        }
+    }
+}
+
+/// 阻塞线程执行失败（例如发生panic）时按数据库错误处理
+impl From<tokio::task::JoinError> for RepositoryError {
+    fn from(e: tokio::task::JoinError) -> Self {
+        Self::Database(Box::new(e))
+    }
+}
+
+/// 实现从SQLx错误的转换，连接池及网络错误视为数据库不可用
+#[cfg(feature = "sqlx_impl")]
+impl From<sqlx::Error> for RepositoryError {
+    fn from(e: sqlx::Error) -> Self {
+        match e {
+            sqlx::Error::PoolTimedOut | sqlx::Error::PoolClosed | sqlx::Error::Io(_) | sqlx::Error::Tls(_) | sqlx::Error::WorkerCrashed => Self::Connection(Box::new(e)),
+            sqlx::Error::Database(ref db_err) if db_err.is_unique_violation() => Self::Duplicate(db_err.message().to_string()),
+            _ => Self::Database(Box::new(e)),
+        }
+    }
+}
+
+/// 实现从Diesel错误的转换
+#[cfg(feature = "diesel_impl")]
+impl From<diesel::result::Error> for RepositoryError {
+    fn from(e: diesel::result::Error) -> Self {
+        use diesel::result::{DatabaseErrorKind, Error};
+        match e {
+            Error::DatabaseError(DatabaseErrorKind::UniqueViolation, ref info) => Self::Duplicate(info.message().to_string()),
+            Error::DatabaseError(DatabaseErrorKind::ClosedConnection, _) | Error::BrokenTransactionManager => Self::Connection(Box::new(e)),
+            _ => Self::Database(Box::new(e)),
+        }
+    }
+}
+
+/// 从连接池获取Diesel连接失败时视为数据库不可用
+#[cfg(feature = "diesel_impl")]
+impl From<diesel::r2d2::PoolError> for RepositoryError {
+    fn from(e: diesel::r2d2::PoolError) -> Self {
+        Self::Connection(Box::new(e))
+    }
+}
+
+/// 实现从SeaORM错误的转换
+#[cfg(feature = "seaorm_impl")]
+impl From<sea_orm::DbErr> for RepositoryError {
+    fn from(e: sea_orm::DbErr) -> Self {
+        if let Some(sea_orm::SqlErr::UniqueConstraintViolation(message)) = e.sql_err() {
+            return Self::Duplicate(message);
+        }
+        match e {
+            sea_orm::DbErr::Conn(_) | sea_orm::DbErr::ConnectionAcquire(_) => Self::Connection(Box::new(e)),
+            _ => Self::Database(Box::new(e)),
+        }
+    }
+}
@@ -0,0 +1,27 @@
+//! 部门管理领域核心
+//!
+//! 包含部门的实体模型、请求参数、视图对象、数据访问层和业务层，
+//! Axum、Actix Web、Rocket 三个演示项目共用本库，各自只保留路由和请求提取相关的代码。
+//!
+//! 数据访问层的实现通过特性选择：`sqlx_impl`、`diesel_impl`、`seaorm_impl`、`memory_impl`，
+//! 连接数据库的实现可再启用 `sqlite`、`postgres` 特性支持对应的数据库。
+
+// 内存实现不连接数据库，无需读取配置文件
+#[cfg(any(feature = "sqlx_impl", feature = "diesel_impl", feature = "seaorm_impl"))]
+pub mod config;
+pub mod errors;
+// 数据库迁移只在连接数据库的实现中使用
+#[cfg(any(feature = "sqlx_impl", feature = "diesel_impl", feature = "seaorm_impl"))]
+pub mod migrations;
+pub mod models;
+pub mod params;
+pub mod repositories;
+pub mod services;
+pub mod views;
+
+// 只在使用SeaORM时才导入entities模块
+#[cfg(feature = "seaorm_impl")]
+pub mod entities;
+// 只在使用Diesel时才导入schema模块
+#[cfg(feature = "diesel_impl")]
+pub mod schema;
@@ -0,0 +1,401 @@
+//! 数据库迁移模块
+//!
+//! 表结构以版本化迁移脚本的形式维护在项目根目录的 `migrations/` 下，每个迁移对应一个目录，
+//! 目录名为 `版本号_名称`，其中按数据库类型分别存放升级脚本 `<数据库>.up.sql` 和回滚脚本 `<数据库>.down.sql`。
+//! 脚本在编译时嵌入二进制，已应用的迁移记录在迁移历史表 [MIGRATIONS_TABLE] 中。
+//!
+//! # 主要组件
+//! - [Migration] - 单个迁移及其各数据库的脚本
+//! - [MigrationExecutor] - 执行迁移脚本的接口，由各ORM实现的部门仓储实现
+//! - [Migrator] - 比较迁移历史表与 [MIGRATIONS]，执行升级、回滚及版本校验
+//! - [MigrateCommand] - `migrate` 子命令
+//!
+//! # 使用方式
+//! - 服务启动时通过 [prepare] 按 `database.auto_migrate` 自动应用未执行的迁移，关闭自动迁移时数据库版本与当前程序不一致则拒绝启动
+//! - `<可执行文件> migrate [up|down [N]|status|check]` 手动管理迁移，执行完成后直接退出，不启动服务
+
+use crate::config::{Config, DatabaseType};
+use async_trait::async_trait;
+use std::error::Error as StdError;
+use std::num::NonZeroUsize;
+
+/// 迁移历史表名
+pub const MIGRATIONS_TABLE: &str = "schema_migrations";
+
+/// 创建迁移历史表的语句，MySQL、SQLite、PostgreSQL 通用
+const CREATE_MIGRATIONS_TABLE_SQL: &str = "CREATE TABLE IF NOT EXISTS schema_migrations (version bigint NOT NULL, name varchar(100) NOT NULL, applied_at timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP, PRIMARY KEY (version))";
+
+/// 单个数据库的迁移脚本
+#[derive(Debug, Clone, Copy)]
+pub struct MigrationScript {
+    /// 升级脚本
+    pub up: &'static str,
+    /// 回滚脚本
+    pub down: &'static str,
+}
+
+/// 数据库迁移
+///
+/// 只包含已启用数据库特性对应的脚本
+#[derive(Debug)]
+pub struct Migration {
+    /// 版本号，按升序依次执行
+    pub version: i64,
+    /// 迁移名称，与 `migrations/` 下的目录名一致
+    pub name: &'static str,
+    /// MySQL脚本
+    mysql: MigrationScript,
+    /// SQLite脚本
+    #[cfg(feature = "sqlite")]
+    sqlite: MigrationScript,
+    /// PostgreSQL脚本
+    #[cfg(feature = "postgres")]
+    postgres: MigrationScript,
+}
+
+impl Migration {
+    /// 获取指定数据库的迁移脚本
+    ///
+    /// # 参数
+    /// * `database_type` - 数据库类型，类型: [DatabaseType]
+    ///
+    /// # 返回值
+    /// 返回该数据库的升级及回滚脚本
+    pub fn script(&self, database_type: DatabaseType) -> &MigrationScript {
+        match database_type {
+            DatabaseType::MySql => &self.mysql,
+            #[cfg(feature = "sqlite")]
+            DatabaseType::Sqlite => &self.sqlite,
+            #[cfg(feature = "postgres")]
+            DatabaseType::Postgres => &self.postgres,
+        }
+    }
+}
+
+/// 按版本号和目录名嵌入一个迁移的全部脚本
+macro_rules! migration {
+    ($version:expr, $dir:literal) => {
+        Migration {
+            version: $version,
+            name: $dir,
+            mysql: MigrationScript {
+                up: include_str!(concat!("../../../migrations/", $dir, "/mysql.up.sql")),
+                down: include_str!(concat!("../../../migrations/", $dir, "/mysql.down.sql")),
+            },
+            #[cfg(feature = "sqlite")]
+            sqlite: MigrationScript {
+                up: include_str!(concat!("../../../migrations/", $dir, "/sqlite.up.sql")),
+                down: include_str!(concat!("../../../migrations/", $dir, "/sqlite.down.sql")),
+            },
+            #[cfg(feature = "postgres")]
+            postgres: MigrationScript {
+                up: include_str!(concat!("../../../migrations/", $dir, "/postgres.up.sql")),
+                down: include_str!(concat!("../../../migrations/", $dir, "/postgres.down.sql")),
+            },
+        }
+    };
+}
+
+/// 当前程序包含的全部迁移，按版本号升序排列，新增迁移时追加到末尾
+pub static MIGRATIONS: &[Migration] = &[migration!(1, "0001_create_tables"), migration!(2, "0002_insert_demo_data"), migration!(3, "0003_add_dept_ancestors"), migration!(4, "0004_add_dept_deleted_at")];
+
+/// 迁移脚本执行接口
+///
+/// 由持有数据库连接的各ORM实现提供，[Migrator] 只通过该接口访问数据库。
+#[async_trait]
+pub trait MigrationExecutor: Send + Sync {
+    /// 当前连接的数据库类型，用于选择对应的迁移脚本
+    fn database_type(&self) -> DatabaseType;
+
+    /// 执行可能包含多条语句的SQL脚本
+    ///
+    /// # 参数
+    /// * `script` - SQL脚本，类型: [&str]
+    ///
+    /// # 返回值
+    /// 返回操作结果，类型: [Result<(), Box<dyn StdError + Send + Sync>>]
+    async fn execute_script(&self, script: &str) -> Result<(), Box<dyn StdError + Send + Sync>>;
+
+    /// 查询迁移历史表中已应用的版本号
+    ///
+    /// # 返回值
+    /// 返回按升序排列的版本号，类型: [Result<Vec<i64>, Box<dyn StdError + Send + Sync>>]
+    async fn select_applied_versions(&self) -> Result<Vec<i64>, Box<dyn StdError + Send + Sync>>;
+}
+
+/// 迁移状态
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MigrationStatus {
+    /// 版本号
+    pub version: i64,
+    /// 迁移名称，数据库中存在当前程序没有的迁移时为None
+    pub name: Option<&'static str>,
+    /// 是否已应用
+    pub applied: bool,
+}
+
+/// 迁移执行器
+pub struct Migrator<'a> {
+    executor: &'a dyn MigrationExecutor,
+    migrations: &'a [Migration],
+}
+
+impl<'a> Migrator<'a> {
+    /// 使用当前程序包含的全部迁移创建执行器
+    ///
+    /// # 参数
+    /// * `executor` - 迁移脚本执行接口的实现
+    pub fn new(executor: &'a dyn MigrationExecutor) -> Self {
+        Self { executor, migrations: MIGRATIONS }
+    }
+
+    /// 查询已应用的版本号，迁移历史表不存在时先创建
+    async fn applied_versions(&self) -> Result<Vec<i64>, Box<dyn StdError + Send + Sync>> {
+        self.executor
+            .execute_script(CREATE_MIGRATIONS_TABLE_SQL)
+            .await?;
+        self.executor.select_applied_versions().await
+    }
+
+    /// 查询全部迁移的状态
+    ///
+    /// # 返回值
+    /// 返回按版本号升序排列的迁移状态，包括数据库中存在而当前程序没有的迁移
+    pub async fn status(&self) -> Result<Vec<MigrationStatus>, Box<dyn StdError + Send + Sync>> {
+        let applied = self.applied_versions().await?;
+        let mut status: Vec<MigrationStatus> = self
+            .migrations
+            .iter()
+            .map(|migration| MigrationStatus {
+                version: migration.version,
+                name: Some(migration.name),
+                applied: applied.contains(&migration.version),
+            })
+            .collect();
+
+        // 数据库比当前程序新时，历史表中会有当前程序不认识的版本
+        for version in applied {
+            if !self
+                .migrations
+                .iter()
+                .any(|migration| migration.version == version)
+            {
+                status.push(MigrationStatus { version, name: None, applied: true });
+            }
+        }
+        status.sort_by_key(|status| status.version);
+        Ok(status)
+    }
+
+    /// 查询未应用的迁移
+    ///
+    /// # 返回值
+    /// 返回按版本号升序排列的未应用迁移
+    pub async fn pending(&self) -> Result<Vec<&'a Migration>, Box<dyn StdError + Send + Sync>> {
+        let applied = self.applied_versions().await?;
+        Ok(self
+            .migrations
+            .iter()
+            .filter(|migration| !applied.contains(&migration.version))
+            .collect())
+    }
+
+    /// 按版本号升序应用全部未执行的迁移
+    ///
+    /// 每个迁移的脚本与迁移历史记录合并为一次执行，PostgreSQL会在同一个隐式事务中完成；
+    /// MySQL的DDL语句会隐式提交，SQLite按语句依次执行，迁移失败时需按错误信息手动处理后重试。
+    ///
+    /// # 返回值
+    /// 返回本次应用的迁移
+    pub async fn up(&self) -> Result<Vec<&'a Migration>, Box<dyn StdError + Send + Sync>> {
+        let database_type = self.executor.database_type();
+        let pending = self.pending().await?;
+        for migration in &pending {
+            let script = format!("{}\nINSERT INTO {MIGRATIONS_TABLE} (version, name) VALUES ({}, '{}');", migration.script(database_type).up, migration.version, migration.name);
+            self.executor
+                .execute_script(&script)
+                .await
+                .map_err(|e| format!("应用迁移 {} 失败: {}", migration.name, e))?;
+        }
+        Ok(pending)
+    }
+
+    /// 按版本号降序回滚最近应用的迁移
+    ///
+    /// # 参数
+    /// * `steps` - 回滚的迁移个数，类型: [usize]
+    ///
+    /// # 返回值
+    /// 返回本次回滚的迁移，数据库中存在当前程序没有的迁移时返回错误
+    pub async fn down(&self, steps: usize) -> Result<Vec<&'a Migration>, Box<dyn StdError + Send + Sync>> {
+        let database_type = self.executor.database_type();
+        let mut applied = self.applied_versions().await?;
+        applied.sort_unstable_by(|a, b| b.cmp(a));
+
+        let mut rolled_back = Vec::new();
+        for version in applied.into_iter().take(steps) {
+            let Some(migration) = self
+                .migrations
+                .iter()
+                .find(|migration| migration.version == version)
+            else {
+                return Err(format!("数据库中的迁移版本 {version} 不在当前程序中，无法回滚").into());
+            };
+            let script = format!("{}\nDELETE FROM {MIGRATIONS_TABLE} WHERE version = {};", migration.script(database_type).down, migration.version);
+            self.executor
+                .execute_script(&script)
+                .await
+                .map_err(|e| format!("回滚迁移 {} 失败: {}", migration.name, e))?;
+            rolled_back.push(migration);
+        }
+        Ok(rolled_back)
+    }
+
+    /// 校验数据库版本是否与当前程序一致
+    ///
+    /// # 返回值
+    /// 存在未应用的迁移，或数据库中存在当前程序没有的迁移时返回错误，错误信息中列出对应的迁移
+    pub async fn check(&self) -> Result<(), Box<dyn StdError + Send + Sync>> {
+        let status = self.status().await?;
+
+        // 数据库比当前程序新，或应用过其他分支的迁移
+        let unknown: Vec<String> = status
+            .iter()
+            .filter(|status| status.name.is_none())
+            .map(|status| status.version.to_string())
+            .collect();
+        if !unknown.is_empty() {
+            return Err(format!("数据库中存在当前程序没有的迁移版本: {}；请使用与数据库版本一致的程序", unknown.join(", ")).into());
+        }
+
+        let pending: Vec<&str> = status
+            .iter()
+            .filter(|status| !status.applied)
+            .filter_map(|status| status.name)
+            .collect();
+        if pending.is_empty() {
+            return Ok(());
+        }
+        Err(format!("数据库结构落后于当前程序，待执行的迁移: {}；请执行 migrate 子命令或开启 database.auto_migrate", pending.join(", ")).into())
+    }
+
+    /// 执行迁移子命令并输出结果
+    ///
+    /// # 参数
+    /// * `command` - 迁移子命令，类型: [MigrateCommand]
+    pub async fn run(&self, command: MigrateCommand) -> Result<(), Box<dyn StdError + Send + Sync>> {
+        match command {
+            MigrateCommand::Up => {
+                let applied = self.up().await?;
+                if applied.is_empty() {
+                    println!("数据库已是最新版本，没有需要应用的迁移");
+                }
+                for migration in applied {
+                    println!("已应用迁移: {}", migration.name);
+                }
+            },
+            MigrateCommand::Down(steps) => {
+                for migration in self.down(steps.get()).await? {
+                    println!("已回滚迁移: {}", migration.name);
+                }
+            },
+            MigrateCommand::Status => {
+                for status in self.status().await? {
+                    let state = if status.applied { "已应用" } else { "未应用" };
+                    match status.name {
+                        Some(name) => println!("[{state}] {name}"),
+                        None => println!("[{state}] 版本 {}（当前程序中不存在该迁移）", status.version),
+                    }
+                }
+            },
+            MigrateCommand::Check => {
+                self.check().await?;
+                println!("数据库已是最新版本");
+            },
+        }
+        Ok(())
+    }
+}
+
+/// 迁移子命令
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MigrateCommand {
+    /// 应用全部未执行的迁移，`migrate` 或 `migrate up`
+    Up,
+    /// 回滚最近的N个迁移，`migrate down [N]`，N为正整数，默认为1
+    Down(NonZeroUsize),
+    /// 查看迁移状态，`migrate status`
+    Status,
+    /// 校验数据库版本，存在未执行的迁移或当前程序没有的迁移时以非零状态码退出，`migrate check`
+    Check,
+}
+
+impl MigrateCommand {
+    /// 从命令行参数解析迁移子命令
+    ///
+    /// # 参数
+    /// * `args` - 不含程序名的命令行参数
+    ///
+    /// # 返回值
+    /// 第一个参数不是 `migrate` 时返回 `Ok(None)`，子命令无法识别时返回错误信息
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Result<Option<Self>, String> {
+        let mut args = args.into_iter();
+        if args.next().as_deref() != Some("migrate") {
+            return Ok(None);
+        }
+
+        let command = match args.next().as_deref() {
+            None | Some("up") => Self::Up,
+            Some("down") => match args.next() {
+                None => Self::Down(NonZeroUsize::MIN),
+                Some(steps) => Self::Down(
+                    steps
+                        .parse()
+                        .map_err(|_| format!("回滚个数必须是正整数: {steps}"))?,
+                ),
+            },
+            Some("status") => Self::Status,
+            Some("check") => Self::Check,
+            Some(other) => return Err(format!("未知的迁移子命令: {other}，可用的子命令: up、down [N]、status、check")),
+        };
+        Ok(Some(command))
+    }
+}
+
+/// 启动前准备数据库结构
+///
+/// 命令行为 `migrate` 子命令时执行对应操作后直接退出进程；
+/// 否则按 `database.auto_migrate` 应用未执行的迁移，关闭自动迁移且数据库版本与当前程序不一致时拒绝启动。
+///
+/// # 参数
+/// * `executor` - 迁移脚本执行接口的实现，通常为当前使用的部门仓储
+pub async fn prepare(executor: &dyn MigrationExecutor) {
+    let migrator = Migrator::new(executor);
+
+    match MigrateCommand::from_args(std::env::args().skip(1)) {
+        Ok(Some(command)) => {
+            let code = match migrator.run(command).await {
+                Ok(()) => 0,
+                Err(e) => {
+                    eprintln!("{e}");
+                    1
+                },
+            };
+            std::process::exit(code);
+        },
+        Ok(None) => {},
+        Err(e) => {
+            eprintln!("{e}");
+            std::process::exit(2);
+        },
+    }
+
+    let config = Config::from_default_file().expect("无法加载配置文件");
+    if config.database.auto_migrate {
+        for migration in migrator.up().await.expect("执行数据库迁移失败") {
+            println!("已应用数据库迁移: {}", migration.name);
+        }
+    } else {
+        migrator.check().await.expect("数据库版本校验失败");
+    }
+}
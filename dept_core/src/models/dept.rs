@@ -7,6 +7,7 @@
 //!
 //! - 定义部门实体结构，参见: [Dept]
 //! - 提供部门实体与参数对象之间的转换实现
+//! - 计算部门的祖级路径，参见: [Dept::path]、[Dept::ancestor_ids]
 
 use chrono::NaiveDateTime;
 #[cfg(feature = "diesel_impl")]
@@ -88,7 +89,7 @@ pub struct Dept {
     #[cfg_attr(feature = "seaorm_impl", sea_orm(field_name = "seq_no"))]
     pub seq_no: Option<i32>,
 
-    /// 部门状态(0正常 1停用)
+    /// 部门状态(1启用 0停用)
     ///
     /// 类型: [Option]<[i32]>，数据库字段名: status
     #[cfg_attr(feature = "sqlx_impl", sqlx(rename = "status"))]
@@ -135,6 +136,59 @@ pub struct Dept {
     #[cfg_attr(feature = "diesel_impl", diesel(column_name = remark))]
     #[cfg_attr(feature = "seaorm_impl", sea_orm(field_name = "remark"))]
     pub remark: Option<String>,
+
+    /// 祖级路径
+    ///
+    /// 由根部门到父级部门的id依次组成，以 [ANCESTORS_SEPARATOR] 分隔且首尾都带分隔符，根部门为 [ROOT_ANCESTORS]，
+    /// 新增、移动部门时由业务层维护
+    ///
+    /// 类型: [Option]<[String]>，数据库字段名: ancestors
+    #[cfg_attr(feature = "sqlx_impl", sqlx(rename = "ancestors"))]
+    #[cfg_attr(feature = "diesel_impl", diesel(column_name = ancestors))]
+    #[cfg_attr(feature = "seaorm_impl", sea_orm(field_name = "ancestors"))]
+    pub ancestors: Option<String>,
+
+    /// 删除时间
+    ///
+    /// 删除部门时记录，为None表示未删除，数据访问层的查询默认排除已删除的部门，参见: [DeptQueryOptions](crate::repositories::dept::dept_repository::DeptQueryOptions)
+    ///
+    /// 类型: [Option]<[NaiveDateTime]>，数据库字段名: deleted_at
+    #[cfg_attr(feature = "sqlx_impl", sqlx(rename = "deleted_at"))]
+    #[cfg_attr(feature = "diesel_impl", diesel(column_name = deleted_at))]
+    #[cfg_attr(feature = "seaorm_impl", sea_orm(field_name = "deleted_at"))]
+    pub deleted_at: Option<NaiveDateTime>,
+}
+
+/// 祖级路径中部门id的分隔符
+pub const ANCESTORS_SEPARATOR: char = '/';
+
+/// 根部门的祖级路径
+pub const ROOT_ANCESTORS: &str = "/";
+
+impl Dept {
+    /// 获取部门的完整路径
+    ///
+    /// 即祖级路径后拼接部门本身的id，也就是其子部门的祖级路径，全部下级部门的祖级路径都以该路径开头；
+    /// 祖级路径尚未维护时按根部门处理
+    ///
+    /// # 返回值
+    /// 返回部门的完整路径，如 `/根部门id/父级部门id/部门id/`
+    pub fn path(&self) -> String {
+        format!("{}{}{ANCESTORS_SEPARATOR}", self.ancestors.as_deref().unwrap_or(ROOT_ANCESTORS), self.id)
+    }
+
+    /// 获取祖级路径中的部门id
+    ///
+    /// # 返回值
+    /// 返回从根部门到父级部门依次排列的部门id，根部门或祖级路径尚未维护时为空
+    pub fn ancestor_ids(&self) -> Vec<&str> {
+        self.ancestors
+            .as_deref()
+            .unwrap_or_default()
+            .split(ANCESTORS_SEPARATOR)
+            .filter(|id| !id.is_empty())
+            .collect()
+    }
 }
 
 impl From<crate::params::dept_param::DeptParam> for Dept {
@@ -155,6 +209,10 @@ impl From<crate::params::dept_param::DeptParam> for Dept {
             update_by: param.update_by,
             update_time: param.update_time,
             remark: param.remark,
+            // 祖级路径由业务层根据父级部门计算，不接受请求传入
+            ancestors: None,
+            // 删除时间只在删除、恢复部门时由数据访问层维护
+            deleted_at: None,
         }
     }
 }
@@ -0,0 +1,284 @@
+//! 部门参数
+//!
+//! 该模块定义了部门参数结构，用于接收和处理部门相关的HTTP请求参数。
+//! 部门参数包含了部门的基本信息，如部门名称、联系方式、状态等。
+//!
+//! # 主要功能
+//!
+//! - 定义部门参数结构，参见: [DeptParam]
+//! - 按分组验证部门参数，验证规则与 `sys_dept` 表的字段长度一致
+//! - 通过数据访问层异步验证父部门状态、部门层级及部门名称唯一性
+//! - 提供部门参数与实体对象之间的转换实现
+
+use crate::errors::{DeptError, dept_error::MESSAGES};
+use crate::models::Dept;
+use crate::params::page_param::PageParam;
+use crate::params::tree_param::TreeParam;
+use crate::repositories::dept::dept_repository::DeptRepository;
+use common_validation::ValidationErrorEnum;
+use common_validation_macros::ValidatableImpl;
+use common_wrapper::enums::status_enum::StatusEnum;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// 部门参数
+///
+/// 用于接收和处理部门相关的HTTP请求参数
+///
+/// 分组验证的字段：
+///
+/// - 新增部门 [CreateGroup](common_validation::CreateGroup): 除部门id外的全部可编辑字段
+/// - 编辑部门 [UpdateGroup](common_validation::UpdateGroup): 部门id及全部可编辑字段
+/// - 修改部门状态 [StatusUpdateGroup](common_validation::StatusUpdateGroup): 部门id、部门状态
+///
+/// 编辑部门时父部门不能为部门本身
+///
+/// 新增、编辑部门时通过 [DeptRepository] 异步验证：父部门必须存在且状态正常，同一父部门下部门名称不能重复；
+/// 编辑部门时父部门不能为部门本身的下级部门
+#[derive(Debug, Clone, Default, Deserialize, Serialize, ValidatableImpl)]
+#[serde(rename_all = "camelCase")]
+#[group_fields(
+    create = ["parent_id", "name", "email", "telephone", "address", "logo", "status", "remark"],
+    update = ["id", "parent_id", "name", "email", "telephone", "address", "logo", "status", "remark"],
+    status_update = ["id", "status"]
+)]
+#[validate(schema = "validate_parent_id", groups = ["update"])]
+#[validate(context = "dyn DeptRepository", error = "DeptError")]
+#[validate(async_schema = "validate_parent_not_descendant", groups = ["update"])]
+#[validate(async_schema = "validate_name_unique", groups = ["create", "update"])]
+pub struct DeptParam {
+    /// 部门id
+    ///
+    /// 类型: [Option]<[String]>
+    #[validate(not_null, exist_length_range(min = 1, max = 32), desc = "部门ID")]
+    pub id: Option<String>,
+
+    /// 父部门id
+    ///
+    /// 类型: [Option]<[String]>
+    #[validate(exist_length_range(min = 1, max = 32), async_custom = "validate_parent_dept", desc = "父部门ID")]
+    pub parent_id: Option<String>,
+
+    /// 部门名称
+    ///
+    /// 类型: [Option]<[String]>
+    #[validate(not_null, length_range(min = 1, max = 30), desc = "部门名称")]
+    pub name: Option<String>,
+
+    /// 邮箱
+    ///
+    /// 类型: [Option]<[String]>
+    #[validate(exist_length_range(min = 1, max = 50), email, desc = "邮箱")]
+    pub email: Option<String>,
+
+    /// 联系电话
+    ///
+    /// 类型: [Option]<[String]>
+    #[validate(exist_length_range(min = 1, max = 11), mobile_phone, desc = "联系电话")]
+    pub telephone: Option<String>,
+
+    /// 地址
+    ///
+    /// 类型: [Option]<[String]>
+    #[validate(exist_length_range(min = 1, max = 200), desc = "地址")]
+    pub address: Option<String>,
+
+    /// logo地址
+    ///
+    /// 类型: [Option]<[String]>
+    #[validate(exist_length_range(min = 1, max = 100), url, desc = "logo地址")]
+    pub logo: Option<String>,
+
+    /// 显示顺序
+    ///
+    /// 类型: [Option]<[i32]>
+    pub seq_no: Option<i32>,
+
+    /// 部门状态(1启用 0停用)
+    ///
+    /// 类型: [Option]<[i32]>
+    #[validate(not_null, min = 0, max = 1, desc = "部门状态")]
+    pub status: Option<i32>,
+
+    /// 创建者
+    ///
+    /// 类型: [Option]<[String]>
+    pub create_by: Option<String>,
+
+    /// 创建时间
+    ///
+    /// 类型: [Option]<[chrono::NaiveDateTime]>
+    pub create_time: Option<chrono::NaiveDateTime>,
+
+    /// 更新者
+    ///
+    /// 类型: [Option]<[String]>
+    pub update_by: Option<String>,
+
+    /// 更新时间
+    ///
+    /// 类型: [Option]<[chrono::NaiveDateTime]>
+    pub update_time: Option<chrono::NaiveDateTime>,
+
+    /// 备注
+    ///
+    /// 类型: [Option]<[String]>
+    #[validate(exist_length_range(min = 1, max = 200), desc = "备注")]
+    pub remark: Option<String>,
+
+    /// 分页参数
+    ///
+    /// 类型: [PageParam]
+    #[serde(flatten)]
+    pub page_param: PageParam,
+
+    /// 树形查询参数，仅获取部门树时使用
+    ///
+    /// 类型: [TreeParam]
+    #[serde(flatten)]
+    pub tree_param: TreeParam,
+}
+
+/// 验证父部门不是部门本身
+///
+/// # 参数
+///
+/// * `param` - 部门参数
+///
+/// # 返回值
+///
+/// 父部门id与部门id相同时返回 [ValidationErrorEnum::Message]，错误信息为消息目录 [MESSAGES] 中的 `DEPT_PARENT_IS_SELF`
+fn validate_parent_id(param: &DeptParam) -> Result<(), ValidationErrorEnum> {
+    match (&param.id, &param.parent_id) {
+        (Some(id), Some(parent_id)) if id == parent_id => Err(ValidationErrorEnum::Message(&MESSAGES, "DEPT_PARENT_IS_SELF")),
+        _ => Ok(()),
+    }
+}
+
+/// 验证父部门存在且状态正常
+///
+/// 在事务中调用时父部门会被锁定到事务结束，避免校验后父部门被并发删除或停用
+///
+/// # 参数
+///
+/// * `parent_id` - 父部门id，为空时表示顶级部门，不需要验证
+/// * `repository` - 执行查询的仓储
+///
+/// # 返回值
+///
+/// 父部门不存在时返回 [DeptError::ParentNotFound]，已停用时返回 [DeptError::ParentDisabled]
+async fn validate_parent_dept(parent_id: &Option<String>, repository: &dyn DeptRepository) -> Result<(), DeptError> {
+    let Some(parent_id) = parent_id else {
+        return Ok(());
+    };
+    // 对ID进行去空格处理
+    let trimmed_parent_id = parent_id.trim();
+    if trimmed_parent_id.is_empty() {
+        return Err(DeptError::InvalidParam("DEPT_PARENT_ID_REQUIRED".to_string()));
+    }
+
+    let parent_dept = repository
+        .select_by_primary_key_for_update(trimmed_parent_id)
+        .await?
+        .ok_or(DeptError::ParentNotFound)?;
+
+    // 检查父级部门状态，使用StatusEnum枚举进行验证
+    if let Some(StatusEnum::Disable) = parent_dept.status.and_then(StatusEnum::from_code) {
+        return Err(DeptError::ParentDisabled);
+    }
+    Ok(())
+}
+
+/// 验证父部门不是部门本身或其下级部门
+///
+/// 从父部门开始沿父级逐级向上查找，经过部门本身时说明父部门是它的下级部门，修改后部门层级会出现环。
+/// 在事务中调用时查找路径上的部门会被锁定到事务结束，避免并发移动的两个部门互相成为对方的下级部门
+///
+/// # 参数
+///
+/// * `param` - 部门参数，部门id或父部门id为空时不需要验证
+/// * `repository` - 执行查询的仓储
+///
+/// # 返回值
+///
+/// 父部门为部门本身或其下级部门时返回 [DeptError::CyclicParent]
+async fn validate_parent_not_descendant(param: &DeptParam, repository: &dyn DeptRepository) -> Result<(), DeptError> {
+    let (Some(id), Some(parent_id)) = (&param.id, &param.parent_id) else {
+        return Ok(());
+    };
+    let id = id.trim();
+    let mut current = parent_id.trim().to_string();
+    // 历史数据中已存在不经过部门本身的环时，查找到重复的部门即停止
+    let mut visited = HashSet::new();
+    while !current.is_empty() && visited.insert(current.clone()) {
+        if current == id {
+            return Err(DeptError::CyclicParent);
+        }
+        current = match repository
+            .select_by_primary_key_for_update(&current)
+            .await?
+        {
+            Some(dept) => dept.parent_id.unwrap_or_default(),
+            None => break,
+        };
+    }
+    Ok(())
+}
+
+/// 验证部门名称在同级部门中唯一
+///
+/// 同一父部门下不能有重名的子部门，不同父部门下的子部门可以有相同的名称。
+/// 编辑部门时排除部门本身，否则只修改其他属性时会与自身重名；新增部门时部门id应为空。
+///
+/// # 参数
+///
+/// * `param` - 部门参数
+/// * `repository` - 执行查询的仓储，与写入处于同一事务时才能保证校验结果在写入前不被改变
+///
+/// # 返回值
+///
+/// 存在同名的同级部门时返回 [DeptError::DuplicateName]
+async fn validate_name_unique(param: &DeptParam, repository: &dyn DeptRepository) -> Result<(), DeptError> {
+    let Some(name) = &param.name else {
+        return Ok(());
+    };
+
+    // 根据父部门ID查询同级部门列表
+    let sibling_depts = repository
+        .select_dept_list(&Dept {
+            parent_id: param.parent_id.clone(),
+            ..Default::default()
+        })
+        .await?;
+
+    if sibling_depts
+        .iter()
+        .any(|dept| dept.name.as_ref() == Some(name) && param.id.as_ref() != Some(&dept.id))
+    {
+        return Err(DeptError::DuplicateName);
+    }
+    Ok(())
+}
+
+impl From<crate::models::dept::Dept> for DeptParam {
+    fn from(dept: crate::models::dept::Dept) -> Self {
+        DeptParam {
+            id: Some(dept.id),
+            name: dept.name,
+            email: dept.email,
+            telephone: dept.telephone,
+            address: dept.address,
+            logo: dept.logo,
+            parent_id: dept.parent_id,
+            seq_no: dept.seq_no,
+            status: dept.status,
+            create_by: dept.create_by,
+            create_time: dept.create_time,
+            update_by: dept.update_by,
+            update_time: dept.update_time,
+            remark: dept.remark,
+            page_param: PageParam::default(),
+            tree_param: TreeParam::default(),
+        }
+    }
+}
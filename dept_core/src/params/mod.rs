@@ -7,6 +7,7 @@
 //!
 //! - [dept_param] - 部门参数，参见: [crate::models::dept]
 //! - [page_param] - 分页参数，参见: [crate::repositories]
+//! - [tree_param] - 树形查询参数，参见: [crate::views::dept_tree]
 
 /// 部门参数
 ///
@@ -17,3 +18,8 @@ pub mod dept_param;
 ///
 /// 用于接收和处理分页相关的请求参数
 pub mod page_param;
+
+/// 树形查询参数
+///
+/// 用于接收和处理部门树等树形查询的根节点、层数参数
+pub mod tree_param;
@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 
 /// 公共分页参数
 #[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct PageParam {
     /// 页码
     pub page_num: Option<u64>,
@@ -0,0 +1,15 @@
+//! 树形查询参数定义
+
+use serde::{Deserialize, Serialize};
+
+/// 公共树形查询参数
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TreeParam {
+    /// 根节点ID，有值时只返回以该节点为根的子树
+    pub root_id: Option<String>,
+    /// 最多返回的层数，根节点为第1层，为空或0时不限制
+    pub max_depth: Option<u32>,
+    /// 是否返回节点详情，为 `true` 时树节点带有状态、显示顺序、子部门数量及是否禁用选择
+    pub detail: Option<bool>,
+}
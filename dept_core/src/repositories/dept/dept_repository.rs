@@ -0,0 +1,401 @@
+//! 部门数据访问层接口定义
+//!
+//! 该模块定义了部门相关的数据访问接口，提供了对部门信息的增删改查操作。
+//! 所有方法都是异步的，支持在多线程环境下安全使用。
+//!
+//! # 主要功能
+//! - 部门的增删改查操作
+//! - 部门列表查询
+//! - 按祖级路径查询部门的全部上级、下级部门
+//! - 部门状态更新
+//! - 逻辑删除、恢复及彻底删除部门，查询默认排除已删除的部门，参见: [DeptQueryOptions]
+//! - 事务，通过 [DeptRepository::begin] 开启的 [DeptTransaction] 把多次读写合并为一个整体
+//!
+//! 所有方法失败时返回 [RepositoryError]，各ORM实现的错误在返回前转换为对应的错误类别。
+//!
+//! # 使用示例
+//! ```rust
+//! // 使用示例需要具体的实现类，这里是接口定义
+//! // 通常会有一个实现了DeptRepository trait的具体结构体
+//! ```
+
+use crate::errors::RepositoryError;
+use crate::models::Dept;
+use async_trait::async_trait;
+use std::fmt::Debug;
+
+/// 部门查询选项
+///
+/// 除 [DeptRepository::select_dept_list_with_options] 外，数据访问层的查询、更新都只作用于未删除的部门
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DeptQueryOptions {
+    /// 是否包含已删除的部门，回收站等需要查看已删除部门的场景设置为true
+    pub include_deleted: bool,
+}
+
+impl DeptQueryOptions {
+    /// 包含已删除部门的查询选项
+    pub const fn include_deleted() -> Self {
+        Self { include_deleted: true }
+    }
+}
+
+/// 部门数据访问trait
+///
+/// 定义了部门相关的数据访问接口，所有实现该trait的结构体都必须提供这些方法的具体实现。
+/// 该trait要求实现Debug、Send和Sync trait，以确保可以在多线程环境中安全使用。
+#[async_trait]
+pub trait DeptRepository: Debug + Send + Sync {
+    /// 根据主键删除部门
+    ///
+    /// 逻辑删除，只把删除时间（`deleted_at`）设置为当前时间，部门已删除或不存在时不做任何修改。
+    /// 删除后除 [DeptRepository::select_dept_list_with_options] 外的方法都不再返回该部门，
+    /// 可以通过 [DeptRepository::restore_by_primary_key] 恢复，或通过 [DeptRepository::purge_by_primary_key] 彻底删除。
+    ///
+    /// # 参数
+    /// * `id` - 部门ID，类型: [&str]
+    ///
+    /// # 返回值
+    /// 返回操作结果，类型: [Result<(), RepositoryError>]
+    ///
+    /// # 示例
+    /// ```rust
+    /// # async fn example() {
+    /// // repo 是实现了 DeptRepository trait 的具体实例
+    /// let result = repo.delete_by_primary_key("1").await;
+    /// match result {
+    ///     Ok(()) => println!("删除成功"),
+    ///     Err(e) => println!("删除失败: {}", e),
+    /// }
+    /// # }
+    /// ```
+    async fn delete_by_primary_key(&self, id: &str) -> Result<(), RepositoryError>;
+
+    /// 根据主键恢复已删除的部门
+    ///
+    /// 清空删除时间，部门未删除或不存在时不做任何修改
+    ///
+    /// # 参数
+    /// * `id` - 部门ID，类型: [&str]
+    ///
+    /// # 返回值
+    /// 返回恢复的行数，类型: [Result<u64, RepositoryError>]
+    async fn restore_by_primary_key(&self, id: &str) -> Result<u64, RepositoryError>;
+
+    /// 根据主键彻底删除已删除的部门
+    ///
+    /// 从数据库中删除已逻辑删除的部门记录，删除后无法恢复；部门未删除或不存在时不做任何修改，
+    /// 删除与逻辑删除的检查在同一条语句中完成，不会误删并发恢复的部门
+    ///
+    /// # 参数
+    /// * `id` - 部门ID，类型: [&str]
+    ///
+    /// # 返回值
+    /// 返回删除的行数，类型: [Result<u64, RepositoryError>]
+    async fn purge_by_primary_key(&self, id: &str) -> Result<u64, RepositoryError>;
+
+    /// 插入部门记录
+    ///
+    /// 插入完整的部门记录，即使某些字段为None也会插入对应字段的NULL值。
+    ///
+    /// # 参数
+    /// * `row` - 部门信息，类型: [&Dept]
+    ///
+    /// # 返回值
+    /// 返回操作结果，类型: [Result<(), RepositoryError>]
+    ///
+    /// # 示例
+    /// ```rust
+    /// # async fn example() {
+    /// use crate::models::Dept;
+    /// use chrono::Utc;
+    ///
+    /// let dept = Dept {
+    ///     id: "1".to_string(),
+    ///     name: Some("研发部".to_string()),
+    ///     // 其他字段...
+    ///     ..Default::default()
+    /// };
+    ///
+    /// // repo 是实现了 DeptRepository trait 的具体实例
+    /// let result = repo.insert(&dept).await;
+    /// match result {
+    ///     Ok(()) => println!("插入成功"),
+    ///     Err(e) => println!("插入失败: {}", e),
+    /// }
+    /// # }
+    /// ```
+    async fn insert(&self, row: &Dept) -> Result<(), RepositoryError>;
+
+    /// 选择性插入部门记录
+    ///
+    /// 只插入部门记录中非None的字段，对于None字段则不进行插入操作。
+    ///
+    /// # 参数
+    /// * `row` - 部门信息，类型: [&Dept]
+    ///
+    /// # 返回值
+    /// 返回操作结果，类型: [Result<(), RepositoryError>]
+    #[allow(unused)]
+    async fn insert_selective(&self, row: &Dept) -> Result<(), RepositoryError>;
+
+    /// 根据主键查询部门
+    ///
+    /// 根据部门ID查询部门信息，如果部门存在则返回Some(Dept)，否则返回None。
+    ///
+    /// # 参数
+    /// * `id` - 部门ID，类型: [&str]
+    ///
+    /// # 返回值
+    /// 返回部门信息，类型: [Result<Option<Dept>, RepositoryError>]
+    ///
+    /// # 示例
+    /// ```rust
+    /// # async fn example() {
+    /// // repo 是实现了 DeptRepository trait 的具体实例
+    /// let result = repo.select_by_primary_key("1").await;
+    /// match result {
+    ///     Ok(Some(dept)) => println!("查询到部门: {:?}", dept),
+    ///     Ok(None) => println!("未找到该部门"),
+    ///     Err(e) => println!("查询失败: {}", e),
+    /// }
+    /// # }
+    /// ```
+    async fn select_by_primary_key(&self, id: &str) -> Result<Option<Dept>, RepositoryError>;
+
+    /// 根据主键查询并锁定部门
+    ///
+    /// 在事务中执行时锁定该部门记录直到事务结束，其他事务锁定同一部门时需要等待，
+    /// 用于保证校验与写入之间该部门不会被并发修改或删除。不在事务中执行时与 [DeptRepository::select_by_primary_key] 相同；
+    /// SQLite没有行锁，写事务本身串行执行，同样按普通查询处理。
+    ///
+    /// # 参数
+    /// * `id` - 部门ID，类型: [&str]
+    ///
+    /// # 返回值
+    /// 返回部门信息，类型: [Result<Option<Dept>, RepositoryError>]
+    async fn select_by_primary_key_for_update(&self, id: &str) -> Result<Option<Dept>, RepositoryError>;
+
+    /// 根据父部门ID查询部门
+    ///
+    /// 根据父部门ID查询子部门信息，返回所有匹配的部门列表。
+    ///
+    /// # 参数
+    /// * `parent_id` - 父部门ID，类型: [&str]
+    ///
+    /// # 返回值
+    /// 返回部门信息，类型: [Result<Vec<Dept>, RepositoryError>]
+    async fn select_dept_by_parent_id(&self, parent_id: &str) -> Result<Vec<Dept>, RepositoryError>;
+
+    /// 查询部门的全部下级部门
+    ///
+    /// 下级部门的祖级路径都以该部门的完整路径（[Dept::path]）开头，先查询部门本身，
+    /// 再按祖级路径前缀匹配一次查出整棵子树，前缀匹配可以使用祖级路径上的索引。
+    /// 结果不包含部门本身，按部门ID排序；部门不存在时返回空列表。
+    ///
+    /// # 参数
+    /// * `id` - 部门ID，类型: [&str]
+    ///
+    /// # 返回值
+    /// 返回全部下级部门，类型: [Result<Vec<Dept>, RepositoryError>]
+    ///
+    /// # 示例
+    /// ```rust
+    /// # async fn example() {
+    /// // repo 是实现了 DeptRepository trait 的具体实例，判断部门2是否在部门1之下
+    /// let result = repo.select_descendants("1").await;
+    /// match result {
+    ///     Ok(depts) => println!("部门2在部门1之下: {}", depts.iter().any(|dept| dept.id == "2")),
+    ///     Err(e) => println!("查询失败: {}", e),
+    /// }
+    /// # }
+    /// ```
+    async fn select_descendants(&self, id: &str) -> Result<Vec<Dept>, RepositoryError>;
+
+    /// 查询部门的全部上级部门
+    ///
+    /// 先查询部门本身，再按其祖级路径中的部门ID（[Dept::ancestor_ids]）查询上级部门。
+    /// 结果不包含部门本身，按从根部门到父级部门的顺序排列；部门不存在或为根部门时返回空列表。
+    ///
+    /// # 参数
+    /// * `id` - 部门ID，类型: [&str]
+    ///
+    /// # 返回值
+    /// 返回全部上级部门，类型: [Result<Vec<Dept>, RepositoryError>]
+    async fn select_ancestors(&self, id: &str) -> Result<Vec<Dept>, RepositoryError>;
+
+    /// 查询部门列表
+    ///
+    /// 根据条件查询部门列表，支持按部门名称模糊查询和部门状态筛选，不包含已删除的部门。
+    ///
+    /// # 参数
+    /// * `row` - 查询条件，类型: [&Dept]
+    ///
+    /// # 返回值
+    /// 返回部门列表，类型: [Result<Vec<Dept>, RepositoryError>]
+    ///
+    /// # 示例
+    /// ```rust
+    /// # async fn example() {
+    /// use crate::models::Dept;
+    ///
+    /// let condition = Dept {
+    ///     name: Some("研发".to_string()),  // 模糊查询包含"研发"的部门
+    ///     status: Some(0),                 // 查询状态为正常的部门
+    ///     ..Default::default()
+    /// };
+    ///
+    /// // repo 是实现了 DeptRepository trait 的具体实例
+    /// let result = repo.select_dept_list(&condition).await;
+    /// match result {
+    ///     Ok(depts) => println!("查询到{}个部门", depts.len()),
+    ///     Err(e) => println!("查询失败: {}", e),
+    /// }
+    /// # }
+    /// ```
+    async fn select_dept_list(&self, row: &Dept) -> Result<Vec<Dept>, RepositoryError> {
+        self.select_dept_list_with_options(row, DeptQueryOptions::default())
+            .await
+    }
+
+    /// 按查询选项查询部门列表
+    ///
+    /// 查询条件与 [DeptRepository::select_dept_list] 一致，`options.include_deleted` 为true时结果中包含已删除的部门，
+    /// 可以按 `deleted_at` 区分
+    ///
+    /// # 参数
+    /// * `row` - 查询条件，类型: [&Dept]
+    /// * `options` - 查询选项，类型: [DeptQueryOptions]
+    ///
+    /// # 返回值
+    /// 返回部门列表，类型: [Result<Vec<Dept>, RepositoryError>]
+    ///
+    /// # 示例
+    /// ```rust
+    /// # async fn example() {
+    /// use crate::models::Dept;
+    ///
+    /// // repo 是实现了 DeptRepository trait 的具体实例，查询回收站中的部门
+    /// let result = repo.select_dept_list_with_options(&Dept::default(), DeptQueryOptions::include_deleted()).await;
+    /// match result {
+    ///     Ok(depts) => println!("已删除{}个部门", depts.iter().filter(|dept| dept.deleted_at.is_some()).count()),
+    ///     Err(e) => println!("查询失败: {}", e),
+    /// }
+    /// # }
+    /// ```
+    async fn select_dept_list_with_options(&self, row: &Dept, options: DeptQueryOptions) -> Result<Vec<Dept>, RepositoryError>;
+
+    /// 分页查询部门列表
+    ///
+    /// 查询条件与 [DeptRepository::select_dept_list] 一致，先统计满足条件的总记录数，
+    /// 再按排序号、ID排序后通过 LIMIT/OFFSET 取出当前页数据。未设置排序号的部门排在最后，与部门树的显示顺序一致，各实现在所有数据库上的顺序一致。
+    ///
+    /// # 参数
+    /// * `row` - 查询条件，类型: [&Dept]
+    /// * `offset` - 跳过的记录数，类型: [u64]
+    /// * `limit` - 本页最多返回的记录数，类型: [u64]
+    ///
+    /// # 返回值
+    /// 返回当前页部门列表及总记录数，类型: [Result<(Vec<Dept>, u64), RepositoryError>]
+    ///
+    /// # 示例
+    /// ```rust
+    /// # async fn example() {
+    /// use crate::models::Dept;
+    ///
+    /// // 查询第2页，每页10条
+    /// let result = repo.select_dept_list_page(&Dept::default(), 10, 10).await;
+    /// match result {
+    ///     Ok((depts, total)) => println!("共{}个部门，本页{}个", total, depts.len()),
+    ///     Err(e) => println!("查询失败: {}", e),
+    /// }
+    /// # }
+    /// ```
+    async fn select_dept_list_page(&self, row: &Dept, offset: u64, limit: u64) -> Result<(Vec<Dept>, u64), RepositoryError>;
+
+    /// 根据主键更新部门
+    ///
+    /// 更新指定ID的部门信息，会更新所有字段，即使某些字段为None也会更新为NULL；已删除的部门不会被更新。
+    ///
+    /// # 参数
+    /// * `row` - 部门信息，类型: [&Dept]
+    ///
+    /// # 返回值
+    /// 返回更新影响的行数，类型: [Result<u64, RepositoryError>]
+    #[allow(unused)]
+    async fn update_by_primary_key(&self, row: &Dept) -> Result<u64, RepositoryError>;
+
+    /// 根据主键选择性更新部门
+    ///
+    /// 更新指定ID的部门信息，只会更新非None字段，对于None字段保持原值不变；已删除的部门不会被更新。
+    ///
+    /// # 参数
+    /// * `row` - 部门信息，类型: [&Dept]
+    ///
+    /// # 返回值
+    /// 返回更新影响的行数，类型: [Result<u64, RepositoryError>]
+    async fn update_by_primary_key_selective(&self, row: &Dept) -> Result<u64, RepositoryError>;
+
+    /// 替换下级部门祖级路径的前缀
+    ///
+    /// 部门移动到新的上级部门后，把祖级路径以 `old_path` 开头的全部部门的该前缀替换为 `new_path`，
+    /// 即 `UPDATE … SET ancestors = new_path || SUBSTR(ancestors, old_path的长度 + 1)`，一条语句更新整棵子树；
+    /// 前缀匹配区分大小写，已删除的部门不会被更新。
+    ///
+    /// # 参数
+    /// * `old_path` - 部门移动前的完整路径（[Dept::path]），类型: [&str]
+    /// * `new_path` - 部门移动后的完整路径，类型: [&str]
+    ///
+    /// # 返回值
+    /// 返回更新影响的行数，类型: [Result<u64, RepositoryError>]
+    async fn update_ancestors_prefix(&self, old_path: &str, new_path: &str) -> Result<u64, RepositoryError>;
+
+    /// 开启事务
+    ///
+    /// 返回的事务同样实现了 [DeptRepository]，通过它执行的读写都在同一个数据库事务中，
+    /// 调用 [DeptTransaction::commit] 后才会生效，未提交就被丢弃时自动回滚。
+    ///
+    /// 在事务中再次调用时各实现的行为不同：
+    /// - SeaORM 实现通过保存点（`SAVEPOINT`）开启嵌套事务，提交只释放保存点，回滚只撤销保存点之后的修改，
+    ///   外层事务提交后才真正生效
+    /// - SQLx、Diesel、内存实现不支持嵌套事务，返回 [RepositoryError::Transaction]
+    ///
+    /// 业务层不应依赖嵌套事务，需要在同一事务中执行的操作应共用同一个事务仓储。
+    ///
+    /// # 返回值
+    /// 返回事务，类型: [Result<Box<dyn DeptTransaction>, RepositoryError>]
+    ///
+    /// # 示例
+    /// ```rust
+    /// # async fn example() -> Result<(), RepositoryError> {
+    /// // repo 是实现了 DeptRepository trait 的具体实例
+    /// let tx = repo.begin().await?;
+    /// if tx.select_by_primary_key_for_update("1").await?.is_some() {
+    ///     tx.insert(&dept).await?;
+    /// }
+    /// tx.commit().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn begin(&self) -> Result<Box<dyn DeptTransaction>, RepositoryError>;
+}
+
+/// 部门数据访问事务
+///
+/// 由 [DeptRepository::begin] 开启，事务内的读写通过其实现的 [DeptRepository] 方法完成。
+/// 提交和回滚都会消耗事务，未调用二者就被丢弃时由具体实现自动回滚。
+#[async_trait]
+pub trait DeptTransaction: DeptRepository {
+    /// 提交事务
+    ///
+    /// # 返回值
+    /// 返回操作结果，类型: [Result<(), RepositoryError>]
+    async fn commit(self: Box<Self>) -> Result<(), RepositoryError>;
+
+    /// 回滚事务
+    ///
+    /// # 返回值
+    /// 返回操作结果，类型: [Result<(), RepositoryError>]
+    #[allow(unused)]
+    async fn rollback(self: Box<Self>) -> Result<(), RepositoryError>;
+}
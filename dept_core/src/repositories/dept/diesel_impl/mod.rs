@@ -0,0 +1,742 @@
+//! Diesel实现的部门数据访问层
+//!
+//! 该模块提供了基于Diesel ORM的部门数据访问实现，支持类型安全的数据库操作。
+//! 实现了DeptRepository trait定义的所有方法。
+//!
+//! 通过Diesel的 [MultiConnection](diesel::MultiConnection) 把多种数据库连接合并为 [DbConnection]，
+//! 具体连接的数据库由 `database.url` 的协议头决定，启用 `sqlite`、`postgres` 特性后可分别连接SQLite、PostgreSQL。
+//! 通过 [DeptRepository::begin] 开启的事务同样由该结构体实现，事务内的操作都使用同一个从连接池借出的连接。
+//! 同时实现了 [MigrationExecutor]，启动时通过同一个连接池执行数据库迁移。
+
+use crate::config::{Config, DatabaseType};
+use crate::errors::RepositoryError;
+use crate::migrations::{MIGRATIONS_TABLE, MigrationExecutor};
+use crate::models::Dept;
+use crate::repositories::dept::AncestorsPrefixFilter;
+use crate::repositories::dept::dept_repository::{DeptQueryOptions, DeptRepository, DeptTransaction};
+use crate::schema::sys_dept;
+use async_trait::async_trait;
+use chrono::{NaiveDateTime, Utc};
+use diesel::connection::{SimpleConnection, TransactionManager};
+use diesel::mysql::MysqlConnection;
+#[cfg(feature = "postgres")]
+use diesel::pg::PgConnection;
+use diesel::prelude::*;
+use diesel::r2d2::{ManageConnection, Pool, PooledConnection, R2D2Connection};
+use diesel::sql_query;
+#[cfg(feature = "sqlite")]
+use diesel::sqlite::SqliteConnection;
+use std::error::Error as StdError;
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// 按数据库类型区分的Diesel连接
+///
+/// 由MultiConnection派生出同时支持各数据库的连接类型及对应的 `MultiBackend`，
+/// 查询只需按 `MultiBackend` 编写一次。
+#[derive(diesel::MultiConnection)]
+pub enum DbConnection {
+    /// MySQL连接
+    Mysql(MysqlConnection),
+    /// SQLite连接
+    #[cfg(feature = "sqlite")]
+    Sqlite(SqliteConnection),
+    /// PostgreSQL连接
+    #[cfg(feature = "postgres")]
+    Postgres(PgConnection),
+}
+
+/// 按数据库类型建立连接的r2d2连接管理器
+///
+/// MultiConnection自带的建立连接逻辑会依次尝试每种数据库，
+/// 这里根据协议头直接选择对应的连接类型，连接失败时能得到准确的错误信息。
+#[derive(Debug)]
+struct DbConnectionManager {
+    database_url: String,
+    database_type: DatabaseType,
+}
+
+impl ManageConnection for DbConnectionManager {
+    type Connection = DbConnection;
+    type Error = diesel::r2d2::Error;
+
+    fn connect(&self) -> Result<Self::Connection, Self::Error> {
+        match self.database_type {
+            DatabaseType::MySql => MysqlConnection::establish(&self.database_url).map(DbConnection::Mysql),
+            #[cfg(feature = "sqlite")]
+            DatabaseType::Sqlite => SqliteConnection::establish(&self.database_url).map(DbConnection::Sqlite),
+            #[cfg(feature = "postgres")]
+            DatabaseType::Postgres => PgConnection::establish(&self.database_url).map(DbConnection::Postgres),
+        }
+        .map_err(diesel::r2d2::Error::ConnectionError)
+    }
+
+    fn is_valid(&self, conn: &mut Self::Connection) -> Result<(), Self::Error> {
+        conn.ping().map_err(diesel::r2d2::Error::QueryError)
+    }
+
+    fn has_broken(&self, conn: &mut Self::Connection) -> bool {
+        std::thread::panicking() || conn.is_broken()
+    }
+}
+
+/// [DbConnection] 使用的事务管理器
+type DbTransactionManager = <DbConnection as Connection>::TransactionManager;
+
+/// 事务独占的连接
+///
+/// 事务结束前一直从连接池借出，未提交或回滚就被丢弃时交给阻塞线程池回滚事务再归还连接池。
+struct TransactionConnection {
+    /// 借出的连接，只在丢弃时取出
+    conn: Option<PooledConnection<DbConnectionManager>>,
+    /// 事务是否已提交或回滚
+    finished: bool,
+}
+
+impl TransactionConnection {
+    /// 事务独占的连接
+    fn conn(&mut self) -> &mut DbConnection {
+        self.conn.as_mut().expect("事务连接只在丢弃时取出")
+    }
+}
+
+impl Debug for TransactionConnection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TransactionConnection")
+            .field("finished", &self.finished)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for TransactionConnection {
+    fn drop(&mut self) {
+        if self.finished {
+            return;
+        }
+        let Some(mut conn) = self.conn.take() else {
+            return;
+        };
+        // 回滚失败时连接仍处于事务中，归还后会被连接池的 has_broken 检查丢弃
+        let rollback = move || {
+            if let Err(e) = DbTransactionManager::rollback_transaction(&mut *conn) {
+                eprintln!("回滚未结束的事务失败: {e}");
+            }
+        };
+        // 通常在异步线程上因提前返回而丢弃，与 run 相同交给阻塞线程池执行，避免阻塞异步运行时
+        match tokio::runtime::Handle::try_current() {
+            Ok(handle) => {
+                handle.spawn_blocking(rollback);
+            },
+            Err(_) => rollback(),
+        }
+    }
+}
+
+/// Diesel实现的部门仓储
+#[derive(Debug)]
+pub struct DeptRepositoryDieselImpl {
+    pool: Pool<DbConnectionManager>,
+    database_type: DatabaseType,
+    /// 事务中的仓储独占的连接，为None时每次操作从连接池借出连接并自动提交
+    transaction: Option<Arc<Mutex<TransactionConnection>>>,
+}
+
+impl DeptRepositoryDieselImpl {
+    /// 创建新的Diesel部门仓储实例
+    ///
+    /// # 返回值
+    /// 返回新的部门仓储实例
+    pub fn new() -> Result<Self, Box<dyn StdError + Send + Sync>> {
+        // 从配置文件中读取数据库URL和连接池配置
+        let config = Config::from_default_file().expect("无法加载配置文件");
+        let database_type = config.database.database_type()?;
+        let manager = DbConnectionManager {
+            database_url: Self::connection_url(&config.database.url, database_type),
+            database_type,
+        };
+
+        // 构建连接池配置
+        let mut pool_builder = Pool::builder();
+
+        // 设置最大连接数
+        if let Some(max_size) = config.database.diesel.max_size {
+            pool_builder = pool_builder.max_size(max_size);
+        }
+
+        // 设置最小空闲连接数
+        if let Some(min_idle) = config.database.diesel.min_idle {
+            pool_builder = pool_builder.min_idle(Some(min_idle));
+        }
+
+        // 设置连接超时时间
+        if let Some(connection_timeout) = config.database.diesel.connection_timeout {
+            pool_builder = pool_builder.connection_timeout(Duration::from_secs(connection_timeout));
+        }
+
+        // 设置连接最大存活时间
+        if let Some(max_lifetime) = config.database.diesel.max_lifetime {
+            pool_builder = pool_builder.max_lifetime(Some(Duration::from_secs(max_lifetime)));
+        }
+
+        // 设置空闲连接超时时间
+        if let Some(idle_timeout) = config.database.diesel.idle_timeout {
+            pool_builder = pool_builder.idle_timeout(Some(Duration::from_secs(idle_timeout)));
+        }
+
+        // 设置借出连接时测试其有效性
+        if let Some(test_on_check_out) = config.database.diesel.test_on_check_out {
+            pool_builder = pool_builder.test_on_check_out(test_on_check_out);
+        }
+
+        // SQLite内存库随连接关闭而销毁，只保留一个常驻连接
+        #[cfg(feature = "sqlite")]
+        if config.database.is_sqlite_memory() {
+            pool_builder = pool_builder
+                .max_size(1)
+                .min_idle(Some(1))
+                .max_lifetime(None)
+                .idle_timeout(None);
+        }
+
+        let pool = pool_builder
+            .build(manager)
+            .map_err(|e| Box::new(e) as Box<dyn StdError + Send + Sync>)?;
+
+        // 执行初始化脚本
+        if let Some(script) = config.database.read_init_script()? {
+            let mut conn = pool.get()?;
+            conn.batch_execute(&script)?;
+        }
+
+        Ok(Self { pool, database_type, transaction: None })
+    }
+
+    /// 将配置中的数据库URL转换为Diesel建立连接使用的地址
+    ///
+    /// Diesel的SQLite连接只接受文件路径或 `:memory:`，需要去掉 `sqlite:` 协议头及查询参数，
+    /// 其他数据库直接使用原URL。
+    fn connection_url(url: &str, database_type: DatabaseType) -> String {
+        match database_type {
+            DatabaseType::MySql => url.to_string(),
+            #[cfg(feature = "sqlite")]
+            DatabaseType::Sqlite => {
+                let path = url
+                    .trim_start_matches("sqlite://")
+                    .trim_start_matches("sqlite:");
+                let path = path.split('?').next().unwrap_or_default();
+                if path.is_empty() || path == ":memory:" || url.contains("mode=memory") { ":memory:".to_string() } else { path.to_string() }
+            },
+            #[cfg(feature = "postgres")]
+            DatabaseType::Postgres => url.to_string(),
+        }
+    }
+
+    /// 在阻塞线程中使用数据库连接执行操作
+    ///
+    /// Diesel是同步ORM，为了适配异步trait需要在阻塞线程中执行；
+    /// 事务中的仓储使用事务独占的连接，否则从连接池借出连接。
+    async fn run<T, F>(&self, f: F) -> Result<T, RepositoryError>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut DbConnection) -> Result<T, RepositoryError> + Send + 'static,
+    {
+        let pool = self.pool.clone();
+        let transaction = self.transaction.clone();
+
+        tokio::task::spawn_blocking(move || match transaction {
+            Some(transaction) => {
+                let mut transaction = transaction
+                    .lock()
+                    .map_err(|_| RepositoryError::Transaction("事务连接不可用".to_string()))?;
+                f(transaction.conn())
+            },
+            None => {
+                let mut conn = pool.get().map_err(RepositoryError::from)?;
+                f(&mut *conn)
+            },
+        })
+        .await?
+    }
+
+    /// 提交或回滚事务
+    async fn finish(&self, commit: bool) -> Result<(), RepositoryError> {
+        let Some(transaction) = self.transaction.clone() else {
+            return Err(RepositoryError::Transaction("当前仓储未开启事务".to_string()));
+        };
+
+        tokio::task::spawn_blocking(move || {
+            let mut transaction = transaction
+                .lock()
+                .map_err(|_| RepositoryError::Transaction("事务连接不可用".to_string()))?;
+            transaction.finished = true;
+            let conn = transaction.conn();
+            let result = if commit { DbTransactionManager::commit_transaction(conn) } else { DbTransactionManager::rollback_transaction(conn) };
+            result.map_err(RepositoryError::from)
+        })
+        .await?
+    }
+
+    /// 构建查询部门列表的动态查询，分页查询与计数查询共用同一组条件
+    fn build_select_dept_list_query(row: &Dept, options: DeptQueryOptions) -> sys_dept::BoxedQuery<'_, MultiBackend> {
+        let mut query = sys_dept::table.into_boxed();
+
+        // 默认排除已删除的部门
+        if !options.include_deleted {
+            query = query.filter(sys_dept::deleted_at.is_null());
+        }
+
+        // 添加所有可能的查询条件
+        if !row.id.is_empty() {
+            query = query.filter(sys_dept::id.eq(&row.id));
+        }
+
+        if let Some(ref name_value) = row.name {
+            query = query.filter(sys_dept::name.like(format!("%{}%", name_value)));
+        }
+
+        if let Some(ref email_value) = row.email {
+            query = query.filter(sys_dept::email.eq(email_value));
+        }
+
+        if let Some(ref telephone_value) = row.telephone {
+            query = query.filter(sys_dept::telephone.eq(telephone_value));
+        }
+
+        if let Some(ref address_value) = row.address {
+            query = query.filter(sys_dept::address.eq(address_value));
+        }
+
+        if let Some(ref logo_value) = row.logo {
+            query = query.filter(sys_dept::logo.eq(logo_value));
+        }
+
+        if let Some(ref parent_id_value) = row.parent_id {
+            query = query.filter(sys_dept::parent_id.eq(parent_id_value));
+        }
+
+        if let Some(seq_no_value) = row.seq_no {
+            query = query.filter(sys_dept::seq_no.eq(seq_no_value));
+        }
+
+        if let Some(status_value) = row.status {
+            query = query.filter(sys_dept::status.eq(status_value));
+        }
+
+        if let Some(ref create_by_value) = row.create_by {
+            query = query.filter(sys_dept::create_by.eq(create_by_value));
+        }
+
+        if let Some(create_time_value) = row.create_time {
+            query = query.filter(sys_dept::create_time.eq(create_time_value));
+        }
+
+        if let Some(ref update_by_value) = row.update_by {
+            query = query.filter(sys_dept::update_by.eq(update_by_value));
+        }
+
+        if let Some(update_time_value) = row.update_time {
+            query = query.filter(sys_dept::update_time.eq(update_time_value));
+        }
+
+        if let Some(ref remark_value) = row.remark {
+            query = query.filter(sys_dept::remark.eq(remark_value));
+        }
+
+        if let Some(ref ancestors_value) = row.ancestors {
+            query = query.filter(sys_dept::ancestors.eq(ancestors_value));
+        }
+
+        if let Some(deleted_at_value) = row.deleted_at {
+            query = query.filter(sys_dept::deleted_at.eq(deleted_at_value));
+        }
+
+        query
+    }
+}
+
+#[async_trait]
+impl DeptRepository for DeptRepositoryDieselImpl {
+    // 为了方便维护和管理model和sqlx的共用,且不像官方教程一样crud都定义一个结构体,而是通过业务控制,这样更通用合理
+
+    /// 根据主键逻辑删除部门，记录删除时间
+    async fn delete_by_primary_key(&self, id: &str) -> Result<(), RepositoryError> {
+        let id_value = id.to_string();
+        let deleted_at = Utc::now().naive_utc();
+
+        self.run(move |conn| {
+            diesel::update(
+                sys_dept::table
+                    .filter(sys_dept::id.eq(id_value))
+                    .filter(sys_dept::deleted_at.is_null()),
+            )
+            .set(sys_dept::deleted_at.eq(Some(deleted_at)))
+            .execute(conn)
+            .map_err(RepositoryError::from)?;
+
+            Ok::<(), RepositoryError>(())
+        })
+        .await
+    }
+
+    /// 根据主键恢复已删除的部门
+    async fn restore_by_primary_key(&self, id: &str) -> Result<u64, RepositoryError> {
+        let id_value = id.to_string();
+
+        self.run(move |conn| {
+            let result = diesel::update(
+                sys_dept::table
+                    .filter(sys_dept::id.eq(id_value))
+                    .filter(sys_dept::deleted_at.is_not_null()),
+            )
+            .set(sys_dept::deleted_at.eq(None::<NaiveDateTime>))
+            .execute(conn)
+            .map_err(RepositoryError::from)?;
+
+            Ok::<u64, RepositoryError>(result as u64)
+        })
+        .await
+    }
+
+    /// 根据主键彻底删除已删除的部门
+    async fn purge_by_primary_key(&self, id: &str) -> Result<u64, RepositoryError> {
+        let id_value = id.to_string();
+
+        self.run(move |conn| {
+            let result = diesel::delete(
+                sys_dept::table
+                    .filter(sys_dept::id.eq(id_value))
+                    .filter(sys_dept::deleted_at.is_not_null()),
+            )
+            .execute(conn)
+            .map_err(RepositoryError::from)?;
+
+            Ok::<u64, RepositoryError>(result as u64)
+        })
+        .await
+    }
+
+    /// 插入部门记录
+    ///
+    /// 由于Diesel是通过Option字段来实现选择性插入的ORM，
+    /// 且当前Dept模型中所有字段都是Option类型，
+    /// 完整插入和选择性插入的逻辑是一致的，都是根据字段是否有值来决定是否插入。
+    /// 因此直接调用选择性插入方法即可，避免代码重复。
+    async fn insert(&self, row: &Dept) -> Result<(), RepositoryError> {
+        // 直接调用选择性插入方法，因为当前模型所有字段都是Option类型
+        // 无论字段是否有值，insert_selective都能正确处理
+        self.insert_selective(row).await
+    }
+
+    /// 选择性插入部门记录
+    async fn insert_selective(&self, row: &Dept) -> Result<(), RepositoryError> {
+        let row = row.clone();
+
+        self.run(move |conn| {
+            diesel::insert_into(sys_dept::table)
+                .values(&row)
+                .execute(conn)
+                .map_err(RepositoryError::from)?;
+
+            Ok::<(), RepositoryError>(())
+        })
+        .await
+    }
+
+    /// 根据主键查询部门
+    async fn select_by_primary_key(&self, id: &str) -> Result<Option<Dept>, RepositoryError> {
+        let id_value = id.to_string();
+
+        self.run(move |conn| {
+            let result = sys_dept::table
+                .filter(sys_dept::id.eq(id_value))
+                .filter(sys_dept::deleted_at.is_null())
+                .first::<Dept>(conn)
+                .optional()
+                .map_err(RepositoryError::from)?;
+
+            Ok::<Option<Dept>, RepositoryError>(result)
+        })
+        .await
+    }
+
+    /// 根据主键查询并锁定部门
+    ///
+    /// MultiBackend不支持Diesel的 `for_update`，与按父部门查询一样使用原生SQL
+    async fn select_by_primary_key_for_update(&self, id: &str) -> Result<Option<Dept>, RepositoryError> {
+        let id_value = id.to_string();
+        let database_type = self.database_type;
+
+        self.run(move |conn| {
+            use crate::models::constants::DEPT_FIELDS;
+            let sql = database_type.adapt_placeholders(&format!("SELECT {DEPT_FIELDS} FROM sys_dept WHERE deleted_at IS NULL AND id = ?{}", database_type.for_update_clause()));
+            let result = sql_query(sql)
+                .bind::<diesel::sql_types::Text, _>(id_value)
+                .get_result::<Dept>(conn)
+                .optional()
+                .map_err(RepositoryError::from)?;
+
+            Ok::<Option<Dept>, RepositoryError>(result)
+        })
+        .await
+    }
+
+    /// 根据父部门ID查询部门
+    ///
+    /// 使用原生SQL实现以优化性能，避免ORM可能带来的性能开销
+    async fn select_dept_by_parent_id(&self, parent_id: &str) -> Result<Vec<Dept>, RepositoryError> {
+        let parent_id_value = parent_id.to_string(); // 克隆字符串以解决生命周期问题
+        let database_type = self.database_type;
+
+        self.run(move |conn| {
+            // 使用原生SQL查询以优化性能
+            use crate::models::constants::DEPT_FIELDS;
+            // 使用DEPT_FIELDS常量构建SQL查询
+            let sql = database_type.adapt_placeholders(&format!("SELECT {DEPT_FIELDS} FROM sys_dept WHERE deleted_at IS NULL AND parent_id = ?"));
+            let results = sql_query(sql)
+                .bind::<diesel::sql_types::Nullable<diesel::sql_types::Text>, _>(parent_id_value)
+                .load::<Dept>(conn)
+                .map_err(RepositoryError::from)?;
+
+            Ok::<Vec<Dept>, RepositoryError>(results)
+        })
+        .await
+    }
+
+    /// 查询部门的全部下级部门，按祖级路径前缀匹配
+    ///
+    /// 前缀匹配的写法因数据库而异（SQLite使用GLOB），与按父部门查询一样使用原生SQL
+    async fn select_descendants(&self, id: &str) -> Result<Vec<Dept>, RepositoryError> {
+        let Some(dept) = self.select_by_primary_key(id).await? else {
+            return Ok(Vec::new());
+        };
+        let database_type = self.database_type;
+        let filter = AncestorsPrefixFilter::new(database_type, &dept.path());
+
+        self.run(move |conn| {
+            use crate::models::constants::DEPT_FIELDS;
+            let sql = database_type.adapt_placeholders(&format!("SELECT {DEPT_FIELDS} FROM sys_dept WHERE deleted_at IS NULL AND {}?{} ORDER BY id", filter.head, filter.tail));
+            let results = sql_query(sql)
+                .bind::<diesel::sql_types::Text, _>(filter.pattern)
+                .load::<Dept>(conn)
+                .map_err(RepositoryError::from)?;
+
+            Ok::<Vec<Dept>, RepositoryError>(results)
+        })
+        .await
+    }
+
+    /// 查询部门的全部上级部门，按祖级路径中的顺序排列
+    async fn select_ancestors(&self, id: &str) -> Result<Vec<Dept>, RepositoryError> {
+        let Some(dept) = self.select_by_primary_key(id).await? else {
+            return Ok(Vec::new());
+        };
+        let ids: Vec<String> = dept
+            .ancestor_ids()
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        self.run(move |conn| {
+            let mut results = sys_dept::table
+                .filter(sys_dept::deleted_at.is_null())
+                .filter(sys_dept::id.eq_any(&ids))
+                .load::<Dept>(conn)
+                .map_err(RepositoryError::from)?;
+            results.sort_by_key(|dept| ids.iter().position(|id| *id == dept.id));
+
+            Ok::<Vec<Dept>, RepositoryError>(results)
+        })
+        .await
+    }
+
+    /// 按查询选项查询部门列表
+    async fn select_dept_list_with_options(&self, row: &Dept, options: DeptQueryOptions) -> Result<Vec<Dept>, RepositoryError> {
+        let row = row.clone();
+
+        self.run(move |conn| {
+            // 构建查询条件
+            let query = Self::build_select_dept_list_query(&row, options);
+
+            // 执行查询
+            let results = query.load::<Dept>(conn).map_err(RepositoryError::from)?;
+
+            Ok::<Vec<Dept>, RepositoryError>(results)
+        })
+        .await
+    }
+
+    /// 分页查询部门列表
+    async fn select_dept_list_page(&self, row: &Dept, offset: u64, limit: u64) -> Result<(Vec<Dept>, u64), RepositoryError> {
+        let row = row.clone();
+
+        self.run(move |conn| {
+            // 先统计满足条件的总记录数
+            let total = Self::build_select_dept_list_query(&row, DeptQueryOptions::default())
+                .count()
+                .get_result::<i64>(conn)
+                .map_err(RepositoryError::from)?;
+
+            // 没有满足条件的记录时无需再查询当前页
+            if total == 0 {
+                return Ok((Vec::new(), 0));
+            }
+
+            // 各数据库对NULL的默认排序不同（MySQL、SQLite升序时排在最前），显式把未设置排序号的排在最后，与部门树的显示顺序一致
+            let results = Self::build_select_dept_list_query(&row, DeptQueryOptions::default())
+                .order((sys_dept::seq_no.is_null().asc(), sys_dept::seq_no.asc(), sys_dept::id.asc()))
+                .limit(limit as i64)
+                .offset(offset as i64)
+                .load::<Dept>(conn)
+                .map_err(RepositoryError::from)?;
+
+            Ok::<(Vec<Dept>, u64), RepositoryError>((results, total as u64))
+        })
+        .await
+    }
+
+    /// 根据主键更新部门
+    ///
+    /// 由于Diesel是通过Option字段来实现选择性更新的ORM，
+    /// 且当前Dept模型中所有字段都是Option类型，
+    /// 完整更新和选择性更新的逻辑是一致的，都是根据字段是否有值来决定是否更新。
+    /// 因此直接调用选择性更新方法即可，避免代码重复。
+    async fn update_by_primary_key(&self, row: &Dept) -> Result<u64, RepositoryError> {
+        // 直接调用选择性更新方法，因为当前模型所有字段都是Option类型
+        // 无论字段是否有值，update_by_primary_key_selective都能正确处理
+        self.update_by_primary_key_selective(row).await
+    }
+
+    /// 根据主键选择性更新部门
+    async fn update_by_primary_key_selective(&self, row: &Dept) -> Result<u64, RepositoryError> {
+        let row = row.clone();
+
+        self.run(move |conn| {
+            let result = diesel::update(
+                sys_dept::table
+                    .filter(sys_dept::id.eq(&row.id))
+                    .filter(sys_dept::deleted_at.is_null()),
+            )
+            .set(&row)
+            .execute(conn)
+            .map_err(RepositoryError::from)?;
+
+            Ok::<u64, RepositoryError>(result as u64)
+        })
+        .await
+    }
+
+    /// 替换下级部门祖级路径的前缀
+    ///
+    /// 按数据库拼接前缀匹配条件及字符串连接（MySQL使用CONCAT，其他数据库使用 `||`），使用原生SQL实现
+    async fn update_ancestors_prefix(&self, old_path: &str, new_path: &str) -> Result<u64, RepositoryError> {
+        let database_type = self.database_type;
+        let filter = AncestorsPrefixFilter::new(database_type, old_path);
+        let new_path = new_path.to_string();
+        // SUBSTR按字符计数，从1开始
+        let start = old_path.chars().count() as i32 + 1;
+
+        self.run(move |conn| {
+            let ancestors = if database_type == DatabaseType::MySql { "CONCAT(?, SUBSTR(ancestors, ?))" } else { "? || SUBSTR(ancestors, ?)" };
+            let sql = database_type.adapt_placeholders(&format!("UPDATE sys_dept SET ancestors = {ancestors} WHERE deleted_at IS NULL AND {}?{}", filter.head, filter.tail));
+            let result = sql_query(sql)
+                .bind::<diesel::sql_types::Text, _>(new_path)
+                .bind::<diesel::sql_types::Integer, _>(start)
+                .bind::<diesel::sql_types::Text, _>(filter.pattern)
+                .execute(conn)
+                .map_err(RepositoryError::from)?;
+
+            Ok::<u64, RepositoryError>(result as u64)
+        })
+        .await
+    }
+
+    /// 开启事务，从连接池借出一个连接，事务结束前由该事务独占
+    async fn begin(&self) -> Result<Box<dyn DeptTransaction>, RepositoryError> {
+        if self.transaction.is_some() {
+            return Err(RepositoryError::Transaction("Diesel实现不支持嵌套事务".to_string()));
+        }
+
+        let pool = self.pool.clone();
+        let conn = tokio::task::spawn_blocking(move || {
+            let mut conn = pool.get().map_err(RepositoryError::from)?;
+
+            DbTransactionManager::begin_transaction(&mut *conn).map_err(RepositoryError::from)?;
+
+            Ok::<PooledConnection<DbConnectionManager>, RepositoryError>(conn)
+        })
+        .await??;
+
+        Ok(Box::new(Self {
+            pool: self.pool.clone(),
+            database_type: self.database_type,
+            transaction: Some(Arc::new(Mutex::new(TransactionConnection { conn: Some(conn), finished: false }))),
+        }))
+    }
+}
+
+#[async_trait]
+impl DeptTransaction for DeptRepositoryDieselImpl {
+    /// 提交事务
+    async fn commit(self: Box<Self>) -> Result<(), RepositoryError> {
+        self.finish(true).await
+    }
+
+    /// 回滚事务
+    async fn rollback(self: Box<Self>) -> Result<(), RepositoryError> {
+        self.finish(false).await
+    }
+}
+
+/// 迁移历史表中的版本号
+#[derive(QueryableByName)]
+struct AppliedVersion {
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    version: i64,
+}
+
+#[async_trait]
+impl MigrationExecutor for DeptRepositoryDieselImpl {
+    fn database_type(&self) -> DatabaseType {
+        self.database_type
+    }
+
+    /// 执行迁移脚本
+    async fn execute_script(&self, script: &str) -> Result<(), Box<dyn StdError + Send + Sync>> {
+        let script = script.to_string();
+        let pool = self.pool.clone();
+
+        tokio::task::spawn_blocking(move || {
+            let mut conn = pool
+                .get()
+                .map_err(|e| Box::new(e) as Box<dyn StdError + Send + Sync>)?;
+
+            conn.batch_execute(&script)
+                .map_err(|e| Box::new(e) as Box<dyn StdError + Send + Sync>)?;
+
+            Ok::<(), Box<dyn StdError + Send + Sync>>(())
+        })
+        .await?
+    }
+
+    /// 查询已应用的迁移版本
+    async fn select_applied_versions(&self) -> Result<Vec<i64>, Box<dyn StdError + Send + Sync>> {
+        let pool = self.pool.clone();
+
+        tokio::task::spawn_blocking(move || {
+            let mut conn = pool
+                .get()
+                .map_err(|e| Box::new(e) as Box<dyn StdError + Send + Sync>)?;
+
+            let versions = sql_query(format!("SELECT version FROM {MIGRATIONS_TABLE} ORDER BY version"))
+                .load::<AppliedVersion>(&mut conn)
+                .map_err(|e| Box::new(e) as Box<dyn StdError + Send + Sync>)?;
+
+            Ok::<Vec<i64>, Box<dyn StdError + Send + Sync>>(versions.into_iter().map(|row| row.version).collect())
+        })
+        .await?
+    }
+}
@@ -0,0 +1,413 @@
+//! 内存实现的部门数据访问层
+//!
+//! 该模块提供了基于 `RwLock<HashMap>` 的部门数据访问实现，数据只保存在当前进程中，
+//! 不需要任何数据库即可运行，适合在单元测试、集成测试中替代真实的数据访问层。
+//!
+//! 查询条件与SQL实现保持一致：部门名称按 `LIKE '%name%'` 模糊匹配（`%`、`_` 为通配符，ASCII字母不区分大小写），
+//! 其余字段按相等匹配，值为NULL的字段不会与任何条件相等；已删除的部门只有查询选项中包含已删除部门时才会返回。
+//!
+//! 事务在开启时复制一份当前数据，事务内的读写都作用于副本，提交时只写回事务内修改过的部门；
+//! 同一时间只允许一个事务，后开启的事务等待前一个事务结束，相当于数据库中锁定了全部部门。
+
+use crate::errors::RepositoryError;
+use crate::models::Dept;
+use crate::models::dept::{ANCESTORS_SEPARATOR, ROOT_ANCESTORS};
+use crate::repositories::dept::dept_repository::{DeptQueryOptions, DeptRepository, DeptTransaction};
+use async_trait::async_trait;
+use chrono::{NaiveDateTime, Utc};
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+use tokio::sync::{Mutex, OwnedMutexGuard};
+
+/// 部门数据访问层的内存实现
+///
+/// 通过 [Default] 创建的实例不包含任何部门
+#[derive(Debug, Default)]
+pub struct DeptRepositoryMemoryImpl {
+    /// 部门数据，键为部门id
+    depts: Arc<RwLock<HashMap<String, Dept>>>,
+    /// 事务锁，事务开启后一直持有到提交或回滚
+    transaction_lock: Arc<Mutex<()>>,
+    /// 事务中的仓储提交时需要的信息，非事务仓储为None
+    transaction: Option<MemoryTransaction>,
+}
+
+/// 内存事务
+#[derive(Debug)]
+struct MemoryTransaction {
+    /// 提交时写回的原数据
+    target: Arc<RwLock<HashMap<String, Dept>>>,
+    /// 事务开启时的数据，提交时与事务内的数据比较得出修改过的部门
+    snapshot: HashMap<String, Dept>,
+    /// 持有期间其他事务无法开启
+    _guard: OwnedMutexGuard<()>,
+}
+
+impl DeptRepositoryMemoryImpl {
+    /// 使用给定的部门数据创建实例，id相同的部门以后出现的为准
+    pub fn with_depts(depts: impl IntoIterator<Item = Dept>) -> Self {
+        let depts = depts
+            .into_iter()
+            .map(|dept| (dept.id.clone(), dept))
+            .collect();
+        Self {
+            depts: Arc::new(RwLock::new(depts)),
+            ..Default::default()
+        }
+    }
+
+    /// 创建包含演示部门数据的实例
+    ///
+    /// 部门层级、排序及状态与迁移 `migrations/0002_insert_demo_data` 导入的演示数据一致，省略了联系方式、备注等字段，
+    /// 祖级路径与迁移 `migrations/0003_add_dept_ancestors` 回填的结果一致，便于在没有数据库的环境下直接体验部门的增删改查
+    pub fn with_demo_data() -> Self {
+        const ROOT: &str = "065a3eb180214ccfbb653f63287d285d";
+        const HEAD_OFFICE: &str = "6bcbfa6a71fa454d8363b885ccbcb30a";
+        const BRANCH_OFFICE: &str = "a308936d3b5d49e185fe15bf7d71b688";
+        let dept = |id: &str, parent_id: &str, name: &str, seq_no: Option<i32>, status: i32, create_time: &str, ancestors: &[&str]| Dept {
+            id: id.to_string(),
+            parent_id: Some(parent_id.to_string()),
+            name: Some(name.to_string()),
+            seq_no,
+            status: Some(status),
+            create_by: Some("admin".to_string()),
+            create_time: NaiveDateTime::parse_from_str(create_time, "%Y-%m-%d %H:%M:%S").ok(),
+            ancestors: Some(
+                ancestors
+                    .iter()
+                    .fold(ROOT_ANCESTORS.to_string(), |path, id| format!("{path}{id}{ANCESTORS_SEPARATOR}")),
+            ),
+            ..Default::default()
+        };
+        Self::with_depts([
+            dept(ROOT, "", "NB PLUS 科技", Some(1), 1, "2024-09-25 17:29:32", &[]),
+            dept("0753bee6314c4f6fa01cb5166e6e4b14", HEAD_OFFICE, "市场部", Some(1), 0, "2024-10-01 01:40:07", &[ROOT, HEAD_OFFICE]),
+            dept("3fd2fbc8c93e4a20a6e46387b36c0264", HEAD_OFFICE, "销售部", Some(1), 0, "2024-09-25 17:38:06", &[ROOT, HEAD_OFFICE]),
+            dept(HEAD_OFFICE, ROOT, "XXX总公司", Some(1), 1, "2024-09-25 17:35:22", &[ROOT]),
+            dept("88921a7b70a04ea2b7d1db1651ffe666", HEAD_OFFICE, "研发部", Some(1), 1, "2024-09-26 01:37:41", &[ROOT, HEAD_OFFICE]),
+            dept(BRANCH_OFFICE, ROOT, "XXX分公司", Some(1), 1, "2024-09-25 17:43:24", &[ROOT]),
+            dept("ebd8118fe94247809abab8c72579cf7d", BRANCH_OFFICE, "运维", None, 0, "2024-10-13 19:34:06", &[ROOT, BRANCH_OFFICE]),
+            dept("f9271a783e074715aceeb4d9b427004d", HEAD_OFFICE, "财务部", Some(1), 0, "2024-09-26 01:39:45", &[ROOT, HEAD_OFFICE]),
+        ])
+    }
+
+    /// 获取读锁
+    fn read(&self) -> Result<RwLockReadGuard<'_, HashMap<String, Dept>>, RepositoryError> {
+        self.depts
+            .read()
+            .map_err(|e| RepositoryError::Database(e.to_string().into()))
+    }
+
+    /// 获取写锁
+    fn write(&self) -> Result<RwLockWriteGuard<'_, HashMap<String, Dept>>, RepositoryError> {
+        self.depts
+            .write()
+            .map_err(|e| RepositoryError::Database(e.to_string().into()))
+    }
+
+    /// 查询满足条件的部门，按id排序以保证多次查询的结果顺序一致
+    fn select_matched(&self, row: &Dept, options: DeptQueryOptions) -> Result<Vec<Dept>, RepositoryError> {
+        let mut list: Vec<Dept> = self
+            .read()?
+            .values()
+            .filter(|dept| (options.include_deleted || dept.deleted_at.is_none()) && Self::matches(row, dept))
+            .cloned()
+            .collect();
+        list.sort_by(|a, b| a.id.cmp(&b.id));
+        Ok(list)
+    }
+
+    /// 判断部门是否满足查询条件，条件与SQL实现的WHERE子句一一对应
+    fn matches(row: &Dept, dept: &Dept) -> bool {
+        (row.id.is_empty() || row.id == dept.id)
+            && row.name.as_ref().is_none_or(|name| {
+                dept.name
+                    .as_deref()
+                    .is_some_and(|value| Self::like(value, &format!("%{}%", name)))
+            })
+            && Self::eq(&row.email, &dept.email)
+            && Self::eq(&row.telephone, &dept.telephone)
+            && Self::eq(&row.address, &dept.address)
+            && Self::eq(&row.logo, &dept.logo)
+            && Self::eq(&row.parent_id, &dept.parent_id)
+            && Self::eq(&row.seq_no, &dept.seq_no)
+            && Self::eq(&row.status, &dept.status)
+            && Self::eq(&row.create_by, &dept.create_by)
+            && Self::eq(&row.create_time, &dept.create_time)
+            && Self::eq(&row.update_by, &dept.update_by)
+            && Self::eq(&row.update_time, &dept.update_time)
+            && Self::eq(&row.remark, &dept.remark)
+            && Self::eq(&row.ancestors, &dept.ancestors)
+            && Self::eq(&row.deleted_at, &dept.deleted_at)
+    }
+
+    /// 相等条件，未设置条件时总是满足
+    fn eq<T: PartialEq>(condition: &Option<T>, value: &Option<T>) -> bool {
+        condition
+            .as_ref()
+            .is_none_or(|condition| value.as_ref() == Some(condition))
+    }
+
+    /// 按SQL的LIKE规则匹配，`%` 匹配任意个字符，`_` 匹配单个字符
+    fn like(value: &str, pattern: &str) -> bool {
+        let value: Vec<char> = value.chars().map(|c| c.to_ascii_lowercase()).collect();
+        let pattern: Vec<char> = pattern.chars().map(|c| c.to_ascii_lowercase()).collect();
+        let (mut v, mut p) = (0, 0);
+        // 最近一次遇到的 `%` 的位置及其开始匹配的字符位置，匹配失败时从这里回溯
+        let mut backtrack: Option<(usize, usize)> = None;
+        while v < value.len() {
+            if p < pattern.len() && (pattern[p] == '_' || pattern[p] == value[v]) {
+                v += 1;
+                p += 1;
+            } else if p < pattern.len() && pattern[p] == '%' {
+                backtrack = Some((p, v));
+                p += 1;
+            } else if let Some((percent, start)) = backtrack {
+                backtrack = Some((percent, start + 1));
+                p = percent + 1;
+                v = start + 1;
+            } else {
+                return false;
+            }
+        }
+        pattern[p..].iter().all(|&c| c == '%')
+    }
+}
+
+#[async_trait]
+impl DeptRepository for DeptRepositoryMemoryImpl {
+    /// 根据主键逻辑删除部门，记录删除时间
+    async fn delete_by_primary_key(&self, id: &str) -> Result<(), RepositoryError> {
+        if let Some(dept) = self.write()?.get_mut(id)
+            && dept.deleted_at.is_none()
+        {
+            dept.deleted_at = Some(Utc::now().naive_utc());
+        }
+        Ok(())
+    }
+
+    /// 根据主键恢复已删除的部门
+    async fn restore_by_primary_key(&self, id: &str) -> Result<u64, RepositoryError> {
+        match self.write()?.get_mut(id) {
+            Some(dept) if dept.deleted_at.is_some() => {
+                dept.deleted_at = None;
+                Ok(1)
+            },
+            _ => Ok(0),
+        }
+    }
+
+    /// 根据主键彻底删除已删除的部门
+    async fn purge_by_primary_key(&self, id: &str) -> Result<u64, RepositoryError> {
+        let mut depts = self.write()?;
+        match depts.get(id) {
+            Some(dept) if dept.deleted_at.is_some() => Ok(depts.remove(id).is_some() as u64),
+            _ => Ok(0),
+        }
+    }
+
+    /// 插入部门记录，id已存在时与数据库的主键冲突一样返回错误
+    async fn insert(&self, row: &Dept) -> Result<(), RepositoryError> {
+        let mut depts = self.write()?;
+        if depts.contains_key(&row.id) {
+            return Err(RepositoryError::Duplicate(format!("部门id已存在: {}", row.id)));
+        }
+        depts.insert(row.id.clone(), row.clone());
+        Ok(())
+    }
+
+    /// 选择性插入部门记录，未设置的字段本来就是None，与全字段插入相同
+    async fn insert_selective(&self, row: &Dept) -> Result<(), RepositoryError> {
+        self.insert(row).await
+    }
+
+    /// 根据主键查询部门
+    async fn select_by_primary_key(&self, id: &str) -> Result<Option<Dept>, RepositoryError> {
+        Ok(self
+            .read()?
+            .get(id)
+            .filter(|dept| dept.deleted_at.is_none())
+            .cloned())
+    }
+
+    /// 根据主键查询并锁定部门，事务本身已串行执行，无需额外加锁
+    async fn select_by_primary_key_for_update(&self, id: &str) -> Result<Option<Dept>, RepositoryError> {
+        self.select_by_primary_key(id).await
+    }
+
+    /// 根据父部门ID查询部门
+    async fn select_dept_by_parent_id(&self, parent_id: &str) -> Result<Vec<Dept>, RepositoryError> {
+        self.select_matched(
+            &Dept {
+                parent_id: Some(parent_id.to_string()),
+                ..Default::default()
+            },
+            DeptQueryOptions::default(),
+        )
+    }
+
+    /// 查询部门的全部下级部门，祖级路径以部门完整路径开头的即为下级部门
+    async fn select_descendants(&self, id: &str) -> Result<Vec<Dept>, RepositoryError> {
+        let Some(dept) = self.select_by_primary_key(id).await? else {
+            return Ok(Vec::new());
+        };
+        let path = dept.path();
+        let mut list: Vec<Dept> = self
+            .read()?
+            .values()
+            .filter(|dept| {
+                dept.deleted_at.is_none()
+                    && dept
+                        .ancestors
+                        .as_deref()
+                        .is_some_and(|ancestors| ancestors.starts_with(&path))
+            })
+            .cloned()
+            .collect();
+        list.sort_by(|a, b| a.id.cmp(&b.id));
+        Ok(list)
+    }
+
+    /// 查询部门的全部上级部门，按祖级路径中的顺序排列
+    async fn select_ancestors(&self, id: &str) -> Result<Vec<Dept>, RepositoryError> {
+        let Some(dept) = self.select_by_primary_key(id).await? else {
+            return Ok(Vec::new());
+        };
+        let depts = self.read()?;
+        Ok(dept
+            .ancestor_ids()
+            .into_iter()
+            .filter_map(|id| {
+                depts
+                    .get(id)
+                    .filter(|dept| dept.deleted_at.is_none())
+                    .cloned()
+            })
+            .collect())
+    }
+
+    /// 按查询选项查询部门列表
+    async fn select_dept_list_with_options(&self, row: &Dept, options: DeptQueryOptions) -> Result<Vec<Dept>, RepositoryError> {
+        self.select_matched(row, options)
+    }
+
+    /// 分页查询部门列表，按 seq_no、id 排序，seq_no为NULL的排在最后，与各SQL实现显式指定的顺序一致
+    async fn select_dept_list_page(&self, row: &Dept, offset: u64, limit: u64) -> Result<(Vec<Dept>, u64), RepositoryError> {
+        let mut list = self.select_matched(row, DeptQueryOptions::default())?;
+        let total = list.len() as u64;
+        list.sort_by_key(|dept| (dept.seq_no.is_none(), dept.seq_no, dept.id.clone()));
+        let list = list
+            .into_iter()
+            .skip(offset as usize)
+            .take(limit as usize)
+            .collect();
+        Ok((list, total))
+    }
+
+    /// 根据主键更新部门的全部字段
+    async fn update_by_primary_key(&self, row: &Dept) -> Result<u64, RepositoryError> {
+        match self.write()?.get_mut(&row.id) {
+            Some(dept) if dept.deleted_at.is_none() => {
+                *dept = row.clone();
+                Ok(1)
+            },
+            _ => Ok(0),
+        }
+    }
+
+    /// 根据主键选择性更新部门，只更新值不为None的字段
+    async fn update_by_primary_key_selective(&self, row: &Dept) -> Result<u64, RepositoryError> {
+        let mut depts = self.write()?;
+        let Some(dept) = depts
+            .get_mut(&row.id)
+            .filter(|dept| dept.deleted_at.is_none())
+        else {
+            return Ok(0);
+        };
+
+        // 与SQL实现一致，没有需要更新的字段时视为未更新任何记录
+        let mut has_field = false;
+        macro_rules! update_fields {
+            ($($field:ident),*) => {
+                $(
+                    if let Some(ref value) = row.$field {
+                        dept.$field = Some(value.clone());
+                        has_field = true;
+                    }
+                )*
+            };
+        }
+        update_fields!(name, email, telephone, address, logo, parent_id, seq_no, status, create_by, create_time, update_by, update_time, remark, ancestors, deleted_at);
+        Ok(has_field as u64)
+    }
+
+    /// 替换下级部门祖级路径的前缀
+    async fn update_ancestors_prefix(&self, old_path: &str, new_path: &str) -> Result<u64, RepositoryError> {
+        let mut rows = 0;
+        for dept in self
+            .write()?
+            .values_mut()
+            .filter(|dept| dept.deleted_at.is_none())
+        {
+            if let Some(suffix) = dept
+                .ancestors
+                .as_deref()
+                .and_then(|ancestors| ancestors.strip_prefix(old_path))
+            {
+                dept.ancestors = Some(format!("{new_path}{suffix}"));
+                rows += 1;
+            }
+        }
+        Ok(rows)
+    }
+
+    /// 开启事务，等待前一个事务结束后复制当前数据
+    async fn begin(&self) -> Result<Box<dyn DeptTransaction>, RepositoryError> {
+        if self.transaction.is_some() {
+            return Err(RepositoryError::Transaction("内存实现不支持嵌套事务".to_string()));
+        }
+
+        let guard = self.transaction_lock.clone().lock_owned().await;
+        let snapshot = self.read()?.clone();
+        Ok(Box::new(Self {
+            depts: Arc::new(RwLock::new(snapshot.clone())),
+            transaction_lock: Arc::default(),
+            transaction: Some(MemoryTransaction { target: self.depts.clone(), snapshot, _guard: guard }),
+        }))
+    }
+}
+
+#[async_trait]
+impl DeptTransaction for DeptRepositoryMemoryImpl {
+    /// 提交事务，只写回事务内新增、修改或删除的部门，事务外对其他部门的修改不会被覆盖
+    async fn commit(self: Box<Self>) -> Result<(), RepositoryError> {
+        let Some(ref transaction) = self.transaction else {
+            return Err(RepositoryError::Transaction("当前仓储未开启事务".to_string()));
+        };
+
+        let depts = self.read()?;
+        let mut target = transaction
+            .target
+            .write()
+            .map_err(|e| RepositoryError::Database(e.to_string().into()))?;
+        for (id, dept) in depts.iter() {
+            if transaction.snapshot.get(id) != Some(dept) {
+                target.insert(id.clone(), dept.clone());
+            }
+        }
+        for id in transaction.snapshot.keys() {
+            if !depts.contains_key(id) {
+                target.remove(id);
+            }
+        }
+        Ok(())
+    }
+
+    /// 回滚事务，直接丢弃事务内的数据副本
+    async fn rollback(self: Box<Self>) -> Result<(), RepositoryError> {
+        if self.transaction.is_none() {
+            return Err(RepositoryError::Transaction("当前仓储未开启事务".to_string()));
+        }
+        Ok(())
+    }
+}
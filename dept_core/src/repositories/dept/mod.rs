@@ -0,0 +1,117 @@
+//! 部门仓储模块
+//!
+//! 该模块定义了部门相关的数据访问接口和实现。
+//! 提供了对部门信息的增删改查等基本操作。
+//!
+//! # 主要组件
+//! - [dept_repository] - 部门数据访问接口定义，参见: [crate::services::dept]
+//! - [diesel_impl] - 基于Diesel ORM的部门数据访问实现（需启用[diesel_impl]特性）
+//! - [memory_impl] - 基于内存的部门数据访问实现，不依赖数据库（需启用[memory_impl]特性）
+//! - [seaorm_impl] - 基于SeaORM的部门数据访问实现（需启用[seaorm_impl]特性）
+//! - [sqlx_impl] - 基于SQLx的部门数据访问实现（需启用[sqlx_impl]特性）
+
+pub mod dept_repository;
+#[cfg(feature = "diesel_impl")]
+pub mod diesel_impl;
+#[cfg(feature = "memory_impl")]
+pub mod memory_impl;
+#[cfg(feature = "seaorm_impl")]
+pub mod seaorm_impl;
+#[cfg(feature = "sqlx_impl")]
+pub mod sqlx_impl;
+
+#[cfg(any(feature = "sqlx_impl", feature = "diesel_impl"))]
+use crate::config::DatabaseType;
+
+/// 构造按前缀匹配的LIKE模式
+///
+/// 前缀中的 `%`、`_` 及转义字符本身都会被转义，语句中需要同时带上 `ESCAPE '!'`，
+/// 用于按祖级路径前缀查询下级部门
+///
+/// # 参数
+/// * `prefix` - 需要匹配的前缀，类型: [&str]
+///
+/// # 返回值
+/// 返回LIKE模式，如 `/a!_b/%`
+#[cfg(any(feature = "sqlx_impl", feature = "diesel_impl", feature = "seaorm_impl"))]
+pub(crate) fn like_prefix_pattern(prefix: &str) -> String {
+    let mut pattern = String::with_capacity(prefix.len() + 1);
+    for c in prefix.chars() {
+        if matches!(c, '%' | '_' | '!') {
+            pattern.push('!');
+        }
+        pattern.push(c);
+    }
+    pattern.push('%');
+    pattern
+}
+
+/// 构造按前缀匹配的GLOB模式（SQLite）
+///
+/// 前缀中的 `*`、`?`、`[` 都会被放入方括号中按字面匹配
+///
+/// # 参数
+/// * `prefix` - 需要匹配的前缀，类型: [&str]
+///
+/// # 返回值
+/// 返回GLOB模式，如 `/a[*]b/*`
+#[cfg(all(feature = "sqlite", any(feature = "sqlx_impl", feature = "diesel_impl", feature = "seaorm_impl")))]
+pub(crate) fn glob_prefix_pattern(prefix: &str) -> String {
+    let mut pattern = String::with_capacity(prefix.len() + 1);
+    for c in prefix.chars() {
+        if matches!(c, '*' | '?' | '[') {
+            pattern.push('[');
+            pattern.push(c);
+            pattern.push(']');
+        } else {
+            pattern.push(c);
+        }
+    }
+    pattern.push('*');
+    pattern
+}
+
+/// 按祖级路径前缀匹配下级部门的SQL条件，由 `head`、绑定的 `pattern`、`tail` 依次拼接而成，供拼接SQL的实现使用
+///
+/// SQLite的LIKE不区分大小写，改用区分大小写的GLOB，祖级路径字段使用默认的BINARY排序规则时同样可以使用索引；
+/// 其他数据库使用LIKE，MySQL的祖级路径字段使用区分大小写的排序规则
+#[cfg(any(feature = "sqlx_impl", feature = "diesel_impl"))]
+pub(crate) struct AncestorsPrefixFilter {
+    /// 绑定参数之前的SQL片段
+    pub(crate) head: &'static str,
+    /// 绑定的匹配模式
+    pub(crate) pattern: String,
+    /// 绑定参数之后的SQL片段
+    pub(crate) tail: &'static str,
+}
+
+#[cfg(any(feature = "sqlx_impl", feature = "diesel_impl"))]
+impl AncestorsPrefixFilter {
+    /// 构造匹配祖级路径以 `prefix` 开头的部门的条件
+    ///
+    /// # 参数
+    /// * `database_type` - 数据库类型，类型: [DatabaseType]
+    /// * `prefix` - 祖级路径前缀，通常为某个部门的完整路径，类型: [&str]
+    pub(crate) fn new(database_type: DatabaseType, prefix: &str) -> Self {
+        match database_type {
+            DatabaseType::MySql => Self::like(prefix),
+            #[cfg(feature = "sqlite")]
+            DatabaseType::Sqlite => Self {
+                head: "ancestors GLOB ",
+                pattern: glob_prefix_pattern(prefix),
+                tail: "",
+            },
+            #[cfg(feature = "postgres")]
+            DatabaseType::Postgres => Self::like(prefix),
+        }
+    }
+
+    /// 使用LIKE匹配前缀的条件
+    fn like(prefix: &str) -> Self {
+        Self {
+            head: "ancestors LIKE ",
+            pattern: like_prefix_pattern(prefix),
+            tail: " ESCAPE '!'",
+        }
+    }
+}
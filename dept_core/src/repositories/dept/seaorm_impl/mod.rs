@@ -0,0 +1,699 @@
+//! SeaORM实现的部门数据访问层
+//!
+//! 该模块提供了基于SeaORM的部门数据访问实现，支持异步数据库操作。
+//! 实现了DeptRepository trait定义的所有方法。
+//!
+//! 连接的数据库由 `database.url` 的协议头决定，启用 `sqlite`、`postgres` 特性后可分别连接SQLite、PostgreSQL。
+//! 仓储按连接类型泛型化，通过 [DeptRepository::begin] 开启的事务即为使用 [DatabaseTransaction] 的同一仓储，
+//! 事务中再次开启事务时SeaORM会创建保存点。
+//! 同时实现了 [MigrationExecutor]，启动时通过同一个连接执行数据库迁移。
+
+use crate::config::{Config, DatabaseType};
+use crate::entities::prelude::SysDept;
+use crate::entities::sys_dept::{ActiveModel, Column, Model};
+use crate::errors::RepositoryError;
+use crate::migrations::{MIGRATIONS_TABLE, MigrationExecutor};
+use crate::models::Dept;
+use crate::repositories::dept::dept_repository::{DeptQueryOptions, DeptRepository, DeptTransaction};
+#[cfg(feature = "sqlite")]
+use crate::repositories::dept::glob_prefix_pattern;
+use crate::repositories::dept::like_prefix_pattern;
+use async_trait::async_trait;
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::sea_query::{Alias, BinOper, Expr, Func, LikeExpr, SimpleExpr};
+use sea_orm::*;
+use std::error::Error as StdError;
+use std::fmt::Debug;
+use std::time::Duration;
+
+/// 实现从Dept模型到SeaORM ActiveModel的转换
+impl From<&Dept> for ActiveModel {
+    fn from(dept: &Dept) -> Self {
+        ActiveModel {
+            id: Set(dept.id.clone()),
+            name: Set(dept.name.clone()),
+            email: Set(dept.email.clone()),
+            telephone: Set(dept.telephone.clone()),
+            address: Set(dept.address.clone()),
+            logo: Set(dept.logo.clone()),
+            parent_id: Set(dept.parent_id.clone()),
+            seq_no: Set(dept.seq_no),
+            status: Set(dept.status),
+            create_by: Set(dept.create_by.clone()),
+            create_time: Set(dept.create_time),
+            update_by: Set(dept.update_by.clone()),
+            update_time: Set(dept.update_time),
+            remark: Set(dept.remark.clone()),
+            ancestors: Set(dept.ancestors.clone()),
+            deleted_at: Set(dept.deleted_at),
+        }
+    }
+}
+
+/// 实现从SeaORM Model到Dept模型的转换
+impl From<Model> for Dept {
+    fn from(model: Model) -> Self {
+        Dept {
+            id: model.id,
+            parent_id: model.parent_id,
+            name: model.name,
+            email: model.email,
+            telephone: model.telephone,
+            address: model.address,
+            logo: model.logo,
+            seq_no: model.seq_no,
+            status: model.status,
+            create_by: model.create_by,
+            create_time: model.create_time.map(|dt| dt.and_utc().naive_utc()),
+            update_by: model.update_by,
+            update_time: model.update_time.map(|dt| dt.and_utc().naive_utc()),
+            remark: model.remark,
+            ancestors: model.ancestors,
+            deleted_at: model.deleted_at,
+        }
+    }
+}
+
+/// SeaORM实现的部门仓储
+///
+/// `C` 为执行语句使用的连接，默认为数据库连接，事务中的仓储为 [DatabaseTransaction]
+#[derive(Debug)]
+pub struct DeptRepositorySeaormImpl<C = DatabaseConnection> {
+    connection: C,
+    database_type: DatabaseType,
+}
+
+impl DeptRepositorySeaormImpl {
+    /// 创建新的SeaORM部门仓储实例
+    ///
+    /// # 返回值
+    /// 返回新的部门仓储实例
+    pub async fn new() -> Result<Self, Box<dyn StdError + Send + Sync>> {
+        // 从配置文件中读取数据库URL和连接池配置
+        let config = Config::from_default_file().expect("无法加载配置文件");
+        let database_url = &config.database.url;
+
+        // 构建连接选项
+        let mut opt = ConnectOptions::new(database_url.to_owned());
+
+        // 只在使用 SeaORM 时才应用连接池配置
+        #[cfg(feature = "seaorm_impl")]
+        {
+            // 设置最大连接数
+            if let Some(max_connections) = config.database.seaorm.max_connections {
+                opt.max_connections(max_connections);
+            }
+
+            // 设置最小连接数
+            if let Some(min_connections) = config.database.seaorm.min_connections {
+                opt.min_connections(min_connections);
+            }
+
+            // 设置连接超时时间
+            if let Some(connect_timeout) = config.database.seaorm.connect_timeout {
+                opt.connect_timeout(Duration::from_secs(connect_timeout));
+            }
+
+            // 设置获取连接的超时时间
+            if let Some(acquire_timeout) = config.database.seaorm.acquire_timeout {
+                opt.acquire_timeout(Duration::from_secs(acquire_timeout));
+            }
+
+            // 设置空闲连接超时时间
+            if let Some(idle_timeout) = config.database.seaorm.idle_timeout {
+                opt.idle_timeout(Duration::from_secs(idle_timeout));
+            }
+
+            // 设置连接最大存活时间
+            if let Some(max_lifetime) = config.database.seaorm.max_lifetime {
+                opt.max_lifetime(Duration::from_secs(max_lifetime));
+            }
+        }
+
+        // 校验协议头，未启用对应数据库特性时给出明确的错误信息
+        let database_type = config.database.database_type()?;
+        let connection = Self::connect(&config, database_type, opt).await?;
+
+        // 执行初始化脚本
+        if let Some(script) = config.database.read_init_script()? {
+            connection
+                .execute_unprepared(&script)
+                .await
+                .map_err(|e| Box::new(e) as Box<dyn StdError + Send + Sync>)?;
+        }
+
+        Ok(Self { connection, database_type })
+    }
+
+    /// 建立数据库连接
+    ///
+    /// SQLite内存库随连接关闭而销毁，而ConnectOptions无法关闭连接的空闲回收，
+    /// 因此直接创建一个只有单个常驻连接的SQLx连接池再交给SeaORM使用。
+    #[cfg_attr(not(feature = "sqlite"), allow(unused_variables))]
+    async fn connect(config: &Config, database_type: DatabaseType, opt: ConnectOptions) -> Result<DatabaseConnection, Box<dyn StdError + Send + Sync>> {
+        match database_type {
+            DatabaseType::MySql => {},
+            #[cfg(feature = "postgres")]
+            DatabaseType::Postgres => {},
+            #[cfg(feature = "sqlite")]
+            DatabaseType::Sqlite => {
+                if config.database.is_sqlite_memory() {
+                    let pool = sqlx::sqlite::SqlitePoolOptions::new()
+                        .max_connections(1)
+                        .min_connections(1)
+                        .idle_timeout(None)
+                        .max_lifetime(None)
+                        .connect(&config.database.url)
+                        .await
+                        .map_err(|e| Box::new(e) as Box<dyn StdError + Send + Sync>)?;
+                    return Ok(SqlxSqliteConnector::from_sqlx_sqlite_pool(pool));
+                }
+            },
+        }
+
+        Database::connect(opt)
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn StdError + Send + Sync>)
+    }
+}
+
+impl<C> DeptRepositorySeaormImpl<C> {
+    /// 构建查询部门列表的过滤条件，分页查询与计数查询共用同一组条件
+    fn build_select_dept_list_condition(row: &Dept, options: DeptQueryOptions) -> Condition {
+        let mut condition = Condition::all();
+
+        // 默认排除已删除的部门
+        if !options.include_deleted {
+            condition = condition.add(Column::DeletedAt.is_null());
+        }
+
+        // 添加所有可能的查询条件
+        if !row.id.is_empty() {
+            condition = condition.add(Column::Id.eq(row.id.clone()));
+        }
+
+        if let Some(ref name) = row.name {
+            condition = condition.add(Column::Name.like(format!("%{}%", name)));
+        }
+
+        if let Some(ref email) = row.email {
+            condition = condition.add(Column::Email.eq(email.clone()));
+        }
+
+        if let Some(ref telephone) = row.telephone {
+            condition = condition.add(Column::Telephone.eq(telephone.clone()));
+        }
+
+        if let Some(ref address) = row.address {
+            condition = condition.add(Column::Address.eq(address.clone()));
+        }
+
+        if let Some(ref logo) = row.logo {
+            condition = condition.add(Column::Logo.eq(logo.clone()));
+        }
+
+        if let Some(ref parent_id) = row.parent_id {
+            condition = condition.add(Column::ParentId.eq(parent_id.clone()));
+        }
+
+        if let Some(seq_no) = row.seq_no {
+            condition = condition.add(Column::SeqNo.eq(seq_no));
+        }
+
+        if let Some(status) = row.status {
+            condition = condition.add(Column::Status.eq(status));
+        }
+
+        if let Some(ref create_by) = row.create_by {
+            condition = condition.add(Column::CreateBy.eq(create_by.clone()));
+        }
+
+        if let Some(create_time) = row.create_time {
+            condition = condition.add(Column::CreateTime.eq(create_time));
+        }
+
+        if let Some(ref update_by) = row.update_by {
+            condition = condition.add(Column::UpdateBy.eq(update_by.clone()));
+        }
+
+        if let Some(update_time) = row.update_time {
+            condition = condition.add(Column::UpdateTime.eq(update_time));
+        }
+
+        if let Some(ref remark) = row.remark {
+            condition = condition.add(Column::Remark.eq(remark.clone()));
+        }
+
+        if let Some(ref ancestors) = row.ancestors {
+            condition = condition.add(Column::Ancestors.eq(ancestors.clone()));
+        }
+
+        if let Some(deleted_at) = row.deleted_at {
+            condition = condition.add(Column::DeletedAt.eq(deleted_at));
+        }
+
+        condition
+    }
+
+    /// 构建祖级路径以 `prefix` 开头的过滤条件
+    ///
+    /// SQLite的LIKE不区分大小写，改用区分大小写的GLOB，其他数据库使用LIKE
+    fn ancestors_prefix_condition(&self, prefix: &str) -> SimpleExpr {
+        match self.database_type {
+            DatabaseType::MySql => Column::Ancestors.like(LikeExpr::new(like_prefix_pattern(prefix)).escape('!')),
+            #[cfg(feature = "sqlite")]
+            DatabaseType::Sqlite => Expr::col(Column::Ancestors).binary(BinOper::Custom("GLOB"), glob_prefix_pattern(prefix)),
+            #[cfg(feature = "postgres")]
+            DatabaseType::Postgres => Column::Ancestors.like(LikeExpr::new(like_prefix_pattern(prefix)).escape('!')),
+        }
+    }
+}
+
+#[async_trait]
+impl<C> DeptRepository for DeptRepositorySeaormImpl<C>
+where
+    C: ConnectionTrait + TransactionTrait + Debug + Send + Sync,
+{
+    /// 根据主键逻辑删除部门，记录删除时间
+    async fn delete_by_primary_key(&self, id: &str) -> Result<(), RepositoryError> {
+        SysDept::update_many()
+            .col_expr(Column::DeletedAt, Expr::value(Utc::now().naive_utc()))
+            .filter(Column::Id.eq(id))
+            .filter(Column::DeletedAt.is_null())
+            .exec(&self.connection)
+            .await
+            .map_err(RepositoryError::from)?;
+        Ok(())
+    }
+
+    /// 根据主键恢复已删除的部门
+    async fn restore_by_primary_key(&self, id: &str) -> Result<u64, RepositoryError> {
+        let result = SysDept::update_many()
+            .col_expr(Column::DeletedAt, Expr::value(Option::<NaiveDateTime>::None))
+            .filter(Column::Id.eq(id))
+            .filter(Column::DeletedAt.is_not_null())
+            .exec(&self.connection)
+            .await
+            .map_err(RepositoryError::from)?;
+        Ok(result.rows_affected)
+    }
+
+    /// 根据主键彻底删除已删除的部门
+    async fn purge_by_primary_key(&self, id: &str) -> Result<u64, RepositoryError> {
+        let result = SysDept::delete_many()
+            .filter(Column::Id.eq(id))
+            .filter(Column::DeletedAt.is_not_null())
+            .exec(&self.connection)
+            .await
+            .map_err(RepositoryError::from)?;
+        Ok(result.rows_affected)
+    }
+
+    /// 插入部门记录
+    async fn insert(&self, row: &Dept) -> Result<(), RepositoryError> {
+        let active_model: ActiveModel = row.into();
+        active_model
+            .insert(&self.connection)
+            .await
+            .map_err(RepositoryError::from)?;
+        Ok(())
+    }
+
+    /// 选择性插入部门记录
+    async fn insert_selective(&self, row: &Dept) -> Result<(), RepositoryError> {
+        // 转换为ActiveModel
+        let mut active_model = ActiveModel { ..Default::default() };
+
+        // id字段在Dept模型中是String类型，不是Option
+        active_model.id = sea_orm::ActiveValue::Set(row.id.clone());
+
+        // 只有 Some 值的字段才插入
+        if let Some(ref name) = row.name {
+            active_model.name = sea_orm::ActiveValue::Set(Some(name.clone()));
+        }
+
+        if let Some(ref email) = row.email {
+            active_model.email = sea_orm::ActiveValue::Set(Some(email.clone()));
+        }
+
+        if let Some(ref telephone) = row.telephone {
+            active_model.telephone = sea_orm::ActiveValue::Set(Some(telephone.clone()));
+        }
+
+        if let Some(ref address) = row.address {
+            active_model.address = sea_orm::ActiveValue::Set(Some(address.clone()));
+        }
+
+        if let Some(ref logo) = row.logo {
+            active_model.logo = sea_orm::ActiveValue::Set(Some(logo.clone()));
+        }
+
+        if let Some(ref parent_id) = row.parent_id {
+            active_model.parent_id = sea_orm::ActiveValue::Set(Some(parent_id.clone()));
+        }
+
+        if let Some(ref seq_no) = row.seq_no {
+            active_model.seq_no = sea_orm::ActiveValue::Set(Some(*seq_no));
+        }
+
+        if let Some(ref status) = row.status {
+            active_model.status = sea_orm::ActiveValue::Set(Some(*status));
+        }
+
+        if let Some(ref create_by) = row.create_by {
+            active_model.create_by = sea_orm::ActiveValue::Set(Some(create_by.clone()));
+        }
+
+        if let Some(ref create_time) = row.create_time {
+            active_model.create_time = sea_orm::ActiveValue::Set(Some(*create_time));
+        }
+
+        if let Some(ref update_by) = row.update_by {
+            active_model.update_by = sea_orm::ActiveValue::Set(Some(update_by.clone()));
+        }
+
+        if let Some(ref update_time) = row.update_time {
+            active_model.update_time = sea_orm::ActiveValue::Set(Some(*update_time));
+        }
+
+        if let Some(ref remark) = row.remark {
+            active_model.remark = sea_orm::ActiveValue::Set(Some(remark.clone()));
+        }
+
+        if let Some(ref ancestors) = row.ancestors {
+            active_model.ancestors = sea_orm::ActiveValue::Set(Some(ancestors.clone()));
+        }
+
+        if let Some(ref deleted_at) = row.deleted_at {
+            active_model.deleted_at = sea_orm::ActiveValue::Set(Some(*deleted_at));
+        }
+
+        active_model
+            .save(&self.connection)
+            .await
+            .map_err(RepositoryError::from)?;
+        Ok(())
+    }
+
+    /// 根据主键查询部门
+    async fn select_by_primary_key(&self, id: &str) -> Result<Option<Dept>, RepositoryError> {
+        let model = SysDept::find_by_id(id)
+            .filter(Column::DeletedAt.is_null())
+            .one(&self.connection)
+            .await
+            .map_err(RepositoryError::from)?;
+        Ok(model.map(|m| m.into()))
+    }
+
+    /// 根据主键查询并锁定部门，SQLite不支持行锁，生成的语句不带锁定子句
+    async fn select_by_primary_key_for_update(&self, id: &str) -> Result<Option<Dept>, RepositoryError> {
+        let model = SysDept::find_by_id(id)
+            .filter(Column::DeletedAt.is_null())
+            .lock_exclusive()
+            .one(&self.connection)
+            .await
+            .map_err(RepositoryError::from)?;
+        Ok(model.map(|m| m.into()))
+    }
+
+    /// 根据父部门ID查询部门列表
+    async fn select_dept_by_parent_id(&self, parent_id: &str) -> Result<Vec<Dept>, RepositoryError> {
+        // 非基础crud方法使用原生sql,方便优化性能
+        //    let sql = r#"
+        //        SELECT id, parent_id, name, email, telephone, address, logo, seq_no, status,
+        //               create_by, create_time, update_by, update_time, remark
+        //        FROM sys_dept
+        //        WHERE deleted_at IS NULL AND parent_id = ?
+        //        ORDER BY seq_no
+        //    "#;
+
+        // 使用原生SQL查询以优化性能
+        use crate::models::constants::DEPT_FIELDS;
+        // 使用DEPT_FIELDS常量构建SQL查询
+        let sql = self
+            .database_type
+            .adapt_placeholders(&format!("SELECT {DEPT_FIELDS} FROM sys_dept WHERE deleted_at IS NULL AND parent_id = ?"));
+        let stmt = Statement::from_sql_and_values(self.connection.get_database_backend(), &sql, vec![parent_id.into()]);
+        let models = SysDept::find()
+            .from_raw_sql(stmt)
+            .all(&self.connection)
+            .await
+            .map_err(RepositoryError::from)?;
+        Ok(models.into_iter().map(|m| m.into()).collect())
+    }
+
+    /// 查询部门的全部下级部门，按祖级路径前缀匹配
+    async fn select_descendants(&self, id: &str) -> Result<Vec<Dept>, RepositoryError> {
+        let Some(dept) = self.select_by_primary_key(id).await? else {
+            return Ok(Vec::new());
+        };
+        let models = SysDept::find()
+            .filter(Column::DeletedAt.is_null())
+            .filter(self.ancestors_prefix_condition(&dept.path()))
+            .order_by_asc(Column::Id)
+            .all(&self.connection)
+            .await
+            .map_err(RepositoryError::from)?;
+        Ok(models.into_iter().map(|m| m.into()).collect())
+    }
+
+    /// 查询部门的全部上级部门，按祖级路径中的顺序排列
+    async fn select_ancestors(&self, id: &str) -> Result<Vec<Dept>, RepositoryError> {
+        let Some(dept) = self.select_by_primary_key(id).await? else {
+            return Ok(Vec::new());
+        };
+        let ids = dept.ancestor_ids();
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let models = SysDept::find()
+            .filter(Column::DeletedAt.is_null())
+            .filter(Column::Id.is_in(ids.iter().copied()))
+            .all(&self.connection)
+            .await
+            .map_err(RepositoryError::from)?;
+        let mut list: Vec<Dept> = models.into_iter().map(|m| m.into()).collect();
+        list.sort_by_key(|dept| ids.iter().position(|id| *id == dept.id));
+        Ok(list)
+    }
+
+    /// 按查询选项查询部门列表
+    async fn select_dept_list_with_options(&self, row: &Dept, options: DeptQueryOptions) -> Result<Vec<Dept>, RepositoryError> {
+        let models = SysDept::find()
+            .filter(Self::build_select_dept_list_condition(row, options))
+            .all(&self.connection)
+            .await
+            .map_err(RepositoryError::from)?;
+        Ok(models.into_iter().map(|m| m.into()).collect())
+    }
+
+    /// 分页查询部门列表
+    async fn select_dept_list_page(&self, row: &Dept, offset: u64, limit: u64) -> Result<(Vec<Dept>, u64), RepositoryError> {
+        let select = SysDept::find().filter(Self::build_select_dept_list_condition(row, DeptQueryOptions::default()));
+
+        // 先统计满足条件的总记录数
+        let total = select
+            .clone()
+            .count(&self.connection)
+            .await
+            .map_err(RepositoryError::from)?;
+
+        // 没有满足条件的记录时无需再查询当前页
+        if total == 0 {
+            return Ok((Vec::new(), 0));
+        }
+
+        // 各数据库对NULL的默认排序不同（MySQL、SQLite升序时排在最前），显式把未设置排序号的排在最后，与部门树的显示顺序一致
+        let models = select
+            .order_by_asc(Expr::col(Column::SeqNo).is_null())
+            .order_by_asc(Column::SeqNo)
+            .order_by_asc(Column::Id)
+            .offset(offset)
+            .limit(limit)
+            .all(&self.connection)
+            .await
+            .map_err(RepositoryError::from)?;
+        Ok((models.into_iter().map(|m| m.into()).collect(), total))
+    }
+
+    /// 根据主键更新部门
+    async fn update_by_primary_key(&self, row: &Dept) -> Result<u64, RepositoryError> {
+        //转换为ActiveModel
+        let mut active_model: ActiveModel = row.into();
+        //由于ID是主键,所以不需要设置
+        active_model.id = sea_orm::ActiveValue::Unchanged(row.id.clone());
+        // 执行更新
+        let result: UpdateResult = SysDept::update_many()
+            .set(active_model)
+            .filter(<SysDept as sea_orm::EntityTrait>::Column::Id.eq(row.id.clone()))
+            .filter(Column::DeletedAt.is_null())
+            .exec(&self.connection)
+            .await
+            .map_err(RepositoryError::from)?;
+        Ok(result.rows_affected)
+    }
+
+    /// 根据主键选择性更新部门
+    async fn update_by_primary_key_selective(&self, row: &Dept) -> Result<u64, RepositoryError> {
+        //转换为ActiveModel
+        let mut active_model = ActiveModel {
+            id: sea_orm::ActiveValue::Unchanged(row.id.clone()), // 主键必须设置
+            ..Default::default()
+        };
+
+        // 只有 Some 值的字段才更新
+        if let Some(ref name) = row.name {
+            active_model.name = sea_orm::ActiveValue::Set(Some(name.clone()));
+        }
+
+        if let Some(ref email) = row.email {
+            active_model.email = sea_orm::ActiveValue::Set(Some(email.clone()));
+        }
+
+        if let Some(ref telephone) = row.telephone {
+            active_model.telephone = sea_orm::ActiveValue::Set(Some(telephone.clone()));
+        }
+
+        if let Some(ref address) = row.address {
+            active_model.address = sea_orm::ActiveValue::Set(Some(address.clone()));
+        }
+
+        if let Some(ref logo) = row.logo {
+            active_model.logo = sea_orm::ActiveValue::Set(Some(logo.clone()));
+        }
+
+        if let Some(ref parent_id) = row.parent_id {
+            active_model.parent_id = sea_orm::ActiveValue::Set(Some(parent_id.clone()));
+        }
+
+        if let Some(ref seq_no) = row.seq_no {
+            active_model.seq_no = sea_orm::ActiveValue::Set(Some(*seq_no));
+        }
+
+        if let Some(ref status) = row.status {
+            active_model.status = sea_orm::ActiveValue::Set(Some(*status));
+        }
+
+        if let Some(ref create_by) = row.create_by {
+            active_model.create_by = sea_orm::ActiveValue::Set(Some(create_by.clone()));
+        }
+
+        if let Some(ref create_time) = row.create_time {
+            active_model.create_time = sea_orm::ActiveValue::Set(Some(*create_time));
+        }
+
+        if let Some(ref update_by) = row.update_by {
+            active_model.update_by = sea_orm::ActiveValue::Set(Some(update_by.clone()));
+        }
+
+        if let Some(ref update_time) = row.update_time {
+            active_model.update_time = sea_orm::ActiveValue::Set(Some(*update_time));
+        }
+
+        if let Some(ref remark) = row.remark {
+            active_model.remark = sea_orm::ActiveValue::Set(Some(remark.clone()));
+        }
+
+        if let Some(ref ancestors) = row.ancestors {
+            active_model.ancestors = sea_orm::ActiveValue::Set(Some(ancestors.clone()));
+        }
+
+        if let Some(ref deleted_at) = row.deleted_at {
+            active_model.deleted_at = sea_orm::ActiveValue::Set(Some(*deleted_at));
+        }
+
+        // 执行更新
+        let result: UpdateResult = SysDept::update_many()
+            .set(active_model)
+            .filter(<SysDept as sea_orm::EntityTrait>::Column::Id.eq(row.id.clone()))
+            .filter(Column::DeletedAt.is_null())
+            .exec(&self.connection)
+            .await
+            .map_err(RepositoryError::from)?;
+        Ok(result.rows_affected)
+    }
+
+    /// 替换下级部门祖级路径的前缀，MySQL使用CONCAT拼接，其他数据库使用 `||`
+    async fn update_ancestors_prefix(&self, old_path: &str, new_path: &str) -> Result<u64, RepositoryError> {
+        // SUBSTR按字符计数，从1开始
+        let start = old_path.chars().count() as i32 + 1;
+        let suffix = Func::cust(Alias::new("SUBSTR"))
+            .arg(Expr::col(Column::Ancestors))
+            .arg(start);
+        let ancestors: SimpleExpr = if self.database_type == DatabaseType::MySql {
+            Func::cust(Alias::new("CONCAT"))
+                .arg(new_path)
+                .arg(suffix)
+                .into()
+        } else {
+            Expr::val(new_path).binary(BinOper::Custom("||"), suffix)
+        };
+        let result = SysDept::update_many()
+            .col_expr(Column::Ancestors, ancestors)
+            .filter(Column::DeletedAt.is_null())
+            .filter(self.ancestors_prefix_condition(old_path))
+            .exec(&self.connection)
+            .await
+            .map_err(RepositoryError::from)?;
+        Ok(result.rows_affected)
+    }
+
+    /// 开启事务，事务中的仓储与当前仓储共用连接池；在事务中调用时由SeaORM创建保存点，作为嵌套事务使用
+    async fn begin(&self) -> Result<Box<dyn DeptTransaction>, RepositoryError> {
+        let connection = self
+            .connection
+            .begin()
+            .await
+            .map_err(RepositoryError::from)?;
+        Ok(Box::new(DeptRepositorySeaormImpl { connection, database_type: self.database_type }))
+    }
+}
+
+#[async_trait]
+impl DeptTransaction for DeptRepositorySeaormImpl<DatabaseTransaction> {
+    /// 提交事务
+    async fn commit(self: Box<Self>) -> Result<(), RepositoryError> {
+        self.connection
+            .commit()
+            .await
+            .map_err(RepositoryError::from)
+    }
+
+    /// 回滚事务，未提交的事务被丢弃时SeaORM也会自动回滚
+    async fn rollback(self: Box<Self>) -> Result<(), RepositoryError> {
+        self.connection
+            .rollback()
+            .await
+            .map_err(RepositoryError::from)
+    }
+}
+
+#[async_trait]
+impl MigrationExecutor for DeptRepositorySeaormImpl {
+    fn database_type(&self) -> DatabaseType {
+        self.database_type
+    }
+
+    /// 执行迁移脚本
+    async fn execute_script(&self, script: &str) -> Result<(), Box<dyn StdError + Send + Sync>> {
+        self.connection
+            .execute_unprepared(script)
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn StdError + Send + Sync>)?;
+        Ok(())
+    }
+
+    /// 查询已应用的迁移版本
+    async fn select_applied_versions(&self) -> Result<Vec<i64>, Box<dyn StdError + Send + Sync>> {
+        let stmt = Statement::from_string(self.connection.get_database_backend(), format!("SELECT version FROM {MIGRATIONS_TABLE} ORDER BY version"));
+        let rows = self
+            .connection
+            .query_all(stmt)
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn StdError + Send + Sync>)?;
+        rows.iter()
+            .map(|row| row.try_get::<i64>("", "version"))
+            .collect::<Result<Vec<i64>, DbErr>>()
+            .map_err(|e| Box::new(e) as Box<dyn StdError + Send + Sync>)
+    }
+}
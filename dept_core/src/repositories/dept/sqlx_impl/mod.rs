@@ -0,0 +1,1020 @@
+//! SQLx实现的部门数据访问层
+//!
+//! 该模块提供了基于SQLx的部门数据访问实现，支持异步数据库操作。
+//! 实现了DeptRepository trait定义的所有方法。
+//!
+//! 连接的数据库由配置项 `database.url` 的协议头决定，默认支持MySQL，
+//! 启用 `sqlite` 特性后还可以连接SQLite文件库或内存库，启用 `postgres` 特性后可以连接PostgreSQL。
+//! 所有参数都通过QueryBuilder绑定，占位符由SQLx按数据库方言生成。
+//! 通过 [DeptRepository::begin] 开启的事务同样由该结构体实现，语句改为在事务独占的连接上执行。
+//! 同时实现了 [MigrationExecutor]，启动时通过同一个连接池执行数据库迁移。
+
+use crate::config::{Config, DatabaseType};
+use crate::errors::RepositoryError;
+use crate::migrations::{MIGRATIONS_TABLE, MigrationExecutor};
+use crate::models::Dept;
+use crate::models::constants::DEPT_FIELDS;
+use crate::repositories::dept::AncestorsPrefixFilter;
+use crate::repositories::dept::dept_repository::{DeptQueryOptions, DeptRepository, DeptTransaction};
+use async_trait::async_trait;
+use chrono::{NaiveDateTime, Utc};
+use sqlx::mysql::{MySql, MySqlPool};
+use sqlx::pool::PoolOptions;
+#[cfg(feature = "postgres")]
+use sqlx::postgres::{PgPool, Postgres};
+#[cfg(feature = "sqlite")]
+use sqlx::sqlite::{Sqlite, SqlitePool};
+use sqlx::{Database, Encode, QueryBuilder, Transaction, Type};
+use std::error::Error as StdError;
+use std::fmt::Debug;
+use std::time::Duration;
+use tokio::sync::Mutex;
+
+/// 按数据库类型区分的连接池
+#[derive(Debug, Clone)]
+enum DbPool {
+    /// MySQL连接池
+    MySql(MySqlPool),
+    /// SQLite连接池
+    #[cfg(feature = "sqlite")]
+    Sqlite(SqlitePool),
+    /// PostgreSQL连接池
+    #[cfg(feature = "postgres")]
+    Postgres(PgPool),
+}
+
+/// 按连接池类型分发执行同一段查询代码
+///
+/// SQLx的查询构建、参数绑定和结果解码都与具体的数据库类型绑定，
+/// 该宏为每种数据库各展开一个分支，分支内的 `$pool` 即为对应类型的连接池，
+/// 各分支的返回值类型必须一致。
+macro_rules! with_pool {
+    ($db_pool:expr, |$pool:ident| $body:expr) => {
+        match $db_pool {
+            DbPool::MySql($pool) => $body,
+            #[cfg(feature = "sqlite")]
+            DbPool::Sqlite($pool) => $body,
+            #[cfg(feature = "postgres")]
+            DbPool::Postgres($pool) => $body,
+        }
+    };
+}
+
+/// 按数据库类型区分的事务
+#[derive(Debug)]
+enum DbTransaction {
+    /// MySQL事务
+    MySql(Transaction<'static, MySql>),
+    /// SQLite事务
+    #[cfg(feature = "sqlite")]
+    Sqlite(Transaction<'static, Sqlite>),
+    /// PostgreSQL事务
+    #[cfg(feature = "postgres")]
+    Postgres(Transaction<'static, Postgres>),
+}
+
+impl DbTransaction {
+    /// 提交或回滚事务
+    async fn finish(self, commit: bool) -> Result<(), sqlx::Error> {
+        match (self, commit) {
+            (Self::MySql(tx), true) => tx.commit().await,
+            (Self::MySql(tx), false) => tx.rollback().await,
+            #[cfg(feature = "sqlite")]
+            (Self::Sqlite(tx), true) => tx.commit().await,
+            #[cfg(feature = "sqlite")]
+            (Self::Sqlite(tx), false) => tx.rollback().await,
+            #[cfg(feature = "postgres")]
+            (Self::Postgres(tx), true) => tx.commit().await,
+            #[cfg(feature = "postgres")]
+            (Self::Postgres(tx), false) => tx.rollback().await,
+        }
+    }
+}
+
+/// 按仓储当前使用的连接分发执行同一段查询代码
+///
+/// 事务中的仓储在事务连接上执行，否则与 `with_pool!` 一样直接使用连接池，
+/// 分支内的 `$conn` 为对应数据库类型的执行器，每个分支中只能使用一次。
+macro_rules! with_conn {
+    ($repository:expr, |$conn:ident| $body:expr) => {
+        match &$repository.transaction {
+            Some(transaction) => {
+                let mut transaction = transaction.lock().await;
+                match &mut *transaction {
+                    DbTransaction::MySql($conn) => {
+                        let $conn = &mut **$conn;
+                        $body
+                    },
+                    #[cfg(feature = "sqlite")]
+                    DbTransaction::Sqlite($conn) => {
+                        let $conn = &mut **$conn;
+                        $body
+                    },
+                    #[cfg(feature = "postgres")]
+                    DbTransaction::Postgres($conn) => {
+                        let $conn = &mut **$conn;
+                        $body
+                    },
+                }
+            },
+            None => with_pool!(&$repository.pool, |$conn| $body),
+        }
+    };
+}
+
+/// SQLx实现的部门仓储
+#[derive(Debug)]
+pub struct DeptRepositorySqlxImpl {
+    pool: DbPool,
+    /// 事务中的仓储所持有的事务，为None时直接使用连接池，每条语句自动提交
+    transaction: Option<Mutex<DbTransaction>>,
+}
+
+impl DeptRepositorySqlxImpl {
+    /// 创建新的SQLx部门仓储实例
+    ///
+    /// 根据数据库URL的协议头创建对应类型的连接池，配置了初始化脚本时在连接后立即执行。
+    ///
+    /// # 返回值
+    /// 返回新的部门仓储实例
+    pub async fn new() -> Result<Self, Box<dyn StdError + Send + Sync>> {
+        // 从配置文件中读取数据库URL
+        let config = Config::from_default_file().expect("无法加载配置文件");
+        let database_url = &config.database.url;
+
+        let pool = match config.database.database_type()? {
+            DatabaseType::MySql => {
+                let pool = Self::build_pool_options(&config)
+                    .connect(database_url)
+                    .await
+                    .map_err(|e| Box::new(e) as Box<dyn StdError + Send + Sync>)?;
+                DbPool::MySql(pool)
+            },
+            #[cfg(feature = "sqlite")]
+            DatabaseType::Sqlite => {
+                let mut pool_options = Self::build_pool_options(&config);
+
+                // 内存库随连接关闭而销毁，只保留一个常驻连接
+                if config.database.is_sqlite_memory() {
+                    pool_options = pool_options
+                        .max_connections(1)
+                        .min_connections(1)
+                        .idle_timeout(None)
+                        .max_lifetime(None);
+                }
+
+                let pool = pool_options
+                    .connect(database_url)
+                    .await
+                    .map_err(|e| Box::new(e) as Box<dyn StdError + Send + Sync>)?;
+                DbPool::Sqlite(pool)
+            },
+            #[cfg(feature = "postgres")]
+            DatabaseType::Postgres => {
+                let pool = Self::build_pool_options(&config)
+                    .connect(database_url)
+                    .await
+                    .map_err(|e| Box::new(e) as Box<dyn StdError + Send + Sync>)?;
+                DbPool::Postgres(pool)
+            },
+        };
+
+        // 执行初始化脚本
+        if let Some(script) = config.database.read_init_script()? {
+            with_pool!(&pool, |pool| sqlx::raw_sql(&script).execute(pool).await.map(|_| ())).map_err(|e| Box::new(e) as Box<dyn StdError + Send + Sync>)?;
+        }
+
+        Ok(Self { pool, transaction: None })
+    }
+
+    /// 根据配置构建连接池选项
+    fn build_pool_options<DB: Database>(config: &Config) -> PoolOptions<DB> {
+        // 构建连接池配置
+        let mut pool_options = PoolOptions::<DB>::new();
+
+        // 只在使用 SQLx 时才应用连接池配置
+        #[cfg(feature = "sqlx_impl")]
+        {
+            // 设置最大连接数
+            if let Some(max_connections) = config.database.sqlx.max_connections {
+                pool_options = pool_options.max_connections(max_connections);
+            }
+
+            // 设置最小连接数
+            if let Some(min_connections) = config.database.sqlx.min_connections {
+                pool_options = pool_options.min_connections(min_connections);
+            }
+
+            // 设置获取连接的超时时间
+            if let Some(acquire_timeout) = config.database.sqlx.acquire_timeout {
+                pool_options = pool_options.acquire_timeout(Duration::from_secs(acquire_timeout));
+            }
+
+            // 设置空闲连接超时时间
+            if let Some(idle_timeout) = config.database.sqlx.idle_timeout {
+                pool_options = pool_options.idle_timeout(Duration::from_secs(idle_timeout));
+            }
+
+            // 设置连接最大存活时间
+            if let Some(max_lifetime) = config.database.sqlx.max_lifetime {
+                pool_options = pool_options.max_lifetime(Duration::from_secs(max_lifetime));
+            }
+        }
+
+        pool_options
+    }
+
+    /// 使用QueryBuilder构建查询部门列表的查询
+    fn build_select_dept_list_query_with_builder<'a, DB>(&'a self, query: &mut QueryBuilder<'a, DB>, row: &'a Dept, options: DeptQueryOptions)
+    where
+        DB: Database,
+        String: Encode<'a, DB> + Type<DB>,
+        i32: Encode<'a, DB> + Type<DB>,
+        NaiveDateTime: Encode<'a, DB> + Type<DB>,
+    {
+        query.push(format!("SELECT {DEPT_FIELDS} FROM sys_dept WHERE 1=1"));
+        self.push_select_dept_list_conditions(query, row, options);
+    }
+
+    /// 使用QueryBuilder构建统计部门数量的查询，条件与部门列表查询一致
+    fn build_count_dept_list_query_with_builder<'a, DB>(&'a self, query: &mut QueryBuilder<'a, DB>, row: &'a Dept, options: DeptQueryOptions)
+    where
+        DB: Database,
+        String: Encode<'a, DB> + Type<DB>,
+        i32: Encode<'a, DB> + Type<DB>,
+        NaiveDateTime: Encode<'a, DB> + Type<DB>,
+    {
+        query.push("SELECT COUNT(*) FROM sys_dept WHERE 1=1");
+        self.push_select_dept_list_conditions(query, row, options);
+    }
+
+    /// 追加部门列表查询条件
+    fn push_select_dept_list_conditions<'a, DB>(&'a self, query: &mut QueryBuilder<'a, DB>, row: &'a Dept, options: DeptQueryOptions)
+    where
+        DB: Database,
+        String: Encode<'a, DB> + Type<DB>,
+        i32: Encode<'a, DB> + Type<DB>,
+        NaiveDateTime: Encode<'a, DB> + Type<DB>,
+    {
+        // 默认排除已删除的部门
+        if !options.include_deleted {
+            query.push(" AND deleted_at IS NULL");
+        }
+
+        // 添加所有可能的查询条件
+        if !row.id.is_empty() {
+            query.push(" AND id = ");
+            query.push_bind(&row.id);
+        }
+
+        if let Some(ref name) = row.name {
+            query.push(" AND name LIKE ");
+            query.push_bind(format!("%{}%", name));
+        }
+
+        if let Some(ref email) = row.email {
+            query.push(" AND email = ");
+            query.push_bind(email);
+        }
+
+        if let Some(ref telephone) = row.telephone {
+            query.push(" AND telephone = ");
+            query.push_bind(telephone);
+        }
+
+        if let Some(ref address) = row.address {
+            query.push(" AND address = ");
+            query.push_bind(address);
+        }
+
+        if let Some(ref logo) = row.logo {
+            query.push(" AND logo = ");
+            query.push_bind(logo);
+        }
+
+        if let Some(ref parent_id) = row.parent_id {
+            query.push(" AND parent_id = ");
+            query.push_bind(parent_id);
+        }
+
+        if let Some(seq_no) = row.seq_no {
+            query.push(" AND seq_no = ");
+            query.push_bind(seq_no);
+        }
+
+        if let Some(status) = row.status {
+            query.push(" AND status = ");
+            query.push_bind(status);
+        }
+
+        if let Some(ref create_by) = row.create_by {
+            query.push(" AND create_by = ");
+            query.push_bind(create_by);
+        }
+
+        if let Some(create_time) = row.create_time {
+            query.push(" AND create_time = ");
+            query.push_bind(create_time);
+        }
+
+        if let Some(ref update_by) = row.update_by {
+            query.push(" AND update_by = ");
+            query.push_bind(update_by);
+        }
+
+        if let Some(update_time) = row.update_time {
+            query.push(" AND update_time = ");
+            query.push_bind(update_time);
+        }
+
+        if let Some(ref remark) = row.remark {
+            query.push(" AND remark = ");
+            query.push_bind(remark);
+        }
+
+        if let Some(ref ancestors) = row.ancestors {
+            query.push(" AND ancestors = ");
+            query.push_bind(ancestors);
+        }
+
+        if let Some(deleted_at) = row.deleted_at {
+            query.push(" AND deleted_at = ");
+            query.push_bind(deleted_at);
+        }
+    }
+
+    /// 使用QueryBuilder构建插入全部字段的语句，值为None的字段插入NULL
+    fn build_insert_query_with_builder<'a, DB>(&'a self, query: &mut QueryBuilder<'a, DB>, row: &'a Dept)
+    where
+        DB: Database,
+        String: Encode<'a, DB> + Type<DB>,
+        i32: Encode<'a, DB> + Type<DB>,
+        NaiveDateTime: Encode<'a, DB> + Type<DB>,
+        Option<String>: Encode<'a, DB>,
+        Option<i32>: Encode<'a, DB>,
+        Option<NaiveDateTime>: Encode<'a, DB>,
+    {
+        // 字段顺序与DEPT_FIELDS保持一致
+        query.push(format!("INSERT INTO sys_dept ({DEPT_FIELDS}) VALUES ("));
+
+        let mut values = query.separated(", ");
+        values.push_bind(&row.id);
+        values.push_bind(&row.parent_id);
+        values.push_bind(&row.name);
+        values.push_bind(&row.email);
+        values.push_bind(&row.telephone);
+        values.push_bind(&row.address);
+        values.push_bind(&row.logo);
+        values.push_bind(row.seq_no);
+        values.push_bind(row.status);
+        values.push_bind(&row.create_by);
+        values.push_bind(row.create_time);
+        values.push_bind(&row.update_by);
+        values.push_bind(row.update_time);
+        values.push_bind(&row.remark);
+        values.push_bind(&row.ancestors);
+        values.push_bind(row.deleted_at);
+        values.push_unseparated(")");
+    }
+
+    /// 使用QueryBuilder构建选择性插入语句，只插入有值的字段
+    ///
+    /// # 返回值
+    /// 没有任何需要插入的字段时返回false
+    fn build_insert_selective_query_with_builder<'a, DB>(&'a self, query: &mut QueryBuilder<'a, DB>, row: &'a Dept) -> bool
+    where
+        DB: Database,
+        String: Encode<'a, DB> + Type<DB>,
+        i32: Encode<'a, DB> + Type<DB>,
+        NaiveDateTime: Encode<'a, DB> + Type<DB>,
+    {
+        // 收集需要插入的字段名
+        let mut fields = Vec::new();
+
+        // 检查每个字段是否有值，如果有值则添加到插入列表中
+        if !row.id.is_empty() {
+            fields.push("id");
+        }
+
+        if row.name.is_some() {
+            fields.push("name");
+        }
+
+        if row.email.is_some() {
+            fields.push("email");
+        }
+
+        if row.telephone.is_some() {
+            fields.push("telephone");
+        }
+
+        if row.address.is_some() {
+            fields.push("address");
+        }
+
+        if row.logo.is_some() {
+            fields.push("logo");
+        }
+
+        if row.parent_id.is_some() {
+            fields.push("parent_id");
+        }
+
+        if row.seq_no.is_some() {
+            fields.push("seq_no");
+        }
+
+        if row.status.is_some() {
+            fields.push("status");
+        }
+
+        if row.create_by.is_some() {
+            fields.push("create_by");
+        }
+
+        if row.create_time.is_some() {
+            fields.push("create_time");
+        }
+
+        if row.update_by.is_some() {
+            fields.push("update_by");
+        }
+
+        if row.update_time.is_some() {
+            fields.push("update_time");
+        }
+
+        if row.remark.is_some() {
+            fields.push("remark");
+        }
+
+        if row.ancestors.is_some() {
+            fields.push("ancestors");
+        }
+
+        if row.deleted_at.is_some() {
+            fields.push("deleted_at");
+        }
+
+        // 如果没有任何字段需要插入，则直接返回
+        if fields.is_empty() {
+            return false;
+        }
+
+        // 构建字段列表
+        query.push(format!("INSERT INTO sys_dept ({}) VALUES (", fields.join(", ")));
+
+        // 按字段顺序绑定参数值，占位符由QueryBuilder按数据库方言生成
+        let mut values = query.separated(", ");
+
+        if !row.id.is_empty() {
+            values.push_bind(&row.id);
+        }
+
+        if let Some(ref name) = row.name {
+            values.push_bind(name);
+        }
+
+        if let Some(ref email) = row.email {
+            values.push_bind(email);
+        }
+
+        if let Some(ref telephone) = row.telephone {
+            values.push_bind(telephone);
+        }
+
+        if let Some(ref address) = row.address {
+            values.push_bind(address);
+        }
+
+        if let Some(ref logo) = row.logo {
+            values.push_bind(logo);
+        }
+
+        if let Some(ref parent_id) = row.parent_id {
+            values.push_bind(parent_id);
+        }
+
+        if let Some(seq_no) = row.seq_no {
+            values.push_bind(seq_no);
+        }
+
+        if let Some(status) = row.status {
+            values.push_bind(status);
+        }
+
+        if let Some(ref create_by) = row.create_by {
+            values.push_bind(create_by);
+        }
+
+        if let Some(create_time) = row.create_time {
+            values.push_bind(create_time);
+        }
+
+        if let Some(ref update_by) = row.update_by {
+            values.push_bind(update_by);
+        }
+
+        if let Some(update_time) = row.update_time {
+            values.push_bind(update_time);
+        }
+
+        if let Some(ref remark) = row.remark {
+            values.push_bind(remark);
+        }
+
+        if let Some(ref ancestors) = row.ancestors {
+            values.push_bind(ancestors);
+        }
+
+        if let Some(deleted_at) = row.deleted_at {
+            values.push_bind(deleted_at);
+        }
+
+        values.push_unseparated(")");
+        true
+    }
+
+    /// 使用QueryBuilder构建更新全部字段的语句，值为None的字段更新为NULL
+    fn build_update_query_with_builder<'a, DB>(&'a self, query: &mut QueryBuilder<'a, DB>, row: &'a Dept)
+    where
+        DB: Database,
+        String: Encode<'a, DB> + Type<DB>,
+        i32: Encode<'a, DB> + Type<DB>,
+        NaiveDateTime: Encode<'a, DB> + Type<DB>,
+        Option<String>: Encode<'a, DB>,
+        Option<i32>: Encode<'a, DB>,
+        Option<NaiveDateTime>: Encode<'a, DB>,
+    {
+        query.push("UPDATE sys_dept SET ");
+
+        let mut fields = query.separated(", ");
+        fields.push("name = ").push_bind_unseparated(&row.name);
+        fields.push("email = ").push_bind_unseparated(&row.email);
+        fields
+            .push("telephone = ")
+            .push_bind_unseparated(&row.telephone);
+        fields
+            .push("address = ")
+            .push_bind_unseparated(&row.address);
+        fields.push("logo = ").push_bind_unseparated(&row.logo);
+        fields
+            .push("parent_id = ")
+            .push_bind_unseparated(&row.parent_id);
+        fields.push("seq_no = ").push_bind_unseparated(row.seq_no);
+        fields.push("status = ").push_bind_unseparated(row.status);
+        fields
+            .push("create_by = ")
+            .push_bind_unseparated(&row.create_by);
+        fields
+            .push("create_time = ")
+            .push_bind_unseparated(row.create_time);
+        fields
+            .push("update_by = ")
+            .push_bind_unseparated(&row.update_by);
+        fields
+            .push("update_time = ")
+            .push_bind_unseparated(row.update_time);
+        fields.push("remark = ").push_bind_unseparated(&row.remark);
+        fields
+            .push("ancestors = ")
+            .push_bind_unseparated(&row.ancestors);
+        fields
+            .push("deleted_at = ")
+            .push_bind_unseparated(row.deleted_at);
+
+        query.push(" WHERE id = ");
+        query.push_bind(&row.id);
+        query.push(" AND deleted_at IS NULL");
+    }
+
+    /// 使用QueryBuilder构建选择性更新语句，只更新有值的字段
+    ///
+    /// # 返回值
+    /// 没有任何需要更新的字段时返回false
+    fn build_update_selective_query_with_builder<'a, DB>(&'a self, query: &mut QueryBuilder<'a, DB>, row: &'a Dept) -> bool
+    where
+        DB: Database,
+        String: Encode<'a, DB> + Type<DB>,
+        i32: Encode<'a, DB> + Type<DB>,
+        NaiveDateTime: Encode<'a, DB> + Type<DB>,
+    {
+        query.push("UPDATE sys_dept SET ");
+
+        // 记录是否有需要更新的字段
+        let mut has_field = false;
+        let mut fields = query.separated(", ");
+
+        if let Some(ref name) = row.name {
+            fields.push("name = ").push_bind_unseparated(name);
+            has_field = true;
+        }
+
+        if let Some(ref email) = row.email {
+            fields.push("email = ").push_bind_unseparated(email);
+            has_field = true;
+        }
+
+        if let Some(ref telephone) = row.telephone {
+            fields.push("telephone = ").push_bind_unseparated(telephone);
+            has_field = true;
+        }
+
+        if let Some(ref address) = row.address {
+            fields.push("address = ").push_bind_unseparated(address);
+            has_field = true;
+        }
+
+        if let Some(ref logo) = row.logo {
+            fields.push("logo = ").push_bind_unseparated(logo);
+            has_field = true;
+        }
+
+        if let Some(ref parent_id) = row.parent_id {
+            fields.push("parent_id = ").push_bind_unseparated(parent_id);
+            has_field = true;
+        }
+
+        if let Some(seq_no) = row.seq_no {
+            fields.push("seq_no = ").push_bind_unseparated(seq_no);
+            has_field = true;
+        }
+
+        if let Some(status) = row.status {
+            fields.push("status = ").push_bind_unseparated(status);
+            has_field = true;
+        }
+
+        if let Some(ref create_by) = row.create_by {
+            fields.push("create_by = ").push_bind_unseparated(create_by);
+            has_field = true;
+        }
+
+        if let Some(create_time) = row.create_time {
+            fields
+                .push("create_time = ")
+                .push_bind_unseparated(create_time);
+            has_field = true;
+        }
+
+        if let Some(ref update_by) = row.update_by {
+            fields.push("update_by = ").push_bind_unseparated(update_by);
+            has_field = true;
+        }
+
+        if let Some(update_time) = row.update_time {
+            fields
+                .push("update_time = ")
+                .push_bind_unseparated(update_time);
+            has_field = true;
+        }
+
+        if let Some(ref remark) = row.remark {
+            fields.push("remark = ").push_bind_unseparated(remark);
+            has_field = true;
+        }
+
+        if let Some(ref ancestors) = row.ancestors {
+            fields.push("ancestors = ").push_bind_unseparated(ancestors);
+            has_field = true;
+        }
+
+        if let Some(deleted_at) = row.deleted_at {
+            fields
+                .push("deleted_at = ")
+                .push_bind_unseparated(deleted_at);
+            has_field = true;
+        }
+
+        // 没有更新任何字段
+        if !has_field {
+            return false;
+        }
+
+        query.push(" WHERE id = ");
+        query.push_bind(&row.id);
+        query.push(" AND deleted_at IS NULL");
+        true
+    }
+}
+
+#[async_trait]
+impl DeptRepository for DeptRepositorySqlxImpl {
+    /// 根据主键逻辑删除部门，记录删除时间
+    async fn delete_by_primary_key(&self, id: &str) -> Result<(), RepositoryError> {
+        let deleted_at = Utc::now().naive_utc();
+        with_conn!(self, |conn| {
+            let mut query = QueryBuilder::new("UPDATE sys_dept SET deleted_at = ");
+            query.push_bind(deleted_at);
+            query.push(" WHERE id = ");
+            query.push_bind(id);
+            query.push(" AND deleted_at IS NULL");
+            query.build().execute(conn).await.map(|_| ())
+        })
+        .map_err(RepositoryError::from)
+    }
+
+    /// 根据主键恢复已删除的部门
+    async fn restore_by_primary_key(&self, id: &str) -> Result<u64, RepositoryError> {
+        with_conn!(self, |conn| {
+            let mut query = QueryBuilder::new("UPDATE sys_dept SET deleted_at = NULL WHERE id = ");
+            query.push_bind(id);
+            query.push(" AND deleted_at IS NOT NULL");
+            query
+                .build()
+                .execute(conn)
+                .await
+                .map(|result| result.rows_affected())
+        })
+        .map_err(RepositoryError::from)
+    }
+
+    /// 根据主键彻底删除已删除的部门
+    async fn purge_by_primary_key(&self, id: &str) -> Result<u64, RepositoryError> {
+        with_conn!(self, |conn| {
+            let mut query = QueryBuilder::new("DELETE FROM sys_dept WHERE id = ");
+            query.push_bind(id);
+            query.push(" AND deleted_at IS NOT NULL");
+            query
+                .build()
+                .execute(conn)
+                .await
+                .map(|result| result.rows_affected())
+        })
+        .map_err(RepositoryError::from)
+    }
+
+    /// 插入部门记录
+    async fn insert(&self, row: &Dept) -> Result<(), RepositoryError> {
+        with_conn!(self, |conn| {
+            let mut query = QueryBuilder::new("");
+            self.build_insert_query_with_builder(&mut query, row);
+            query.build().execute(conn).await.map(|_| ())
+        })
+        .map_err(RepositoryError::from)
+    }
+
+    /// 选择性插入部门记录
+    async fn insert_selective(&self, row: &Dept) -> Result<(), RepositoryError> {
+        with_conn!(self, |conn| {
+            let mut query = QueryBuilder::new("");
+            // 如果没有任何字段需要插入，则返回错误
+            if !self.build_insert_selective_query_with_builder(&mut query, row) {
+                return Err(RepositoryError::Database("没有需要插入的字段".into()));
+            }
+            query.build().execute(conn).await.map(|_| ())
+        })
+        .map_err(RepositoryError::from)
+    }
+
+    /// 根据主键查询部门
+    async fn select_by_primary_key(&self, id: &str) -> Result<Option<Dept>, RepositoryError> {
+        with_conn!(self, |conn| {
+            let mut query = QueryBuilder::new(format!("SELECT {DEPT_FIELDS} FROM sys_dept WHERE deleted_at IS NULL AND id = "));
+            query.push_bind(id);
+            query.build_query_as::<Dept>().fetch_optional(conn).await
+        })
+        .map_err(RepositoryError::from)
+    }
+
+    /// 根据主键查询并锁定部门
+    async fn select_by_primary_key_for_update(&self, id: &str) -> Result<Option<Dept>, RepositoryError> {
+        let for_update = self.database_type().for_update_clause();
+        with_conn!(self, |conn| {
+            let mut query = QueryBuilder::new(format!("SELECT {DEPT_FIELDS} FROM sys_dept WHERE deleted_at IS NULL AND id = "));
+            query.push_bind(id);
+            query.push(for_update);
+            query.build_query_as::<Dept>().fetch_optional(conn).await
+        })
+        .map_err(RepositoryError::from)
+    }
+
+    /// 根据父部门ID查询部门
+    async fn select_dept_by_parent_id(&self, parent_id: &str) -> Result<Vec<Dept>, RepositoryError> {
+        with_conn!(self, |conn| {
+            let mut query = QueryBuilder::new(format!("SELECT {DEPT_FIELDS} FROM sys_dept WHERE deleted_at IS NULL AND parent_id = "));
+            query.push_bind(parent_id);
+            query.build_query_as::<Dept>().fetch_all(conn).await
+        })
+        .map_err(RepositoryError::from)
+    }
+
+    /// 查询部门的全部下级部门，按祖级路径前缀匹配
+    async fn select_descendants(&self, id: &str) -> Result<Vec<Dept>, RepositoryError> {
+        let Some(dept) = self.select_by_primary_key(id).await? else {
+            return Ok(Vec::new());
+        };
+        let filter = AncestorsPrefixFilter::new(self.database_type(), &dept.path());
+        with_conn!(self, |conn| {
+            let mut query = QueryBuilder::new(format!("SELECT {DEPT_FIELDS} FROM sys_dept WHERE deleted_at IS NULL AND {}", filter.head));
+            query.push_bind(&filter.pattern);
+            query.push(filter.tail);
+            query.push(" ORDER BY id");
+            query.build_query_as::<Dept>().fetch_all(conn).await
+        })
+        .map_err(RepositoryError::from)
+    }
+
+    /// 查询部门的全部上级部门，按祖级路径中的顺序排列
+    async fn select_ancestors(&self, id: &str) -> Result<Vec<Dept>, RepositoryError> {
+        let Some(dept) = self.select_by_primary_key(id).await? else {
+            return Ok(Vec::new());
+        };
+        let ids = dept.ancestor_ids();
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let mut list = with_conn!(self, |conn| {
+            let mut query = QueryBuilder::new(format!("SELECT {DEPT_FIELDS} FROM sys_dept WHERE deleted_at IS NULL AND id IN ("));
+            let mut values = query.separated(", ");
+            for id in &ids {
+                values.push_bind(*id);
+            }
+            values.push_unseparated(")");
+            query.build_query_as::<Dept>().fetch_all(conn).await
+        })
+        .map_err(RepositoryError::from)?;
+        list.sort_by_key(|dept| ids.iter().position(|id| *id == dept.id));
+        Ok(list)
+    }
+
+    /// 按查询选项查询部门列表
+    async fn select_dept_list_with_options(&self, row: &Dept, options: DeptQueryOptions) -> Result<Vec<Dept>, RepositoryError> {
+        with_conn!(self, |conn| {
+            let mut query = QueryBuilder::new("");
+            self.build_select_dept_list_query_with_builder(&mut query, row, options);
+            query.build_query_as::<Dept>().fetch_all(conn).await
+        })
+        .map_err(RepositoryError::from)
+    }
+
+    /// 分页查询部门列表
+    async fn select_dept_list_page(&self, row: &Dept, offset: u64, limit: u64) -> Result<(Vec<Dept>, u64), RepositoryError> {
+        let total: i64 = with_conn!(self, |conn| {
+            let mut count_query = QueryBuilder::new("");
+            self.build_count_dept_list_query_with_builder(&mut count_query, row, DeptQueryOptions::default());
+            count_query.build_query_scalar().fetch_one(conn).await
+        })
+        .map_err(RepositoryError::from)?;
+
+        // 没有满足条件的记录时无需再查询当前页
+        if total == 0 {
+            return Ok((Vec::new(), 0));
+        }
+
+        let list = with_conn!(self, |conn| {
+            let mut query = QueryBuilder::new("");
+            self.build_select_dept_list_query_with_builder(&mut query, row, DeptQueryOptions::default());
+            // 各数据库对NULL的默认排序不同（MySQL、SQLite升序时排在最前），显式把未设置排序号的排在最后，与部门树的显示顺序一致
+            query.push(" ORDER BY seq_no IS NULL, seq_no, id LIMIT ");
+            query.push_bind(limit as i64);
+            query.push(" OFFSET ");
+            query.push_bind(offset as i64);
+            query.build_query_as::<Dept>().fetch_all(conn).await
+        })
+        .map_err(RepositoryError::from)?;
+
+        Ok((list, total as u64))
+    }
+
+    /// 根据主键更新部门
+    async fn update_by_primary_key(&self, row: &Dept) -> Result<u64, RepositoryError> {
+        with_conn!(self, |conn| {
+            let mut query = QueryBuilder::new("");
+            self.build_update_query_with_builder(&mut query, row);
+            query
+                .build()
+                .execute(conn)
+                .await
+                .map(|result| result.rows_affected())
+        })
+        .map_err(RepositoryError::from)
+    }
+
+    /// 根据主键选择性更新部门
+    async fn update_by_primary_key_selective(&self, row: &Dept) -> Result<u64, RepositoryError> {
+        with_conn!(self, |conn| {
+            let mut query = QueryBuilder::new("");
+            // 没有需要更新的字段时不执行语句
+            if !self.build_update_selective_query_with_builder(&mut query, row) {
+                return Ok(0);
+            }
+            query
+                .build()
+                .execute(conn)
+                .await
+                .map(|result| result.rows_affected())
+        })
+        .map_err(RepositoryError::from)
+    }
+
+    /// 替换下级部门祖级路径的前缀，MySQL使用CONCAT拼接，其他数据库使用 `||`
+    async fn update_ancestors_prefix(&self, old_path: &str, new_path: &str) -> Result<u64, RepositoryError> {
+        let database_type = self.database_type();
+        let filter = AncestorsPrefixFilter::new(database_type, old_path);
+        // SUBSTR按字符计数，从1开始
+        let start = old_path.chars().count() as i32 + 1;
+        with_conn!(self, |conn| {
+            let mut query = QueryBuilder::new("UPDATE sys_dept SET ancestors = ");
+            if database_type == DatabaseType::MySql {
+                query.push("CONCAT(");
+                query.push_bind(new_path);
+                query.push(", SUBSTR(ancestors, ");
+                query.push_bind(start);
+                query.push("))");
+            } else {
+                query.push_bind(new_path);
+                query.push(" || SUBSTR(ancestors, ");
+                query.push_bind(start);
+                query.push(")");
+            }
+            query.push(" WHERE deleted_at IS NULL AND ");
+            query.push(filter.head);
+            query.push_bind(&filter.pattern);
+            query.push(filter.tail);
+            query
+                .build()
+                .execute(conn)
+                .await
+                .map(|result| result.rows_affected())
+        })
+        .map_err(RepositoryError::from)
+    }
+
+    /// 开启事务，事务中的仓储与当前仓储共用连接池
+    async fn begin(&self) -> Result<Box<dyn DeptTransaction>, RepositoryError> {
+        if self.transaction.is_some() {
+            return Err(RepositoryError::Transaction("SQLx实现不支持嵌套事务".to_string()));
+        }
+
+        let transaction = match &self.pool {
+            DbPool::MySql(pool) => pool.begin().await.map(DbTransaction::MySql),
+            #[cfg(feature = "sqlite")]
+            DbPool::Sqlite(pool) => pool.begin().await.map(DbTransaction::Sqlite),
+            #[cfg(feature = "postgres")]
+            DbPool::Postgres(pool) => pool.begin().await.map(DbTransaction::Postgres),
+        }
+        .map_err(RepositoryError::from)?;
+
+        Ok(Box::new(Self {
+            pool: self.pool.clone(),
+            transaction: Some(Mutex::new(transaction)),
+        }))
+    }
+}
+
+#[async_trait]
+impl DeptTransaction for DeptRepositorySqlxImpl {
+    /// 提交事务
+    async fn commit(self: Box<Self>) -> Result<(), RepositoryError> {
+        let Some(transaction) = self.transaction else {
+            return Err(RepositoryError::Transaction("当前仓储未开启事务".to_string()));
+        };
+        transaction
+            .into_inner()
+            .finish(true)
+            .await
+            .map_err(RepositoryError::from)
+    }
+
+    /// 回滚事务，未提交的事务被丢弃时SQLx也会自动回滚
+    async fn rollback(self: Box<Self>) -> Result<(), RepositoryError> {
+        let Some(transaction) = self.transaction else {
+            return Err(RepositoryError::Transaction("当前仓储未开启事务".to_string()));
+        };
+        transaction
+            .into_inner()
+            .finish(false)
+            .await
+            .map_err(RepositoryError::from)
+    }
+}
+
+#[async_trait]
+impl MigrationExecutor for DeptRepositorySqlxImpl {
+    /// 根据连接池类型判断数据库类型
+    fn database_type(&self) -> DatabaseType {
+        match self.pool {
+            DbPool::MySql(_) => DatabaseType::MySql,
+            #[cfg(feature = "sqlite")]
+            DbPool::Sqlite(_) => DatabaseType::Sqlite,
+            #[cfg(feature = "postgres")]
+            DbPool::Postgres(_) => DatabaseType::Postgres,
+        }
+    }
+
+    /// 执行迁移脚本
+    async fn execute_script(&self, script: &str) -> Result<(), Box<dyn StdError + Send + Sync>> {
+        with_pool!(&self.pool, |pool| sqlx::raw_sql(script).execute(pool).await.map(|_| ())).map_err(|e| Box::new(e) as Box<dyn StdError + Send + Sync>)
+    }
+
+    /// 查询已应用的迁移版本
+    async fn select_applied_versions(&self) -> Result<Vec<i64>, Box<dyn StdError + Send + Sync>> {
+        with_pool!(&self.pool, |pool| {
+            sqlx::query_scalar::<_, i64>(&format!("SELECT version FROM {MIGRATIONS_TABLE} ORDER BY version"))
+                .fetch_all(pool)
+                .await
+        })
+        .map_err(|e| Box::new(e) as Box<dyn StdError + Send + Sync>)
+    }
+}
@@ -20,11 +20,14 @@ diesel::table! {
         status -> Nullable<Integer>,
         #[max_length = 30]
         create_by -> Nullable<Varchar>,
-        create_time -> Nullable<Datetime>,
+        create_time -> Nullable<Timestamp>,
         #[max_length = 30]
         update_by -> Nullable<Varchar>,
-        update_time -> Nullable<Datetime>,
+        update_time -> Nullable<Timestamp>,
         #[max_length = 200]
         remark -> Nullable<Varchar>,
+        #[max_length = 500]
+        ancestors -> Nullable<Varchar>,
+        deleted_at -> Nullable<Timestamp>,
     }
 }
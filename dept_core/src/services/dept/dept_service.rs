@@ -0,0 +1,74 @@
+//! 部门服务接口定义
+
+use crate::models::Dept;
+use crate::params::dept_param::DeptParam;
+use crate::views::{dept_tree::DeptTree, dept_vo::DeptVO};
+use async_trait::async_trait;
+use common_wrapper::i18n::Locale;
+use common_wrapper::{ListWrapper, PageWrapper, ResponseWrapper, SingleWrapper};
+use std::collections::HashMap;
+
+/// 部门服务trait
+///
+/// 返回响应的方法按参数 `locale` 输出响应消息、错误描述及状态描述
+#[async_trait]
+pub trait DeptService: Send + Sync {
+    /// 获取部门树
+    ///
+    /// 按查询条件筛选部门后构建部门树，满足条件的部门的上级部门也保留在树中，每一层都按显示顺序、名称排序；
+    /// 参数中的 `rootId` 有值时只返回该部门及其下级部门，`maxDepth` 限制返回的层数，`detail` 为 `true` 时返回节点详情
+    async fn get_dept_tree(&self, dept_param: DeptParam, locale: Locale) -> ListWrapper<DeptTree>;
+
+    /// 获取部门信息Map 用于部门信息匹配
+    async fn get_dept(&self, dept_param: DeptParam) -> HashMap<String, Dept>;
+
+    /// 查询部门列表
+    async fn select_dept_list(&self, dept_param: DeptParam, locale: Locale) -> ListWrapper<Dept>;
+
+    /// 查询部门VO列表
+    ///
+    /// 查询部门列表信息，返回包含状态描述和父部门名称等额外信息的部门VO列表
+    async fn select_dept_vo_list(&self, dept_param: DeptParam, locale: Locale) -> ListWrapper<DeptVO>;
+
+    /// 分页查询部门VO列表
+    ///
+    /// 按 `page_num`、`page_size` 在数据库端分页，返回当前页的部门VO及总记录数、总页数等分页信息
+    async fn select_dept_vo_page(&self, dept_param: DeptParam, locale: Locale) -> PageWrapper<DeptVO>;
+
+    /// 新增部门
+    ///
+    /// 新增启用的部门时按部门状态策略检查全部上级部门
+    async fn add_dept(&self, dept_param: DeptParam, locale: Locale) -> ResponseWrapper;
+
+    /// 编辑部门
+    ///
+    /// 启用部门或修改父级部门时按部门状态策略检查新的上级部门，停用部门时停用下级部门，规则同 [DeptService::move_dept]
+    async fn edit_dept(&self, dept_param: DeptParam, locale: Locale) -> ResponseWrapper;
+
+    /// 移动部门
+    ///
+    /// 将部门连同其下级部门移动到新的父级部门下，新的父级部门不能为部门本身或其下级部门；
+    /// 新的上级部门中存在已停用部门时，按部门状态策略拒绝移动启用的部门，或停用部门及其全部下级部门
+    async fn move_dept(&self, id: &str, new_parent_id: &str, locale: Locale) -> ResponseWrapper;
+
+    /// 编辑部门状态
+    ///
+    /// 按部门状态策略（参见 [DeptStatusPolicy](crate::services::dept::dept_status_policy::DeptStatusPolicy)）停用下级部门、
+    /// 检查上级部门，成功时数据为部门本身及下级部门实际更新的行数
+    async fn edit_dept_status(&self, id: &str, status: i32, locale: Locale) -> SingleWrapper<u64>;
+
+    /// 删除部门
+    ///
+    /// 逻辑删除，只能删除没有子部门的部门，删除后可以通过 [DeptService::restore_dept] 恢复
+    async fn delete_dept(&self, dept_id: &str, locale: Locale) -> ResponseWrapper;
+
+    /// 恢复已删除的部门
+    ///
+    /// 原父级部门需要存在且未停用，同级部门中不能有同名部门；恢复启用的部门时按部门状态策略检查全部上级部门
+    async fn restore_dept(&self, dept_id: &str, locale: Locale) -> ResponseWrapper;
+
+    /// 彻底删除部门
+    ///
+    /// 只能彻底删除已删除且没有子部门（包括已删除的子部门）的部门，删除后无法恢复
+    async fn purge_dept(&self, dept_id: &str, locale: Locale) -> ResponseWrapper;
+}
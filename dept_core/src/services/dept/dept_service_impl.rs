@@ -0,0 +1,874 @@
+//! 部门服务实现
+
+use async_trait::async_trait;
+use chrono::Utc;
+use common_validation::{AsyncValidatable, CreateGroup, UpdateGroup};
+use common_wrapper::enums::status_enum::StatusEnum;
+use common_wrapper::enums::wrapper_err::WrapperErrEnum;
+use common_wrapper::i18n::{Locale, Localize};
+use common_wrapper::{ListWrapper, PageInfo, PageWrapper, ResponseWrapper, SingleWrapper};
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{
+    errors::{DeptError, RepositoryError, dept_error::MESSAGES},
+    models::{
+        Dept,
+        dept::{ANCESTORS_SEPARATOR, ROOT_ANCESTORS},
+    },
+    params::{dept_param::DeptParam, tree_param::TreeParam},
+    repositories::dept::dept_repository::{DeptQueryOptions, DeptRepository},
+    services::dept::{dept_service::DeptService, dept_status_policy::DeptStatusPolicy},
+    views::{dept_tree::DeptTree, dept_vo::DeptVO},
+};
+
+/// 部门服务实现
+pub struct DeptServiceImpl {
+    repository: Arc<dyn DeptRepository>,
+    status_policy: DeptStatusPolicy,
+}
+
+/// 构建部门树时各层共用的数据
+struct DeptTreeContext<'a> {
+    /// 父级部门ID到子部门的映射，构建某个部门的子节点时取出
+    children_map: HashMap<String, Vec<Dept>>,
+    /// 满足查询条件的部门ID
+    matched_ids: &'a HashSet<String>,
+    /// 各部门的直接子部门数量，不返回节点详情时为空
+    child_counts: Option<HashMap<String, u64>>,
+    /// 最多返回的层数，为空时不限制
+    max_depth: Option<u32>,
+}
+
+impl DeptServiceImpl {
+    /// 创建新的部门服务实例
+    ///
+    /// # 参数
+    /// * `repository` - 部门仓库trait的实现
+    ///
+    /// # 返回值
+    /// 返回新的部门服务实例
+    pub fn new(repository: Arc<dyn DeptRepository>) -> Self {
+        Self { repository, status_policy: DeptStatusPolicy::default() }
+    }
+
+    /// 设置部门状态策略
+    ///
+    /// 未设置时使用 [DeptStatusPolicy::Independent]
+    ///
+    /// # 参数
+    /// * `status_policy` - 修改部门状态时对上级、下级部门的处理方式
+    ///
+    /// # 返回值
+    /// 返回使用该策略的部门服务实例
+    pub fn with_status_policy(mut self, status_policy: DeptStatusPolicy) -> Self {
+        self.status_policy = status_policy;
+        self
+    }
+
+    /// 查询构建部门树所需的部门
+    ///
+    /// 返回全部部门及满足查询条件的部门ID，没有查询条件时只查询一次
+    async fn select_tree_depts(&self, condition: &Dept) -> Result<(Vec<Dept>, HashSet<String>), RepositoryError> {
+        let dept_list = self.repository.select_dept_list(&Dept::default()).await?;
+        let matched_ids = if *condition == Dept::default() {
+            dept_list.iter().map(|dept| dept.id.clone()).collect()
+        } else {
+            self.repository
+                .select_dept_list(condition)
+                .await?
+                .into_iter()
+                .map(|dept| dept.id)
+                .collect()
+        };
+        Ok((dept_list, matched_ids))
+    }
+
+    /// 构建部门树
+    ///
+    /// 满足查询条件的部门连同其全部上级部门出现在树中，只作为上级部门保留的节点 `matched` 为 `false`。
+    /// 父级部门为空的部门作为根节点，`root_id` 有值时只以该部门为根节点，不在树中时返回空树。
+    /// 每一层都按 [DeptServiceImpl::compare_display_order] 排序，`max_depth` 为空或0时不限制层数。
+    fn build_dept_tree(dept_list: Vec<Dept>, matched_ids: &HashSet<String>, tree_param: &TreeParam) -> Vec<DeptTree> {
+        // 沿父级部门逐级向上收集满足条件的部门及其上级部门，遇到已收集的部门即停止，数据中存在环时也能结束
+        let parent_map: HashMap<&str, &str> = dept_list
+            .iter()
+            .filter_map(|dept| {
+                dept.parent_id
+                    .as_deref()
+                    .map(|parent_id| (dept.id.as_str(), parent_id))
+            })
+            .collect();
+        let mut included = HashSet::new();
+        for id in matched_ids {
+            let mut current = Some(id.as_str());
+            while let Some(id) = current
+                && included.insert(id.to_string())
+            {
+                current = parent_map
+                    .get(id)
+                    .copied()
+                    .filter(|parent_id| !parent_id.is_empty());
+            }
+        }
+
+        // 子部门数量按全部部门统计
+        let child_counts = tree_param.detail.unwrap_or(false).then(|| {
+            let mut child_counts: HashMap<String, u64> = HashMap::new();
+            for parent_id in parent_map.values() {
+                *child_counts.entry(parent_id.to_string()).or_default() += 1;
+            }
+            child_counts
+        });
+
+        let root_id = tree_param
+            .root_id
+            .as_deref()
+            .map(str::trim)
+            .filter(|root_id| !root_id.is_empty());
+        let mut roots = Vec::new();
+        let mut children_map: HashMap<String, Vec<Dept>> = HashMap::new();
+        for dept in dept_list
+            .into_iter()
+            .filter(|dept| included.contains(&dept.id))
+        {
+            let is_root = match root_id {
+                Some(root_id) => dept.id == root_id,
+                None => dept.parent_id.as_deref().is_none_or(str::is_empty),
+            };
+            if is_root {
+                roots.push(dept);
+            } else if let Some(parent_id) = dept
+                .parent_id
+                .clone()
+                .filter(|parent_id| !parent_id.is_empty())
+            {
+                children_map.entry(parent_id).or_default().push(dept);
+            }
+        }
+
+        let mut context = DeptTreeContext {
+            children_map,
+            matched_ids,
+            child_counts,
+            max_depth: tree_param.max_depth.filter(|max_depth| *max_depth > 0),
+        };
+        Self::build_tree_level(roots, 1, &mut context)
+    }
+
+    /// 构建部门树的一层节点，`depth` 为该层的层数
+    ///
+    /// 每个部门的子部门从 `children_map` 中取出后再递归构建，
+    /// 数据中即使存在环也不会重复访问同一部门。
+    fn build_tree_level(mut depts: Vec<Dept>, depth: u32, context: &mut DeptTreeContext) -> Vec<DeptTree> {
+        depts.sort_by(Self::compare_display_order);
+        depts
+            .into_iter()
+            .map(|dept| {
+                let children = match context.children_map.remove(&dept.id) {
+                    Some(children) if context.max_depth.is_none_or(|max_depth| depth < max_depth) => Self::build_tree_level(children, depth + 1, context),
+                    _ => Vec::new(),
+                };
+                let mut node = DeptTree {
+                    matched: context.matched_ids.contains(&dept.id),
+                    id: dept.id,
+                    parent_id: dept.parent_id,
+                    name: dept.name,
+                    children,
+                    ..Default::default()
+                };
+                if let Some(child_counts) = &context.child_counts {
+                    node.status = dept.status;
+                    node.seq_no = dept.seq_no;
+                    node.child_count = Some(child_counts.get(&node.id).copied().unwrap_or(0));
+                    node.disabled = Some(matches!(dept.status.and_then(StatusEnum::from_code), Some(StatusEnum::Disable)));
+                }
+                node
+            })
+            .collect()
+    }
+
+    /// 部门树中同一层部门的显示顺序
+    ///
+    /// 按显示顺序升序，未设置显示顺序的排在最后；显示顺序相同时按名称、ID排序，保证每次结果一致。
+    fn compare_display_order(a: &Dept, b: &Dept) -> Ordering {
+        let seq_no = |dept: &Dept| (dept.seq_no.is_none(), dept.seq_no);
+        seq_no(a)
+            .cmp(&seq_no(b))
+            .then_with(|| a.name.cmp(&b.name))
+            .then_with(|| a.id.cmp(&b.id))
+    }
+
+    /// 验证部门状态是否有效
+    fn validate_dept_status(&self, status: Option<i32>) -> Result<i32, DeptError> {
+        match status {
+            Some(s) => {
+                match StatusEnum::from_code(s) {
+                    Some(_) => Ok(s), // 状态码有效
+                    None => Err(DeptError::InvalidParam("DEPT_STATUS_INVALID".to_string())),
+                }
+            },
+            None => Err(DeptError::InvalidParam("DEPT_STATUS_REQUIRED".to_string())),
+        }
+    }
+
+    /// 验证部门是否存在
+    ///
+    /// 在事务中调用时该部门会被锁定到事务结束
+    async fn validate_dept_exists(repository: &dyn DeptRepository, dept_id: &str) -> Result<Dept, DeptError> {
+        // 对ID进行去空格处理
+        let trimmed_dept_id = dept_id.trim();
+        if trimmed_dept_id.is_empty() {
+            return Err(DeptError::InvalidParam("DEPT_ID_REQUIRED".to_string()));
+        }
+
+        repository
+            .select_by_primary_key_for_update(trimmed_dept_id)
+            .await?
+            .ok_or(DeptError::NotFound)
+    }
+
+    /// 查询已删除的部门
+    ///
+    /// 已删除的部门无法锁定，恢复、彻底删除时由对应语句的条件保证只作用于已删除的部门，
+    /// 检查之后部门被并发恢复或彻底删除时语句影响的行数为0，调用方应返回 [DeptError::NotDeleted]
+    ///
+    /// # 返回值
+    /// 部门不存在时返回 [DeptError::NotFound]，未删除时返回 [DeptError::NotDeleted]
+    async fn find_deleted_dept(repository: &dyn DeptRepository, dept_id: &str) -> Result<Dept, DeptError> {
+        // 对ID进行去空格处理
+        let trimmed_dept_id = dept_id.trim();
+        if trimmed_dept_id.is_empty() {
+            return Err(DeptError::InvalidParam("DEPT_ID_REQUIRED".to_string()));
+        }
+
+        let condition = Dept { id: trimmed_dept_id.to_string(), ..Default::default() };
+        let dept = repository
+            .select_dept_list_with_options(&condition, DeptQueryOptions::include_deleted())
+            .await?
+            .into_iter()
+            .next()
+            .ok_or(DeptError::NotFound)?;
+        if dept.deleted_at.is_none() {
+            return Err(DeptError::NotDeleted);
+        }
+        Ok(dept)
+    }
+
+    /// 计算父级部门下的部门的祖级路径，父级部门ID为空时为根部门
+    ///
+    /// 父级部门已在验证时锁定到事务结束，读取到的祖级路径在提交前不会被并发修改
+    async fn ancestors_under(repository: &dyn DeptRepository, parent_id: Option<&str>) -> Result<String, DeptError> {
+        let parent_id = parent_id.map(str::trim).unwrap_or_default();
+        if parent_id.is_empty() {
+            return Ok(ROOT_ANCESTORS.to_string());
+        }
+
+        repository
+            .select_by_primary_key(parent_id)
+            .await?
+            .map(|parent| parent.path())
+            .ok_or(DeptError::ParentNotFound)
+    }
+
+    /// 改写部门全部下级部门的祖级路径
+    ///
+    /// 下级部门的祖级路径都以部门原来的完整路径开头，部门移动到新的祖级路径下后只需替换这段前缀，
+    /// 由一条UPDATE语句更新整棵子树
+    async fn rebase_descendants(repository: &dyn DeptRepository, dept: &Dept, ancestors: &str) -> Result<(), DeptError> {
+        let new_path = format!("{ancestors}{}{ANCESTORS_SEPARATOR}", dept.id);
+        repository
+            .update_ancestors_prefix(&dept.path(), &new_path)
+            .await?;
+        Ok(())
+    }
+
+    /// 按祖级路径逐个锁定并检查上级部门，返回其中是否存在已停用的部门
+    ///
+    /// 上级部门被锁定到事务结束，检查之后不会被并发停用
+    async fn has_disabled_ancestor(repository: &dyn DeptRepository, ancestors: &str) -> Result<bool, DeptError> {
+        let path = Dept {
+            ancestors: Some(ancestors.to_string()),
+            ..Default::default()
+        };
+        for ancestor_id in path.ancestor_ids() {
+            let ancestor = repository
+                .select_by_primary_key_for_update(ancestor_id)
+                .await?;
+            if let Some(StatusEnum::Disable) = ancestor
+                .and_then(|ancestor| ancestor.status)
+                .and_then(StatusEnum::from_code)
+            {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// 部门启用时按部门状态策略检查祖级路径上的全部上级部门
+    ///
+    /// 新增、恢复、移动部门及修改状态后部门为启用状态时调用，`ancestors` 为部门最终所在的祖级路径；
+    /// 策略要求上级部门均未停用且存在已停用的上级部门时返回 [DeptError::AncestorDisabled]
+    async fn check_ancestors_enabled(&self, repository: &dyn DeptRepository, ancestors: &str) -> Result<(), DeptError> {
+        if self.status_policy.require_enabled_ancestors() && Self::has_disabled_ancestor(repository, ancestors).await? {
+            return Err(DeptError::AncestorDisabled);
+        }
+        Ok(())
+    }
+
+    /// 部门移动到新的祖级路径下时按部门状态策略处理新的上级部门
+    ///
+    /// 部门为启用状态时先检查新的全部上级部门（参见 [DeptServiceImpl::check_ancestors_enabled]）；
+    /// 策略级联停用且新的上级部门中存在已停用部门时，部门改为停用，调用方更新部门后需停用其全部下级部门
+    ///
+    /// # 参数
+    /// * `ancestors` - 部门新的祖级路径
+    /// * `status` - 部门移动前的状态
+    ///
+    /// # 返回值
+    /// 返回部门移动后的状态
+    async fn status_after_move(&self, repository: &dyn DeptRepository, ancestors: &str, status: Option<i32>) -> Result<Option<i32>, DeptError> {
+        if status == Some(StatusEnum::Enable as i32) {
+            self.check_ancestors_enabled(repository, ancestors).await?;
+        }
+        if self.status_policy.cascade_disable() && Self::has_disabled_ancestor(repository, ancestors).await? {
+            return Ok(Some(StatusEnum::Disable as i32));
+        }
+        Ok(status)
+    }
+
+    /// 停用部门后按部门状态策略停用其全部下级部门
+    ///
+    /// 只更新尚未停用的下级部门，返回更新的行数；策略不级联停用时直接返回0
+    async fn disable_descendants(&self, repository: &dyn DeptRepository, dept_id: &str) -> Result<u64, DeptError> {
+        if !self.status_policy.cascade_disable() {
+            return Ok(0);
+        }
+        let disable = StatusEnum::Disable as i32;
+        let mut rows = 0;
+        for descendant in repository.select_descendants(dept_id).await? {
+            if descendant.status == Some(disable) {
+                continue;
+            }
+            let descendant = Dept {
+                id: descendant.id,
+                status: Some(disable),
+                update_by: Some("system".to_string()),
+                update_time: Some(Utc::now().naive_utc()),
+                ..Default::default()
+            };
+            rows += repository
+                .update_by_primary_key_selective(&descendant)
+                .await?;
+        }
+        Ok(rows)
+    }
+
+    /// 构造部门VO，补充指定语言的状态描述和父部门名称
+    fn build_dept_vo(dept: Dept, parent_name: Option<String>, locale: Locale) -> DeptVO {
+        // 获取状态描述
+        let status_desc = dept
+            .status
+            .and_then(StatusEnum::from_code)
+            .map(|status_enum| status_enum.localize(locale));
+
+        DeptVO { base: dept, status_desc, parent_name }
+    }
+
+    /// 将写操作的执行结果转换为指定语言的响应，失败时响应码由错误类型决定
+    fn to_response(result: Result<(), DeptError>, locale: Locale) -> ResponseWrapper {
+        match result {
+            Ok(()) => ResponseWrapper::success_default().localize(locale),
+            Err(e) => e.to_response(locale),
+        }
+    }
+
+    /// 新增部门
+    async fn try_add_dept(&self, mut dept_param: DeptParam) -> Result<(), DeptError> {
+        // 验证部门状态
+        let status = self.validate_dept_status(dept_param.status)?;
+        // 新增部门的ID由服务端生成，忽略请求中的部门ID，名称唯一性校验不排除任何部门
+        dept_param.id = None;
+
+        // 父级部门校验、名称唯一性校验与插入在同一事务中执行，父级部门被锁定到事务结束，
+        // 避免并发新增出同名部门，或在正在删除的部门下新增子部门；提前返回时事务随之回滚
+        let tx = self.repository.begin().await?;
+
+        // 验证父级部门及部门名称唯一性
+        dept_param
+            .validate_async_with_group::<CreateGroup>(tx.as_ref())
+            .await?;
+
+        // 创建部门实体
+        let mut dept = Dept::from(dept_param);
+        // 设置ID
+        dept.id = Uuid::new_v4().simple().to_string(); // 32位无连字符，与 char(32) 主键匹配
+        // 设置创建者（TODO: 获取当前登录用户）
+        dept.create_by = Some("system".to_string());
+        dept.create_time = Some(Utc::now().naive_utc());
+        // 确保状态正确设置
+        dept.status = Some(status);
+        // 根据父级部门设置祖级路径
+        let ancestors = Self::ancestors_under(tx.as_ref(), dept.parent_id.as_deref()).await?;
+        // 新增启用的部门时检查全部上级部门
+        if status == StatusEnum::Enable as i32 {
+            self.check_ancestors_enabled(tx.as_ref(), &ancestors)
+                .await?;
+        }
+        dept.ancestors = Some(ancestors);
+
+        tx.insert(&dept).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// 编辑部门
+    async fn try_edit_dept(&self, dept_param: DeptParam) -> Result<(), DeptError> {
+        // 验证部门ID
+        let dept_id = match &dept_param.id {
+            Some(id) => id,
+            None => return Err(DeptError::InvalidParam("DEPT_ID_REQUIRED".to_string())),
+        };
+
+        // 校验与更新在同一事务中执行，部门自身及父级部门被锁定到事务结束
+        let tx = self.repository.begin().await?;
+
+        // 验证部门是否存在
+        let existing = Self::validate_dept_exists(tx.as_ref(), dept_id).await?;
+
+        // 验证部门状态
+        let status = self.validate_dept_status(dept_param.status)?;
+
+        // 验证父级部门、部门层级及部门名称唯一性（编辑部门时排除自身），父级部门不能为部门本身的下级部门
+        dept_param
+            .validate_async_with_group::<UpdateGroup>(tx.as_ref())
+            .await?;
+
+        // 创建部门实体
+        let mut dept = Dept::from(dept_param);
+        // 设置更新者（TODO: 获取当前登录用户）
+        dept.update_by = Some("system".to_string());
+        dept.update_time = Some(Utc::now().naive_utc());
+        // 确保状态正确设置
+        dept.status = Some(status);
+        // 修改了父级部门时部门连同下级部门移动到新的祖级路径下，并按策略处理新的上级部门
+        let moved = match dept.parent_id.as_deref() {
+            Some(parent_id) if existing.parent_id.as_deref() != Some(parent_id) => {
+                let ancestors = Self::ancestors_under(tx.as_ref(), Some(parent_id)).await?;
+                Self::rebase_descendants(tx.as_ref(), &existing, &ancestors).await?;
+                dept.status = self
+                    .status_after_move(tx.as_ref(), &ancestors, dept.status)
+                    .await?;
+                dept.ancestors = Some(ancestors);
+                true
+            },
+            _ => false,
+        };
+
+        // 未移动的部门由停用改为启用时检查全部上级部门
+        let enable = StatusEnum::Enable as i32;
+        if !moved
+            && status == enable
+            && existing.status != Some(enable)
+            && let Some(ancestors) = existing.ancestors.as_deref()
+        {
+            self.check_ancestors_enabled(tx.as_ref(), ancestors).await?;
+        }
+
+        tx.update_by_primary_key_selective(&dept).await?;
+
+        // 停用部门或移动到已停用的上级部门下时按策略停用下级部门，修改父级部门时下级部门的祖级路径此前已改写完毕
+        let disable = StatusEnum::Disable as i32;
+        if dept.status == Some(disable) && (existing.status != Some(disable) || moved) {
+            self.disable_descendants(tx.as_ref(), &dept.id).await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// 移动部门
+    ///
+    /// 下级部门通过父级部门ID关联，修改部门本身的父级部门ID后整棵子树随之移动，
+    /// 部门本身及全部下级部门的祖级路径在同一事务中改写；
+    /// 新的上级部门中存在已停用部门时按部门状态策略拒绝移动启用的部门，或停用部门及其全部下级部门
+    async fn try_move_dept(&self, id: &str, new_parent_id: &str) -> Result<(), DeptError> {
+        // 对ID进行去空格处理
+        let trimmed_id = id.trim();
+        if trimmed_id.is_empty() {
+            return Err(DeptError::InvalidParam("DEPT_ID_REQUIRED".to_string()));
+        }
+        let trimmed_parent_id = new_parent_id.trim();
+        if trimmed_parent_id.is_empty() {
+            return Err(DeptError::InvalidParam("DEPT_PARENT_ID_REQUIRED".to_string()));
+        }
+
+        // 校验与更新在同一事务中执行，部门自身、新的父级部门及其全部上级部门被锁定到事务结束
+        let tx = self.repository.begin().await?;
+
+        // 验证部门是否存在
+        let dept = Self::validate_dept_exists(tx.as_ref(), trimmed_id).await?;
+
+        // 按编辑部门的规则验证新的父级部门：存在且状态正常、不是部门本身或其下级部门、其下没有同名部门
+        let mut dept_param = DeptParam::from(dept.clone());
+        dept_param.parent_id = Some(trimmed_parent_id.to_string());
+        dept_param
+            .validate_async_with_group::<UpdateGroup>(tx.as_ref())
+            .await?;
+
+        // 按策略处理新的上级部门后改写下级部门的祖级路径，再更新部门本身
+        let ancestors = Self::ancestors_under(tx.as_ref(), Some(trimmed_parent_id)).await?;
+        let status = self
+            .status_after_move(tx.as_ref(), &ancestors, dept.status)
+            .await?;
+        Self::rebase_descendants(tx.as_ref(), &dept, &ancestors).await?;
+
+        let moved = Dept {
+            id: trimmed_id.to_string(),
+            parent_id: Some(trimmed_parent_id.to_string()),
+            ancestors: Some(ancestors),
+            status: status.filter(|status| Some(*status) != dept.status),
+            update_by: Some("system".to_string()),
+            update_time: Some(Utc::now().naive_utc()),
+            ..Default::default()
+        };
+        tx.update_by_primary_key_selective(&moved).await?;
+
+        // 移动到已停用的上级部门下时按策略停用下级部门
+        if status == Some(StatusEnum::Disable as i32) {
+            self.disable_descendants(tx.as_ref(), trimmed_id).await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// 编辑部门状态
+    ///
+    /// 按部门状态策略在同一事务中检查上级部门、停用下级部门，部门本身被锁定到事务结束，
+    /// 返回部门本身及下级部门实际更新的行数
+    async fn try_edit_dept_status(&self, id: &str, status: i32) -> Result<u64, DeptError> {
+        // 对ID进行去空格处理
+        let trimmed_id = id.trim();
+        if trimmed_id.is_empty() {
+            return Err(DeptError::InvalidParam("DEPT_ID_REQUIRED".to_string()));
+        }
+
+        // 验证部门状态
+        self.validate_dept_status(Some(status))?;
+
+        let tx = self.repository.begin().await?;
+
+        // 验证部门是否存在
+        let existing = Self::validate_dept_exists(tx.as_ref(), trimmed_id).await?;
+
+        if status == StatusEnum::Enable as i32
+            && let Some(ancestors) = existing.ancestors.as_deref()
+        {
+            self.check_ancestors_enabled(tx.as_ref(), ancestors).await?;
+        }
+
+        let dept = Dept {
+            id: trimmed_id.to_string(),
+            status: Some(status),
+            update_by: Some("system".to_string()),
+            update_time: Some(chrono::Utc::now().naive_utc()),
+            ..Default::default()
+        };
+        let mut rows = tx.update_by_primary_key_selective(&dept).await?;
+
+        if status == StatusEnum::Disable as i32 {
+            rows += self.disable_descendants(tx.as_ref(), trimmed_id).await?;
+        }
+        tx.commit().await?;
+        Ok(rows)
+    }
+
+    /// 删除部门
+    async fn try_delete_dept(&self, dept_id: &str) -> Result<(), DeptError> {
+        // 对ID进行去空格处理
+        let trimmed_dept_id = dept_id.trim();
+        if trimmed_dept_id.is_empty() {
+            return Err(DeptError::InvalidParam("DEPT_ID_REQUIRED".to_string()));
+        }
+
+        // 子部门检查与删除在同一事务中执行，待删除的部门被锁定到事务结束，
+        // 新增子部门时会锁定同一条记录，因此不会在检查之后又插入子部门
+        let tx = self.repository.begin().await?;
+
+        // 验证部门是否存在
+        Self::validate_dept_exists(tx.as_ref(), trimmed_dept_id).await?;
+
+        // 检查是否存在子部门
+        if !tx
+            .select_dept_by_parent_id(trimmed_dept_id)
+            .await?
+            .is_empty()
+        {
+            return Err(DeptError::HasChildren);
+        }
+
+        // 记录删除者后逻辑删除，只能删除没有子部门的部门，未删除的部门的祖级路径不会指向已删除的部门
+        let dept = Dept {
+            id: trimmed_dept_id.to_string(),
+            update_by: Some("system".to_string()),
+            update_time: Some(Utc::now().naive_utc()),
+            ..Default::default()
+        };
+        tx.update_by_primary_key_selective(&dept).await?;
+        tx.delete_by_primary_key(trimmed_dept_id).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// 恢复已删除的部门
+    ///
+    /// 按编辑部门的规则验证原父级部门，父级部门同样已删除时需要先恢复父级部门；
+    /// 删除期间父级部门可能被移动，祖级路径按父级部门当前的路径重新计算，启用的部门按部门状态策略检查新的上级部门
+    async fn try_restore_dept(&self, dept_id: &str) -> Result<(), DeptError> {
+        let tx = self.repository.begin().await?;
+
+        let dept = Self::find_deleted_dept(tx.as_ref(), dept_id).await?;
+
+        // 验证父级部门存在且状态正常、同级部门中没有同名部门，顶级部门不需要验证父级部门
+        let mut dept_param = DeptParam::from(dept.clone());
+        if dept_param
+            .parent_id
+            .as_deref()
+            .is_some_and(|parent_id| parent_id.trim().is_empty())
+        {
+            dept_param.parent_id = None;
+        }
+        dept_param
+            .validate_async_with_group::<UpdateGroup>(tx.as_ref())
+            .await?;
+
+        let ancestors = Self::ancestors_under(tx.as_ref(), dept_param.parent_id.as_deref()).await?;
+        // 恢复启用的部门时检查父级部门当前的全部上级部门
+        if dept.status == Some(StatusEnum::Enable as i32) {
+            self.check_ancestors_enabled(tx.as_ref(), &ancestors)
+                .await?;
+        }
+        // 并发恢复或彻底删除时部门可能已不再处于删除状态
+        if tx.restore_by_primary_key(&dept.id).await? == 0 {
+            return Err(DeptError::NotDeleted);
+        }
+
+        let dept = Dept {
+            id: dept.id,
+            ancestors: Some(ancestors),
+            update_by: Some("system".to_string()),
+            update_time: Some(Utc::now().naive_utc()),
+            ..Default::default()
+        };
+        tx.update_by_primary_key_selective(&dept).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// 彻底删除部门
+    ///
+    /// 只能彻底删除已删除的部门，已删除的子部门同样需要先彻底删除，否则子部门无法再恢复
+    async fn try_purge_dept(&self, dept_id: &str) -> Result<(), DeptError> {
+        let tx = self.repository.begin().await?;
+
+        let dept = Self::find_deleted_dept(tx.as_ref(), dept_id).await?;
+
+        // 检查是否存在子部门，包括已删除的子部门
+        let condition = Dept { parent_id: Some(dept.id.clone()), ..Default::default() };
+        if !tx
+            .select_dept_list_with_options(&condition, DeptQueryOptions::include_deleted())
+            .await?
+            .is_empty()
+        {
+            return Err(DeptError::HasChildren);
+        }
+
+        // 并发恢复时部门可能已不再处于删除状态，彻底删除语句不会删除未删除的部门
+        if tx.purge_by_primary_key(&dept.id).await? == 0 {
+            return Err(DeptError::NotDeleted);
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl DeptService for DeptServiceImpl {
+    async fn get_dept_tree(&self, dept_param: DeptParam, locale: Locale) -> ListWrapper<DeptTree> {
+        let tree_param = dept_param.tree_param.clone();
+        // 转换参数类型
+        let dept = Dept::from(dept_param);
+        match self.select_tree_depts(&dept).await {
+            Ok((dept_list, matched_ids)) => {
+                let tree_list = Self::build_dept_tree(dept_list, &matched_ids, &tree_param);
+                let mut wrapper = ListWrapper::new();
+                wrapper.set_success(tree_list);
+                wrapper.localize(locale)
+            },
+            Err(e) => {
+                let mut wrapper = ListWrapper::new();
+                wrapper.set_error(WrapperErrEnum::from(&e), MESSAGES.format(locale, "DEPT_TREE_QUERY_FAILED", &[&e.localize(locale)]));
+                wrapper
+            },
+        }
+    }
+
+    async fn get_dept(&self, dept_param: DeptParam) -> HashMap<String, Dept> {
+        // 转换参数类型
+        let dept = Dept::from(dept_param);
+        match self.repository.select_dept_list(&dept).await {
+            Ok(dept_list) => {
+                let mut dept_map = HashMap::with_capacity(dept_list.len());
+                for dept in dept_list {
+                    dept_map.insert(dept.id.clone(), dept);
+                }
+                dept_map
+            },
+            Err(_) => HashMap::new(),
+        }
+    }
+
+    async fn select_dept_list(&self, dept_param: DeptParam, locale: Locale) -> ListWrapper<Dept> {
+        match self
+            .repository
+            .select_dept_list(&Dept::from(dept_param))
+            .await
+        {
+            Ok(dept_list) => {
+                let mut wrapper = ListWrapper::new();
+                wrapper.set_success(dept_list);
+                wrapper.localize(locale)
+            },
+            Err(e) => {
+                let mut wrapper = ListWrapper::new();
+                wrapper.set_error(WrapperErrEnum::from(&e), MESSAGES.format(locale, "DEPT_LIST_QUERY_FAILED", &[&e.localize(locale)]));
+                wrapper
+            },
+        }
+    }
+
+    async fn select_dept_vo_list(&self, dept_param: DeptParam, locale: Locale) -> ListWrapper<DeptVO> {
+        // 先获取部门列表
+        let dept_result = self.select_dept_list(dept_param, locale).await;
+
+        // 如果没有数据或出现错误，直接返回转换后的结果
+        let depts = match dept_result.get_data() {
+            Some(data) => data,
+            None => return dept_result.map(|_| vec![]), // 转换为VO类型的空列表
+        };
+
+        // 获取所有部门信息，用于匹配父部门名称
+        let all_depts = self.get_dept(DeptParam::default()).await;
+
+        // 转换为VO列表
+        let dept_vos: Vec<DeptVO> = depts
+            .iter()
+            .map(|dept| {
+                // 获取父部门名称
+                let parent_name = dept.parent_id.as_ref().and_then(|parent_id| {
+                    if !parent_id.is_empty() {
+                        all_depts
+                            .get(parent_id)
+                            .and_then(|parent_dept| parent_dept.name.clone())
+                    } else {
+                        None
+                    }
+                });
+
+                Self::build_dept_vo(dept.clone(), parent_name, locale)
+            })
+            .collect();
+
+        // 创建成功响应
+        let mut wrapper = ListWrapper::new();
+        wrapper.set_success(dept_vos);
+        wrapper.localize(locale)
+    }
+
+    async fn select_dept_vo_page(&self, dept_param: DeptParam, locale: Locale) -> PageWrapper<DeptVO> {
+        let page_info = PageInfo::new(dept_param.page_param.page_num, dept_param.page_param.page_size);
+        let current_page = page_info.get_current_page_num();
+        let page_size = page_info.get_page_size();
+
+        let (depts, total) = match self
+            .repository
+            .select_dept_list_page(&Dept::from(dept_param), page_info.calculate_offset(), page_size)
+            .await
+        {
+            Ok(result) => result,
+            Err(e) => {
+                let mut wrapper = PageWrapper::new();
+                wrapper.set_error(WrapperErrEnum::from(&e), MESSAGES.format(locale, "DEPT_PAGE_QUERY_FAILED", &[&e.localize(locale)]));
+                return wrapper;
+            },
+        };
+
+        // 只查询当前页涉及的父部门，避免为匹配父部门名称加载整张部门表
+        let mut parent_names: HashMap<String, Option<String>> = HashMap::new();
+        for parent_id in depts.iter().filter_map(|dept| dept.parent_id.as_ref()) {
+            if parent_id.is_empty() || parent_names.contains_key(parent_id) {
+                continue;
+            }
+            let parent_name = match self.repository.select_by_primary_key(parent_id).await {
+                Ok(parent_dept) => parent_dept.and_then(|parent_dept| parent_dept.name),
+                Err(_) => None,
+            };
+            parent_names.insert(parent_id.clone(), parent_name);
+        }
+
+        // 转换为VO列表
+        let dept_vos: Vec<DeptVO> = depts
+            .into_iter()
+            .map(|dept| {
+                let parent_name = dept
+                    .parent_id
+                    .as_ref()
+                    .and_then(|parent_id| parent_names.get(parent_id).cloned().flatten());
+                Self::build_dept_vo(dept, parent_name, locale)
+            })
+            .collect();
+
+        let mut wrapper = PageWrapper::new();
+        wrapper.set_success(dept_vos, total, current_page, page_size);
+        wrapper.localize(locale)
+    }
+
+    async fn add_dept(&self, dept_param: DeptParam, locale: Locale) -> ResponseWrapper {
+        Self::to_response(self.try_add_dept(dept_param).await, locale)
+    }
+
+    async fn edit_dept(&self, dept_param: DeptParam, locale: Locale) -> ResponseWrapper {
+        Self::to_response(self.try_edit_dept(dept_param).await, locale)
+    }
+
+    async fn move_dept(&self, id: &str, new_parent_id: &str, locale: Locale) -> ResponseWrapper {
+        Self::to_response(self.try_move_dept(id, new_parent_id).await, locale)
+    }
+
+    async fn edit_dept_status(&self, id: &str, status: i32, locale: Locale) -> SingleWrapper<u64> {
+        let mut wrapper = SingleWrapper::new();
+        match self.try_edit_dept_status(id, status).await {
+            Ok(rows) => {
+                wrapper.set_success(rows);
+                wrapper.localize(locale)
+            },
+            Err(e) => {
+                wrapper.set_error(WrapperErrEnum::from(&e), e.localize(locale));
+                wrapper
+            },
+        }
+    }
+
+    async fn delete_dept(&self, dept_id: &str, locale: Locale) -> ResponseWrapper {
+        Self::to_response(self.try_delete_dept(dept_id).await, locale)
+    }
+
+    async fn restore_dept(&self, dept_id: &str, locale: Locale) -> ResponseWrapper {
+        Self::to_response(self.try_restore_dept(dept_id).await, locale)
+    }
+
+    async fn purge_dept(&self, dept_id: &str, locale: Locale) -> ResponseWrapper {
+        Self::to_response(self.try_purge_dept(dept_id).await, locale)
+    }
+}
@@ -0,0 +1,91 @@
+//! 部门状态策略
+//!
+//! 修改部门状态时如何对待上级、下级部门，由 [DeptServiceImpl::with_status_policy](crate::services::dept::dept_service_impl::DeptServiceImpl::with_status_policy) 设置，
+//! 各框架示例从环境变量 `DEPT_STATUS_POLICY` 读取，参见: [DeptStatusPolicy::from_env]
+
+use std::fmt;
+use std::str::FromStr;
+
+/// 设置部门状态策略的环境变量
+pub const DEPT_STATUS_POLICY_ENV: &str = "DEPT_STATUS_POLICY";
+
+/// 部门状态策略
+///
+/// 新增、编辑部门时父级部门不能为已停用部门，[DeptStatusPolicy::Strict] 在部门状态或位置变化时保持同样的约束：
+/// 停用部门时下级部门一起停用，上级部门中存在已停用部门时不能启用。
+/// 默认的 [DeptStatusPolicy::Independent] 只修改部门本身，与引入策略之前的行为一致。
+/// 启用部门时不会启用下级部门，下级部门需要逐个启用。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DeptStatusPolicy {
+    /// 只修改部门本身，不检查上级部门，也不修改下级部门
+    ///
+    /// 配置值: `independent`
+    #[default]
+    Independent,
+    /// 停用部门时同时停用全部下级部门
+    ///
+    /// 配置值: `cascade_disable`
+    CascadeDisable,
+    /// 上级部门中存在已停用部门时拒绝启用
+    ///
+    /// 配置值: `require_enabled_ancestors`
+    RequireEnabledAncestors,
+    /// 同时使用 [DeptStatusPolicy::CascadeDisable] 和 [DeptStatusPolicy::RequireEnabledAncestors]
+    ///
+    /// 配置值: `strict`
+    Strict,
+}
+
+impl DeptStatusPolicy {
+    /// 停用部门时是否同时停用全部下级部门
+    pub const fn cascade_disable(&self) -> bool {
+        matches!(self, Self::CascadeDisable | Self::Strict)
+    }
+
+    /// 启用部门时是否要求全部上级部门均未停用
+    pub const fn require_enabled_ancestors(&self) -> bool {
+        matches!(self, Self::RequireEnabledAncestors | Self::Strict)
+    }
+
+    /// 配置值
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Independent => "independent",
+            Self::CascadeDisable => "cascade_disable",
+            Self::RequireEnabledAncestors => "require_enabled_ancestors",
+            Self::Strict => "strict",
+        }
+    }
+
+    /// 从环境变量 `DEPT_STATUS_POLICY` 读取部门状态策略
+    ///
+    /// # 返回值
+    /// 未设置时返回默认策略，取值无效时返回错误信息
+    pub fn from_env() -> Result<Self, String> {
+        match std::env::var(DEPT_STATUS_POLICY_ENV) {
+            Ok(policy) => policy.parse(),
+            Err(_) => Ok(Self::default()),
+        }
+    }
+}
+
+impl FromStr for DeptStatusPolicy {
+    type Err = String;
+
+    /// 按配置值解析，忽略首尾空格及大小写
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "independent" => Ok(Self::Independent),
+            "cascade_disable" => Ok(Self::CascadeDisable),
+            "require_enabled_ancestors" => Ok(Self::RequireEnabledAncestors),
+            "strict" => Ok(Self::Strict),
+            _ => Err(format!("不支持的部门状态策略: {s}，可选值为 independent、cascade_disable、require_enabled_ancestors、strict")),
+        }
+    }
+}
+
+impl fmt::Display for DeptStatusPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
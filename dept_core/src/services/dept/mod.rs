@@ -2,3 +2,4 @@
 
 pub mod dept_service;
 pub mod dept_service_impl;
+pub mod dept_status_policy;
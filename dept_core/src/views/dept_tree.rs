@@ -0,0 +1,36 @@
+//! 部门树节点视图定义
+//!
+//! 该模块定义了用于构建部门树形结构的视图对象。
+
+use serde::{Deserialize, Serialize};
+
+/// 部门树节点
+///
+/// 状态、显示顺序、子部门数量及是否禁用选择为节点详情，只在请求参数 `detail` 为 `true` 时返回
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeptTree {
+    /// 部门ID
+    pub id: String,
+    /// 上级部门ID
+    pub parent_id: Option<String>,
+    /// 部门名称
+    pub name: Option<String>,
+    /// 是否满足查询条件，为 `false` 时该部门只是作为满足条件的部门的上级部门保留在树中
+    #[serde(default)]
+    pub matched: bool,
+    /// 部门状态
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<i32>,
+    /// 显示顺序
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seq_no: Option<i32>,
+    /// 直接子部门数量，按全部部门统计，不受查询条件及层数限制影响
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub child_count: Option<u64>,
+    /// 是否禁用选择，部门已停用时不能选作父级部门
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disabled: Option<bool>,
+    /// 子部门列表
+    pub children: Vec<DeptTree>,
+}
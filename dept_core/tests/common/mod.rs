@@ -0,0 +1,204 @@
+//! 集成测试共用的辅助代码
+//!
+//! 提供测试期间临时创建的 PostgreSQL 实例（需要 `initdb`、`pg_ctl` 在 PATH 中，或通过 `PG_BIN` 指定所在目录），
+//! 设置了 `TEST_DATABASE_URL` 时直接使用该数据库，不再创建实例；
+//! 以及各数据访问层实现共用的检查，内存实现与数据库实现的测试分别调用，每个测试文件只使用其中一部分。
+#![allow(dead_code)]
+
+use common_wrapper::ResponseTrait;
+use common_wrapper::i18n::Locale;
+use dept_core::models::Dept;
+use dept_core::params::dept_param::DeptParam;
+use dept_core::params::page_param::PageParam;
+use dept_core::params::tree_param::TreeParam;
+use dept_core::repositories::dept::dept_repository::{DeptQueryOptions, DeptRepository};
+use dept_core::services::dept::dept_service::DeptService;
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+/// 测试期间临时创建的 PostgreSQL 实例，离开作用域时停止并删除数据目录
+pub struct PostgresInstance {
+    pg_ctl: PathBuf,
+    data_dir: PathBuf,
+}
+
+impl PostgresInstance {
+    /// 在临时目录中初始化并启动实例，返回实例及其连接地址；找不到或无法运行 PostgreSQL 时返回 None
+    pub fn start() -> Option<(Self, String)> {
+        let bin_dir = std::env::var("PG_BIN")
+            .map(PathBuf::from)
+            .unwrap_or_default();
+        let initdb = bin_dir.join("initdb");
+        let pg_ctl = bin_dir.join("pg_ctl");
+        let data_dir = std::env::temp_dir().join(format!("dept_core_pg_{}", std::process::id()));
+        let port = free_port();
+
+        let initialized = Command::new(&initdb)
+            .args(["-U", "postgres", "--auth=trust", "-E", "UTF8", "--no-locale", "-D"])
+            .arg(&data_dir)
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .is_ok_and(|status| status.success());
+        if !initialized {
+            let _ = std::fs::remove_dir_all(&data_dir);
+            return None;
+        }
+
+        let instance = Self { pg_ctl, data_dir };
+        // 只监听本机TCP端口，不创建Unix套接字（默认的套接字目录通常需要额外权限）
+        let started = Command::new(&instance.pg_ctl)
+            .args(["start", "-w", "-o"])
+            .arg(format!("-p {port} -h 127.0.0.1 -k ''"))
+            .arg("-D")
+            .arg(&instance.data_dir)
+            .arg("-l")
+            .arg(instance.data_dir.join("postgres.log"))
+            .stdout(Stdio::null())
+            .status()
+            .is_ok_and(|status| status.success());
+        if !started {
+            return None;
+        }
+
+        Some((instance, format!("postgres://postgres@127.0.0.1:{port}/postgres")))
+    }
+}
+
+impl Drop for PostgresInstance {
+    fn drop(&mut self) {
+        let _ = Command::new(&self.pg_ctl)
+            .args(["stop", "-m", "fast", "-D"])
+            .arg(&self.data_dir)
+            .stdout(Stdio::null())
+            .status();
+        let _ = std::fs::remove_dir_all(&self.data_dir);
+    }
+}
+
+/// 获取测试用的 PostgreSQL 数据库
+///
+/// 设置了 `TEST_DATABASE_URL` 时直接返回该地址，否则临时创建实例，实例须保留到测试结束；
+/// 两者都不可用时返回 None，调用方应跳过测试
+pub fn postgres_database() -> Option<(Option<PostgresInstance>, String)> {
+    if let Ok(url) = std::env::var("TEST_DATABASE_URL") {
+        return Some((None, url));
+    }
+    PostgresInstance::start().map(|(instance, url)| (Some(instance), url))
+}
+
+/// 向系统申请一个当前空闲的端口
+fn free_port() -> u16 {
+    TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port()
+}
+
+/// 检查彻底删除只作用于已删除的部门
+///
+/// 模拟彻底删除部门时，检查部门已删除之后、执行删除语句之前部门被并发恢复的情况，部门应保留下来
+pub async fn check_purge_restored_dept(repository: &dyn DeptRepository) {
+    let dept = Dept {
+        id: "purge_restored_dept".to_string(),
+        name: Some("彻底删除测试部门".to_string()),
+        ancestors: Some("/".to_string()),
+        ..Default::default()
+    };
+    repository.insert(&dept).await.unwrap();
+    repository.delete_by_primary_key(&dept.id).await.unwrap();
+
+    // 检查时部门已删除
+    let condition = Dept { id: dept.id.clone(), ..Default::default() };
+    let depts = repository
+        .select_dept_list_with_options(&condition, DeptQueryOptions::include_deleted())
+        .await
+        .unwrap();
+    assert!(depts[0].deleted_at.is_some());
+
+    // 检查之后部门被恢复，彻底删除不影响任何行
+    assert_eq!(repository.restore_by_primary_key(&dept.id).await.unwrap(), 1);
+    assert_eq!(repository.purge_by_primary_key(&dept.id).await.unwrap(), 0);
+    assert!(
+        repository
+            .select_by_primary_key(&dept.id)
+            .await
+            .unwrap()
+            .is_some()
+    );
+
+    // 再次删除后可以彻底删除
+    repository.delete_by_primary_key(&dept.id).await.unwrap();
+    assert_eq!(repository.purge_by_primary_key(&dept.id).await.unwrap(), 1);
+    let depts = repository
+        .select_dept_list_with_options(&condition, DeptQueryOptions::include_deleted())
+        .await
+        .unwrap();
+    assert!(depts.is_empty());
+}
+
+/// 检查分页查询与部门树的显示顺序一致
+///
+/// 演示数据中 `XXX分公司` 下只有未设置排序号的 `运维`，在其下新增排序号为2、1的部门后，
+/// 分页查询与部门树都按排序号升序排列，未设置排序号的 `运维` 排在最后；检查完成后删除新增的部门
+pub async fn check_display_order(service: &dyn DeptService) {
+    const BRANCH_OFFICE_ID: &str = "a308936d3b5d49e185fe15bf7d71b688";
+    for (name, seq_no) in [("排序测试部门2", 2), ("排序测试部门1", 1)] {
+        let param = DeptParam {
+            parent_id: Some(BRANCH_OFFICE_ID.to_string()),
+            name: Some(name.to_string()),
+            seq_no: Some(seq_no),
+            status: Some(1),
+            ..Default::default()
+        };
+        let added = service.add_dept(param, Locale::default()).await;
+        assert!(added.is_success(), "{}", added.get_message());
+    }
+    let expected = ["排序测试部门1", "排序测试部门2", "运维"];
+
+    let page = service
+        .select_dept_vo_page(
+            DeptParam {
+                parent_id: Some(BRANCH_OFFICE_ID.to_string()),
+                page_param: PageParam { page_num: Some(1), page_size: Some(10) },
+                ..Default::default()
+            },
+            Locale::default(),
+        )
+        .await;
+    assert!(page.is_success(), "{}", page.get_message());
+    let page = page.get_data().as_deref().unwrap_or_default();
+    let names: Vec<_> = page
+        .iter()
+        .map(|vo| vo.base.name.as_deref().unwrap_or_default())
+        .collect();
+    assert_eq!(names, expected);
+
+    let tree = service
+        .get_dept_tree(
+            DeptParam {
+                tree_param: TreeParam {
+                    root_id: Some(BRANCH_OFFICE_ID.to_string()),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            Locale::default(),
+        )
+        .await;
+    assert!(tree.is_success(), "{}", tree.get_message());
+    let tree = tree.get_data().as_deref().unwrap_or_default();
+    let names: Vec<_> = tree[0]
+        .children
+        .iter()
+        .map(|node| node.name.as_deref().unwrap_or_default())
+        .collect();
+    assert_eq!(names, expected);
+
+    for vo in page.iter().filter(|vo| vo.base.seq_no.is_some()) {
+        let deleted = service.delete_dept(&vo.base.id, Locale::default()).await;
+        assert!(deleted.is_success(), "{}", deleted.get_message());
+    }
+}
@@ -0,0 +1,90 @@
+//! 错误类型测试
+//!
+//! 验证业务错误及数据访问层错误的错误码，以及到响应码的映射
+
+use common_wrapper::enums::wrapper_err::WrapperErrEnum;
+use common_wrapper::i18n::{Locale, Localize};
+use common_wrapper::{ResponseTrait, ResponseWrapper};
+use dept_core::errors::{DeptError, RepositoryError};
+
+/// 测试错误码
+#[test]
+fn test_error_code() {
+    assert_eq!(DeptError::InvalidParam("部门ID不能为空".to_string()).code(), "DEPT_INVALID_PARAM");
+    assert_eq!(DeptError::NotFound.code(), "DEPT_NOT_FOUND");
+    assert_eq!(DeptError::ParentNotFound.code(), "DEPT_PARENT_NOT_FOUND");
+    assert_eq!(DeptError::ParentDisabled.code(), "DEPT_PARENT_DISABLED");
+    assert_eq!(DeptError::DuplicateName.code(), "DEPT_DUPLICATE_NAME");
+    assert_eq!(DeptError::HasChildren.code(), "DEPT_HAS_CHILDREN");
+    assert_eq!(DeptError::CyclicParent.code(), "DEPT_CYCLIC_PARENT");
+    assert_eq!(DeptError::AncestorDisabled.code(), "DEPT_ANCESTOR_DISABLED");
+    assert_eq!(DeptError::NotDeleted.code(), "DEPT_NOT_DELETED");
+
+    // 数据访问层错误沿用数据访问层的错误码
+    let e = DeptError::from(RepositoryError::Transaction("当前仓储未开启事务".to_string()));
+    assert_eq!(e.code(), "REPOSITORY_TRANSACTION");
+    assert_eq!(RepositoryError::Duplicate("id".to_string()).code(), "REPOSITORY_DUPLICATE");
+    assert_eq!(RepositoryError::Connection("连接超时".into()).code(), "REPOSITORY_CONNECTION");
+    assert_eq!(RepositoryError::Database("语法错误".into()).code(), "REPOSITORY_DATABASE");
+}
+
+/// 测试错误到响应码的映射
+#[test]
+fn test_wrapper_err() {
+    assert_eq!(WrapperErrEnum::from(&DeptError::InvalidParam(String::new())), WrapperErrEnum::InvalidParam);
+    assert_eq!(WrapperErrEnum::from(&DeptError::ParentNotFound), WrapperErrEnum::InvalidParam);
+    assert_eq!(WrapperErrEnum::from(&DeptError::NotFound), WrapperErrEnum::NotFound);
+    assert_eq!(WrapperErrEnum::from(&DeptError::ParentDisabled), WrapperErrEnum::Conflict);
+    assert_eq!(WrapperErrEnum::from(&DeptError::DuplicateName), WrapperErrEnum::Conflict);
+    assert_eq!(WrapperErrEnum::from(&DeptError::HasChildren), WrapperErrEnum::Conflict);
+    assert_eq!(WrapperErrEnum::from(&DeptError::CyclicParent), WrapperErrEnum::Conflict);
+    assert_eq!(WrapperErrEnum::from(&DeptError::AncestorDisabled), WrapperErrEnum::Conflict);
+    assert_eq!(WrapperErrEnum::from(&DeptError::NotDeleted), WrapperErrEnum::Conflict);
+    assert_eq!(WrapperErrEnum::from(&DeptError::from(RepositoryError::Duplicate(String::new()))), WrapperErrEnum::Conflict);
+    assert_eq!(WrapperErrEnum::from(&DeptError::from(RepositoryError::Connection("连接超时".into()))), WrapperErrEnum::ServiceUnavailable);
+    assert_eq!(WrapperErrEnum::from(&DeptError::from(RepositoryError::Database("语法错误".into()))), WrapperErrEnum::DatabaseError);
+}
+
+/// 测试错误转换为响应
+#[test]
+fn test_response_wrapper() {
+    let response = ResponseWrapper::from(DeptError::NotFound);
+    assert_eq!(response.get_code(), WrapperErrEnum::NotFound as i32);
+    assert_eq!(response.get_message(), "传入的部门信息不存在!");
+    assert!(!response.is_success());
+
+    let response = ResponseWrapper::from(DeptError::InvalidParam("部门状态不能为空!".to_string()));
+    assert_eq!(response.get_code(), WrapperErrEnum::InvalidParam as i32);
+    assert_eq!(response.get_message(), "部门状态不能为空!");
+}
+
+/// 测试错误描述的多语言消息
+#[test]
+fn test_localize() {
+    // 参数错误保存消息编码，错误描述为简体中文
+    let e = DeptError::InvalidParam("DEPT_ID_REQUIRED".to_string());
+    assert_eq!(e.to_string(), "部门ID不能为空!");
+    assert_eq!(e.localize(Locale::EnUs), "Department ID must not be empty!");
+    // 不是消息编码时原样输出
+    assert_eq!(DeptError::InvalidParam("名称过长".to_string()).localize(Locale::EnUs), "名称过长");
+
+    assert_eq!(DeptError::HasChildren.localize(Locale::ZhCn), DeptError::HasChildren.to_string());
+    assert_eq!(DeptError::CyclicParent.localize(Locale::ZhCn), DeptError::CyclicParent.to_string());
+    assert_eq!(DeptError::AncestorDisabled.localize(Locale::ZhCn), DeptError::AncestorDisabled.to_string());
+    assert_eq!(DeptError::NotDeleted.localize(Locale::ZhCn), DeptError::NotDeleted.to_string());
+    let e = DeptError::from(RepositoryError::Duplicate("id".to_string()));
+    assert_eq!(e.localize(Locale::ZhCn), e.to_string());
+    assert_eq!(e.localize(Locale::EnUs), "Data already exists: id");
+    let e = RepositoryError::Connection("连接超时".into());
+    assert_eq!(e.to_string(), "数据库连接失败: 连接超时");
+    assert_eq!(e.localize(Locale::ZhCn), e.to_string());
+
+    // 父部门为部门本身的参数错误与其他消息一样以 `!` 结尾
+    let e = DeptError::InvalidParam("DEPT_PARENT_IS_SELF".to_string());
+    assert_eq!(e.to_string(), "父部门不能为部门本身!");
+    assert_eq!(e.localize(Locale::EnUs), "The parent department cannot be the department itself!");
+
+    let response = DeptError::NotFound.to_response(Locale::EnUs);
+    assert_eq!(response.get_code(), WrapperErrEnum::NotFound as i32);
+    assert_eq!(response.get_message(), "The department does not exist!");
+}
@@ -0,0 +1,82 @@
+//! 数据库迁移测试
+//!
+//! 验证 `migrate` 子命令的参数解析，以及通过记录已应用版本的执行器验证版本校验
+//!
+//! 使用方法：`cargo test -p dept_core --features sqlx_impl --test migrations_test`
+#![cfg(any(feature = "sqlx_impl", feature = "diesel_impl", feature = "seaorm_impl"))]
+
+use async_trait::async_trait;
+use dept_core::config::DatabaseType;
+use dept_core::migrations::{MIGRATIONS, MigrateCommand, MigrationExecutor, Migrator};
+use std::error::Error as StdError;
+use std::num::NonZeroUsize;
+
+/// 只返回指定已应用版本的迁移执行器，不执行任何脚本
+struct AppliedVersions(Vec<i64>);
+
+#[async_trait]
+impl MigrationExecutor for AppliedVersions {
+    fn database_type(&self) -> DatabaseType {
+        DatabaseType::MySql
+    }
+
+    async fn execute_script(&self, _script: &str) -> Result<(), Box<dyn StdError + Send + Sync>> {
+        Ok(())
+    }
+
+    async fn select_applied_versions(&self) -> Result<Vec<i64>, Box<dyn StdError + Send + Sync>> {
+        Ok(self.0.clone())
+    }
+}
+
+/// 解析 `migrate` 子命令
+fn parse(args: &[&str]) -> Result<Option<MigrateCommand>, String> {
+    MigrateCommand::from_args(args.iter().map(|arg| arg.to_string()))
+}
+
+/// 测试解析迁移子命令
+#[test]
+fn test_from_args() {
+    assert_eq!(parse(&[]), Ok(None));
+    assert_eq!(parse(&["--help"]), Ok(None));
+    assert_eq!(parse(&["migrate"]), Ok(Some(MigrateCommand::Up)));
+    assert_eq!(parse(&["migrate", "up"]), Ok(Some(MigrateCommand::Up)));
+    assert_eq!(parse(&["migrate", "down"]), Ok(Some(MigrateCommand::Down(NonZeroUsize::MIN))));
+    assert_eq!(parse(&["migrate", "down", "3"]), Ok(Some(MigrateCommand::Down(NonZeroUsize::new(3).unwrap()))));
+    assert_eq!(parse(&["migrate", "status"]), Ok(Some(MigrateCommand::Status)));
+    assert_eq!(parse(&["migrate", "check"]), Ok(Some(MigrateCommand::Check)));
+
+    // 回滚个数必须是正整数
+    for steps in ["0", "-1", "abc"] {
+        assert!(parse(&["migrate", "down", steps]).is_err(), "{steps}");
+    }
+    assert!(parse(&["migrate", "redo"]).is_err());
+}
+
+/// 测试校验数据库版本
+#[tokio::test]
+async fn test_check() {
+    let versions: Vec<i64> = MIGRATIONS
+        .iter()
+        .map(|migration| migration.version)
+        .collect();
+
+    // 与当前程序一致
+    let executor = AppliedVersions(versions.clone());
+    Migrator::new(&executor).check().await.unwrap();
+
+    // 数据库落后于当前程序
+    let executor = AppliedVersions(versions[..versions.len() - 1].to_vec());
+    assert!(Migrator::new(&executor).check().await.is_err());
+
+    // 数据库中存在当前程序没有的迁移，如更新的版本或其他分支的迁移
+    let mut newer = versions.clone();
+    newer.push(versions.last().unwrap() + 1);
+    let executor = AppliedVersions(newer);
+    let e = Migrator::new(&executor).check().await.unwrap_err();
+    assert!(
+        e.to_string()
+            .contains(&(versions.last().unwrap() + 1).to_string()),
+        "{e}"
+    );
+}
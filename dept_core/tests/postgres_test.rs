@@ -0,0 +1,276 @@
+//! PostgreSQL 集成测试
+//!
+//! 测试启动时会临时创建一个本地 PostgreSQL 实例（参见 [common::PostgresInstance]），依次以启用的每种ORM实现
+//! 连接该实例，回滚并重新应用全部迁移后，通过部门服务验证分页、增删改查、移动及并发新增，最后验证迁移的升级、回滚。
+//!
+//! 如果已有可用的 PostgreSQL，可以通过 `TEST_DATABASE_URL` 指定连接地址跳过实例创建，
+//! 注意测试会回滚全部迁移（删除 `sys_dept` 等表）后重新建表并导入演示数据。
+//!
+//! 使用方法：`cargo test -p dept_core --features sqlx_impl,seaorm_impl,postgres --test postgres_test`
+#![cfg(all(feature = "postgres", any(feature = "sqlx_impl", feature = "diesel_impl", feature = "seaorm_impl")))]
+
+mod common;
+
+use common_wrapper::ResponseTrait;
+use common_wrapper::i18n::Locale;
+use dept_core::migrations::{MigrationExecutor, Migrator};
+use dept_core::models::Dept;
+use dept_core::params::dept_param::DeptParam;
+use dept_core::params::page_param::PageParam;
+use dept_core::repositories::dept::dept_repository::DeptRepository;
+use dept_core::services::dept::dept_service::DeptService;
+use dept_core::services::dept::dept_service_impl::DeptServiceImpl;
+use std::sync::Arc;
+
+/// 测试用的部门，挂在已启用的 `XXX分公司` 下
+const PARENT_ID: &str = "a308936d3b5d49e185fe15bf7d71b688";
+
+/// 移动测试部门的目标父部门 `研发部`
+const NEW_PARENT_ID: &str = "88921a7b70a04ea2b7d1db1651ffe666";
+
+/// 按名称查询部门VO列表，返回部门及其父部门名称
+async fn list_by_name(service: &DeptServiceImpl, name: &str) -> Vec<(Dept, Option<String>)> {
+    let result = service
+        .select_dept_vo_list(DeptParam { name: Some(name.to_string()), ..Default::default() }, Locale::default())
+        .await;
+    assert!(result.is_success(), "{}", result.get_message());
+    result
+        .get_data()
+        .iter()
+        .flatten()
+        .map(|vo| (vo.base.clone(), vo.parent_name.clone()))
+        .collect()
+}
+
+/// 新增部门的参数
+fn new_dept(name: &str) -> DeptParam {
+    DeptParam {
+        parent_id: Some(PARENT_ID.to_string()),
+        name: Some(name.to_string()),
+        seq_no: Some(1),
+        status: Some(1),
+        ..Default::default()
+    }
+}
+
+/// 在已连接 PostgreSQL 的仓储上验证部门的分页、增删改查及迁移的升级、回滚
+async fn check_dept_crud<R>(repository: Arc<R>)
+where
+    R: DeptRepository + MigrationExecutor + 'static,
+{
+    let migrator = Migrator::new(repository.as_ref());
+    // 回滚全部迁移后重新建表并导入演示数据
+    migrator.down(usize::MAX).await.unwrap();
+    migrator.up().await.unwrap();
+    let service = Arc::new(DeptServiceImpl::new(repository.clone()));
+
+    // 分页查询初始化数据
+    let page = service
+        .select_dept_vo_page(
+            DeptParam {
+                page_param: PageParam { page_num: Some(2), page_size: Some(3) },
+                ..Default::default()
+            },
+            Locale::default(),
+        )
+        .await;
+    assert!(page.is_success(), "{}", page.get_message());
+    assert_eq!(page.get_total(), 8);
+    assert_eq!(page.get_data().as_ref().unwrap().len(), 3);
+
+    // 分页查询与部门树的显示顺序一致
+    common::check_display_order(service.as_ref()).await;
+
+    // 新增
+    let added = service
+        .add_dept(new_dept("PG测试部门"), Locale::default())
+        .await;
+    assert!(added.is_success(), "{}", added.get_message());
+    let depts = list_by_name(&service, "PG测试部门").await;
+    assert_eq!(depts.len(), 1);
+    let id = depts[0].0.id.clone();
+    assert_eq!(id.len(), 32);
+    assert_eq!(depts[0].1.as_deref(), Some("XXX分公司"));
+
+    // 修改
+    let edited = service
+        .edit_dept(
+            DeptParam {
+                id: Some(id.clone()),
+                name: Some("PG测试部门2".to_string()),
+                seq_no: Some(2),
+                ..new_dept("")
+            },
+            Locale::default(),
+        )
+        .await;
+    assert!(edited.is_success(), "{}", edited.get_message());
+    let depts = list_by_name(&service, "PG测试部门2").await;
+    assert_eq!(depts.len(), 1);
+    assert_eq!(depts[0].0.seq_no, Some(2));
+
+    // 移动部门，下级部门的祖级路径随之改写
+    let child = service
+        .add_dept(
+            DeptParam {
+                parent_id: Some(id.clone()),
+                ..new_dept("PG测试下级部门")
+            },
+            Locale::default(),
+        )
+        .await;
+    assert!(child.is_success(), "{}", child.get_message());
+    // 部门id只有大小写不同的部门及其下级部门不属于同一棵子树，不随之移动
+    let dept = list_by_name(&service, "PG测试部门2").await.remove(0).0;
+    let upper = Dept {
+        id: id.to_uppercase(),
+        parent_id: dept.parent_id.clone(),
+        name: Some("PG大写部门".to_string()),
+        ancestors: dept.ancestors.clone(),
+        ..Default::default()
+    };
+    repository.insert(&upper).await.unwrap();
+    let upper_child = Dept {
+        id: "pg_upper_child".to_string(),
+        parent_id: Some(upper.id.clone()),
+        name: Some("PG大写部门下级部门".to_string()),
+        ancestors: Some(upper.path()),
+        ..Default::default()
+    };
+    repository.insert(&upper_child).await.unwrap();
+    let moved = service
+        .move_dept(&id, NEW_PARENT_ID, Locale::default())
+        .await;
+    assert!(moved.is_success(), "{}", moved.get_message());
+    let new_parent = repository
+        .select_by_primary_key(NEW_PARENT_ID)
+        .await
+        .unwrap()
+        .unwrap();
+    let dept = &list_by_name(&service, "PG测试部门2").await[0].0;
+    assert_eq!(dept.ancestors.as_deref(), Some(new_parent.path().as_str()));
+    let child = list_by_name(&service, "PG测试下级部门").await.remove(0).0;
+    assert_eq!(child.ancestors, Some(dept.path()));
+    let descendants = repository.select_descendants(&id).await.unwrap();
+    assert_eq!(
+        descendants
+            .iter()
+            .map(|dept| dept.id.as_str())
+            .collect::<Vec<_>>(),
+        [child.id.as_str()]
+    );
+    let upper_child_ancestors = repository
+        .select_by_primary_key(&upper_child.id)
+        .await
+        .unwrap()
+        .and_then(|dept| dept.ancestors);
+    assert_eq!(upper_child_ancestors, upper_child.ancestors);
+    let deleted = service.delete_dept(&child.id, Locale::default()).await;
+    assert!(deleted.is_success(), "{}", deleted.get_message());
+
+    // 修改状态
+    let disabled = service.edit_dept_status(&id, 0, Locale::default()).await;
+    assert!(disabled.is_success(), "{}", disabled.get_message());
+    assert_eq!(list_by_name(&service, "PG测试部门2").await[0].0.status, Some(0));
+
+    // 部门树
+    let tree = service
+        .get_dept_tree(DeptParam::default(), Locale::default())
+        .await;
+    assert!(tree.is_success(), "{}", tree.get_message());
+
+    // 删除
+    let deleted = service.delete_dept(&id, Locale::default()).await;
+    assert!(deleted.is_success(), "{}", deleted.get_message());
+    assert!(list_by_name(&service, "PG测试部门").await.is_empty());
+
+    // 彻底删除只作用于已删除的部门
+    common::check_purge_restored_dept(repository.as_ref()).await;
+
+    // 并发新增同名部门，父部门在事务中被锁定到提交，只有一个请求能通过名称唯一性校验
+    let handles: Vec<_> = (0..5)
+        .map(|_| {
+            let service = service.clone();
+            tokio::spawn(async move {
+                service
+                    .add_dept(new_dept("PG并发部门"), Locale::default())
+                    .await
+            })
+        })
+        .collect();
+    let mut succeeded = 0;
+    for handle in handles {
+        let result = handle.await.unwrap();
+        if result.is_success() {
+            succeeded += 1;
+        } else {
+            // 其余请求因名称重复返回冲突
+            assert_eq!(result.get_code(), -5, "{}", result.get_message());
+        }
+    }
+    assert_eq!(succeeded, 1);
+    assert_eq!(list_by_name(&service, "PG并发部门").await.len(), 1);
+
+    // 回滚全部迁移后版本校验失败，升级后恢复一致
+    migrator.down(usize::MAX).await.unwrap();
+    assert!(migrator.check().await.is_err());
+    migrator.up().await.unwrap();
+    migrator.check().await.unwrap();
+    assert!(
+        migrator
+            .status()
+            .await
+            .unwrap()
+            .iter()
+            .all(|status| status.applied)
+    );
+
+    // 回滚最近一个迁移后校验失败，重新升级即可恢复
+    assert_eq!(migrator.down(1).await.unwrap().len(), 1);
+    assert!(migrator.check().await.is_err());
+    assert_eq!(migrator.up().await.unwrap().len(), 1);
+    migrator.check().await.unwrap();
+
+    // 数据库中存在当前程序没有的迁移时校验失败
+    repository
+        .execute_script("INSERT INTO schema_migrations (version, name) VALUES (9999, '9999_unknown')")
+        .await
+        .unwrap();
+    assert!(migrator.check().await.is_err());
+    repository
+        .execute_script("DELETE FROM schema_migrations WHERE version = 9999")
+        .await
+        .unwrap();
+    migrator.check().await.unwrap();
+}
+
+/// 测试各ORM实现在 PostgreSQL 上的分页、增删改查及迁移
+///
+/// 各实现共用同一个数据库，依次执行
+#[tokio::test(flavor = "multi_thread")]
+async fn test_dept_crud_on_postgres() {
+    let Some((_instance, database_url)) = common::postgres_database() else {
+        println!("未找到可用的 PostgreSQL（initdb/pg_ctl），跳过测试；可设置 PG_BIN 或 TEST_DATABASE_URL");
+        return;
+    };
+    // 各实现从配置文件读取数据库URL，由环境变量覆盖为测试数据库，本文件只有这一个测试，不会与其他线程同时读写环境变量
+    unsafe { std::env::set_var("DATABASE_URL", &database_url) };
+
+    #[cfg(feature = "sqlx_impl")]
+    {
+        use dept_core::repositories::dept::sqlx_impl::DeptRepositorySqlxImpl;
+        check_dept_crud(Arc::new(DeptRepositorySqlxImpl::new().await.unwrap())).await;
+    }
+
+    #[cfg(feature = "diesel_impl")]
+    {
+        use dept_core::repositories::dept::diesel_impl::DeptRepositoryDieselImpl;
+        check_dept_crud(Arc::new(DeptRepositoryDieselImpl::new().unwrap())).await;
+    }
+
+    #[cfg(feature = "seaorm_impl")]
+    {
+        use dept_core::repositories::dept::seaorm_impl::DeptRepositorySeaormImpl;
+        check_dept_crud(Arc::new(DeptRepositorySeaormImpl::new().await.unwrap())).await;
+    }
+}
@@ -0,0 +1,25 @@
+//! 部门数据访问层内存实现的测试
+//!
+//! 在内存实现上运行各数据访问层实现共用的检查（参见 `tests/common`），数据库实现由对应数据库的集成测试调用
+//!
+//! 使用方法：`cargo test -p dept_core --features memory_impl --test repository_test`
+#![cfg(feature = "memory_impl")]
+
+mod common;
+
+use dept_core::repositories::dept::memory_impl::DeptRepositoryMemoryImpl;
+use dept_core::services::dept::dept_service_impl::DeptServiceImpl;
+use std::sync::Arc;
+
+/// 测试彻底删除检查之后被恢复的部门时部门保留下来
+#[tokio::test]
+async fn test_purge_restored_dept() {
+    common::check_purge_restored_dept(&DeptRepositoryMemoryImpl::with_demo_data()).await;
+}
+
+/// 测试分页查询与部门树的显示顺序一致
+#[tokio::test]
+async fn test_display_order() {
+    let service = DeptServiceImpl::new(Arc::new(DeptRepositoryMemoryImpl::with_demo_data()));
+    common::check_display_order(&service).await;
+}
@@ -0,0 +1,234 @@
+//! 部门服务按部门状态策略修改部门的测试
+//!
+//! 基于内存实现的演示数据，验证各策略在停用部门时是否级联停用下级部门，
+//! 以及在上级部门已停用时是否拒绝启用、新增、编辑、移动及恢复启用的部门
+//!
+//! 使用方法：`cargo test -p dept_core --features memory_impl --test status_policy_service_test`
+#![cfg(feature = "memory_impl")]
+
+use common_wrapper::ResponseTrait;
+use common_wrapper::i18n::Locale;
+use dept_core::models::Dept;
+use dept_core::params::dept_param::DeptParam;
+use dept_core::repositories::dept::dept_repository::DeptRepository;
+use dept_core::repositories::dept::memory_impl::DeptRepositoryMemoryImpl;
+use dept_core::services::dept::dept_service::DeptService;
+use dept_core::services::dept::dept_service_impl::DeptServiceImpl;
+use dept_core::services::dept::dept_status_policy::DeptStatusPolicy;
+use std::sync::Arc;
+
+/// 根部门 `NB PLUS 科技`
+const ROOT_ID: &str = "065a3eb180214ccfbb653f63287d285d";
+/// `XXX总公司`，挂在根部门下
+const HEAD_OFFICE_ID: &str = "6bcbfa6a71fa454d8363b885ccbcb30a";
+/// 已启用的 `研发部`，挂在 `XXX总公司` 下
+const RD_DEPT_ID: &str = "88921a7b70a04ea2b7d1db1651ffe666";
+/// 已启用的 `XXX分公司`，挂在根部门下
+const BRANCH_OFFICE_ID: &str = "a308936d3b5d49e185fe15bf7d71b688";
+/// 已停用的 `运维`，挂在 `XXX分公司` 下
+const OPS_DEPT_ID: &str = "ebd8118fe94247809abab8c72579cf7d";
+
+/// 全部部门状态策略
+const POLICIES: [DeptStatusPolicy; 4] = [DeptStatusPolicy::Independent, DeptStatusPolicy::CascadeDisable, DeptStatusPolicy::RequireEnabledAncestors, DeptStatusPolicy::Strict];
+
+/// 创建基于演示数据、使用指定策略的部门服务，同时返回仓储以便直接读写部门
+fn dept_service(policy: DeptStatusPolicy) -> (Arc<dyn DeptRepository>, DeptServiceImpl) {
+    let repository = Arc::new(DeptRepositoryMemoryImpl::with_demo_data()) as Arc<dyn DeptRepository>;
+    let service = DeptServiceImpl::new(repository.clone()).with_status_policy(policy);
+    (repository, service)
+}
+
+/// 绕过部门服务直接修改部门状态，用于构造上级部门已停用的场景
+async fn set_status(repository: &dyn DeptRepository, id: &str, status: i32) {
+    let dept = Dept {
+        id: id.to_string(),
+        status: Some(status),
+        ..Default::default()
+    };
+    repository
+        .update_by_primary_key_selective(&dept)
+        .await
+        .unwrap();
+}
+
+/// 查询部门
+async fn dept(repository: &dyn DeptRepository, id: &str) -> Dept {
+    repository.select_by_primary_key(id).await.unwrap().unwrap()
+}
+
+/// 编辑 `XXX分公司` 的参数
+fn branch_office(parent_id: &str, status: i32) -> DeptParam {
+    DeptParam {
+        id: Some(BRANCH_OFFICE_ID.to_string()),
+        parent_id: Some(parent_id.to_string()),
+        name: Some("XXX分公司".to_string()),
+        status: Some(status),
+        ..Default::default()
+    }
+}
+
+/// 测试停用部门时按策略级联停用下级部门
+#[tokio::test]
+async fn test_disable_cascade() {
+    for policy in POLICIES {
+        // 修改状态停用 `XXX总公司`，下级部门中只有 `研发部` 尚未停用
+        let (repository, service) = dept_service(policy);
+        let result = service
+            .edit_dept_status(HEAD_OFFICE_ID, 0, Locale::default())
+            .await;
+        assert!(result.is_success(), "{policy}: {}", result.get_message());
+        let expected = if policy.cascade_disable() { (Some(2), Some(0)) } else { (Some(1), Some(1)) };
+        assert_eq!((*result.get_data(), dept(repository.as_ref(), RD_DEPT_ID).await.status), expected, "{policy}");
+
+        // 编辑部门停用 `XXX分公司` 时同样按策略处理下级部门
+        set_status(repository.as_ref(), OPS_DEPT_ID, 1).await;
+        let result = service
+            .edit_dept(branch_office(ROOT_ID, 0), Locale::default())
+            .await;
+        assert!(result.is_success(), "{policy}: {}", result.get_message());
+        let expected = if policy.cascade_disable() { Some(0) } else { Some(1) };
+        assert_eq!(dept(repository.as_ref(), OPS_DEPT_ID).await.status, expected, "{policy}");
+    }
+}
+
+/// 测试上级部门已停用时按策略拒绝启用部门及新增启用的部门
+#[tokio::test]
+async fn test_enable_under_disabled_ancestor() {
+    for policy in POLICIES {
+        // 根部门已停用，`运维` 的父级部门 `XXX分公司` 仍为启用状态
+        let (repository, service) = dept_service(policy);
+        set_status(repository.as_ref(), ROOT_ID, 0).await;
+
+        let result = service
+            .edit_dept_status(OPS_DEPT_ID, 1, Locale::default())
+            .await;
+        let added = service
+            .add_dept(
+                DeptParam {
+                    parent_id: Some(BRANCH_OFFICE_ID.to_string()),
+                    name: Some("测试部门".to_string()),
+                    status: Some(1),
+                    ..Default::default()
+                },
+                Locale::default(),
+            )
+            .await;
+        let edited = service
+            .edit_dept(
+                DeptParam {
+                    id: Some(OPS_DEPT_ID.to_string()),
+                    parent_id: Some(BRANCH_OFFICE_ID.to_string()),
+                    name: Some("运维".to_string()),
+                    status: Some(1),
+                    ..Default::default()
+                },
+                Locale::default(),
+            )
+            .await;
+        if policy.require_enabled_ancestors() {
+            for code in [result.get_code(), added.get_code(), edited.get_code()] {
+                assert_eq!(code, -5, "{policy}");
+            }
+            assert_eq!(dept(repository.as_ref(), OPS_DEPT_ID).await.status, Some(0), "{policy}");
+        } else {
+            assert!(result.is_success(), "{policy}: {}", result.get_message());
+            assert!(added.is_success(), "{policy}: {}", added.get_message());
+            assert!(edited.is_success(), "{policy}: {}", edited.get_message());
+        }
+
+        // 新增停用的部门不受影响
+        let added = service
+            .add_dept(
+                DeptParam {
+                    parent_id: Some(BRANCH_OFFICE_ID.to_string()),
+                    name: Some("停用的测试部门".to_string()),
+                    status: Some(0),
+                    ..Default::default()
+                },
+                Locale::default(),
+            )
+            .await;
+        assert!(added.is_success(), "{policy}: {}", added.get_message());
+    }
+}
+
+/// 测试移动到已停用的上级部门下时按策略拒绝移动，或停用部门及其全部下级部门
+#[tokio::test]
+async fn test_move_under_disabled_ancestor() {
+    for policy in POLICIES {
+        // `XXX总公司` 已停用，其下的 `研发部` 仍为启用状态，`XXX分公司` 及 `运维` 均已启用
+        let (repository, service) = dept_service(policy);
+        set_status(repository.as_ref(), HEAD_OFFICE_ID, 0).await;
+        set_status(repository.as_ref(), OPS_DEPT_ID, 1).await;
+
+        let result = service
+            .move_dept(BRANCH_OFFICE_ID, RD_DEPT_ID, Locale::default())
+            .await;
+        let branch_office = dept(repository.as_ref(), BRANCH_OFFICE_ID).await;
+        let ops_dept = dept(repository.as_ref(), OPS_DEPT_ID).await;
+        if policy.require_enabled_ancestors() {
+            assert_eq!(result.get_code(), -5, "{policy}");
+            assert_eq!(branch_office.parent_id.as_deref(), Some(ROOT_ID), "{policy}");
+            continue;
+        }
+        assert!(result.is_success(), "{policy}: {}", result.get_message());
+        assert_eq!(branch_office.parent_id.as_deref(), Some(RD_DEPT_ID), "{policy}");
+        let expected = if policy.cascade_disable() { Some(0) } else { Some(1) };
+        assert_eq!((branch_office.status, ops_dept.status), (expected, expected), "{policy}");
+    }
+
+    // 停用的部门可以移动，下级部门随之停用
+    let (repository, service) = dept_service(DeptStatusPolicy::Strict);
+    set_status(repository.as_ref(), HEAD_OFFICE_ID, 0).await;
+    set_status(repository.as_ref(), BRANCH_OFFICE_ID, 0).await;
+    set_status(repository.as_ref(), OPS_DEPT_ID, 1).await;
+    let result = service
+        .move_dept(BRANCH_OFFICE_ID, RD_DEPT_ID, Locale::default())
+        .await;
+    assert!(result.is_success(), "{}", result.get_message());
+    assert_eq!(dept(repository.as_ref(), OPS_DEPT_ID).await.status, Some(0));
+
+    // 编辑部门修改父级部门时规则相同
+    let (repository, service) = dept_service(DeptStatusPolicy::Strict);
+    set_status(repository.as_ref(), HEAD_OFFICE_ID, 0).await;
+    let result = service
+        .edit_dept(branch_office(RD_DEPT_ID, 1), Locale::default())
+        .await;
+    assert_eq!(result.get_code(), -5);
+    let (repository, service) = dept_service(DeptStatusPolicy::CascadeDisable);
+    set_status(repository.as_ref(), HEAD_OFFICE_ID, 0).await;
+    set_status(repository.as_ref(), OPS_DEPT_ID, 1).await;
+    let result = service
+        .edit_dept(branch_office(RD_DEPT_ID, 1), Locale::default())
+        .await;
+    assert!(result.is_success(), "{}", result.get_message());
+    assert_eq!(dept(repository.as_ref(), BRANCH_OFFICE_ID).await.status, Some(0));
+    assert_eq!(dept(repository.as_ref(), OPS_DEPT_ID).await.status, Some(0));
+}
+
+/// 测试上级部门已停用时按策略拒绝恢复启用的部门
+#[tokio::test]
+async fn test_restore_under_disabled_ancestor() {
+    for policy in POLICIES {
+        // 删除启用的 `运维` 后停用根部门，父级部门 `XXX分公司` 仍为启用状态
+        let (repository, service) = dept_service(policy);
+        set_status(repository.as_ref(), OPS_DEPT_ID, 1).await;
+        let deleted = service.delete_dept(OPS_DEPT_ID, Locale::default()).await;
+        assert!(deleted.is_success(), "{policy}: {}", deleted.get_message());
+        set_status(repository.as_ref(), ROOT_ID, 0).await;
+
+        let result = service.restore_dept(OPS_DEPT_ID, Locale::default()).await;
+        if policy.require_enabled_ancestors() {
+            assert_eq!(result.get_code(), -5, "{policy}");
+            assert!(
+                repository
+                    .select_by_primary_key(OPS_DEPT_ID)
+                    .await
+                    .unwrap()
+                    .is_none()
+            );
+        } else {
+            assert!(result.is_success(), "{policy}: {}", result.get_message());
+        }
+    }
+}
@@ -0,0 +1,30 @@
+//! 部门状态策略测试
+//!
+//! 验证策略的配置值解析及各策略包含的规则
+
+use dept_core::services::dept::dept_status_policy::DeptStatusPolicy;
+
+/// 测试配置值解析
+#[test]
+fn test_parse() {
+    for policy in [DeptStatusPolicy::Independent, DeptStatusPolicy::CascadeDisable, DeptStatusPolicy::RequireEnabledAncestors, DeptStatusPolicy::Strict] {
+        assert_eq!(policy.to_string().parse::<DeptStatusPolicy>(), Ok(policy));
+    }
+    // 忽略首尾空格及大小写
+    assert_eq!(" Cascade_Disable ".parse::<DeptStatusPolicy>(), Ok(DeptStatusPolicy::CascadeDisable));
+    assert!("cascade".parse::<DeptStatusPolicy>().is_err());
+}
+
+/// 测试各策略包含的规则
+#[test]
+fn test_rules() {
+    assert_eq!(DeptStatusPolicy::default(), DeptStatusPolicy::Independent);
+    assert!(DeptStatusPolicy::Strict.cascade_disable());
+    assert!(DeptStatusPolicy::Strict.require_enabled_ancestors());
+    assert!(DeptStatusPolicy::CascadeDisable.cascade_disable());
+    assert!(!DeptStatusPolicy::CascadeDisable.require_enabled_ancestors());
+    assert!(!DeptStatusPolicy::RequireEnabledAncestors.cascade_disable());
+    assert!(DeptStatusPolicy::RequireEnabledAncestors.require_enabled_ancestors());
+    assert!(!DeptStatusPolicy::Independent.cascade_disable());
+    assert!(!DeptStatusPolicy::Independent.require_enabled_ancestors());
+}
@@ -0,0 +1,8 @@
+-- 删除演示库的全部表结构（MySQL）
+
+DROP TABLE IF EXISTS sys_user_role;
+DROP TABLE IF EXISTS sys_user;
+DROP TABLE IF EXISTS sys_role_menu;
+DROP TABLE IF EXISTS sys_role;
+DROP TABLE IF EXISTS sys_menu;
+DROP TABLE IF EXISTS sys_dept;
@@ -0,0 +1,96 @@
+-- 创建演示库的全部表结构（MySQL）
+-- 使用 IF NOT EXISTS，已按旧版建表脚本建好表的数据库执行时不会报错
+
+CREATE TABLE IF NOT EXISTS `sys_dept` (
+  `id` char(32) NOT NULL COMMENT '部门id',
+  `name` varchar(30) DEFAULT NULL COMMENT '部门名称',
+  `email` varchar(50) DEFAULT NULL COMMENT '邮箱',
+  `telephone` varchar(11) DEFAULT NULL COMMENT '联系电话',
+  `address` varchar(200) DEFAULT NULL COMMENT '地址',
+  `logo` varchar(100) DEFAULT NULL COMMENT 'logo地址',
+  `parent_id` char(32) DEFAULT NULL COMMENT '父部门id',
+  `seq_no` int DEFAULT NULL COMMENT '显示顺序',
+  `status` int DEFAULT NULL COMMENT '部门状态(0正常 1停用)',
+  `create_by` varchar(30) DEFAULT NULL COMMENT '创建者',
+  `create_time` datetime DEFAULT NULL COMMENT '创建时间',
+  `update_by` varchar(30) DEFAULT NULL COMMENT '更新者',
+  `update_time` datetime DEFAULT NULL COMMENT '更新时间',
+  `remark` varchar(200) DEFAULT NULL COMMENT '备注',
+  PRIMARY KEY (`id`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COMMENT='部门表';
+
+CREATE TABLE IF NOT EXISTS `sys_menu` (
+  `id` char(32) NOT NULL COMMENT '菜单ID',
+  `name` varchar(30) DEFAULT NULL COMMENT '菜单名称',
+  `parent_id` char(32) DEFAULT NULL COMMENT '父菜单ID',
+  `seq_no` int DEFAULT NULL COMMENT '显示顺序',
+  `menu_type` char(1) DEFAULT NULL COMMENT '菜单类型（D目录 M菜单 B按钮',
+  `url` varchar(200) DEFAULT NULL COMMENT '请求地址',
+  `perms` varchar(100) DEFAULT NULL COMMENT '权限标识',
+  `status` int DEFAULT NULL COMMENT '菜单状态(0停用 1正常)',
+  `hidden` int DEFAULT NULL COMMENT '是否在侧边栏隐藏(0显示 1隐藏)',
+  `always_show` int DEFAULT NULL COMMENT '是否始终显示根菜单(0隐藏 1显示)',
+  `redirect` varchar(200) DEFAULT NULL COMMENT '重定向地址，当设置 noRedirect 的时候该路由在面包屑导航中不可被点击',
+  `component` varchar(200) DEFAULT NULL COMMENT '当前路由外层包裹的组件信息(嵌套路由)',
+  `href` varchar(200) DEFAULT NULL COMMENT '外部链接地址',
+  `icon` varchar(200) DEFAULT NULL COMMENT '侧边栏中显示的图标',
+  `no_cache` int DEFAULT NULL COMMENT '不缓存页面(0缓存 1不缓存)',
+  `affix` int DEFAULT NULL COMMENT '页面附加在标签视图中(0不附加 1附加)',
+  `breadcrumb` int DEFAULT NULL COMMENT '该项目将隐藏在breadcrumb中(0隐藏 1显示)',
+  `active_menu` varchar(200) DEFAULT NULL COMMENT ' 如果设置路径，侧边栏会突出显示您设置的路径(例: /example/list)',
+  `create_by` varchar(30) DEFAULT NULL COMMENT '创建者',
+  `create_time` datetime DEFAULT NULL COMMENT '创建时间',
+  `update_by` varchar(30) DEFAULT NULL COMMENT '更新者',
+  `update_time` datetime DEFAULT NULL COMMENT '更新时间',
+  `remark` varchar(200) DEFAULT NULL COMMENT '备注',
+  PRIMARY KEY (`id`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COMMENT='菜单权限表';
+
+CREATE TABLE IF NOT EXISTS `sys_role` (
+  `id` char(32) NOT NULL COMMENT '角色ID',
+  `name` varchar(30) DEFAULT NULL COMMENT '角色名称',
+  `role_key` varchar(100) DEFAULT NULL COMMENT '角色权限字符串',
+  `seq_no` int DEFAULT NULL COMMENT '显示顺序',
+  `status` int DEFAULT NULL COMMENT '角色状态(0停用 1正常)',
+  `create_by` varchar(30) DEFAULT NULL COMMENT '创建者',
+  `create_time` datetime DEFAULT NULL COMMENT '创建时间',
+  `update_by` varchar(30) DEFAULT NULL COMMENT '更新者',
+  `update_time` datetime DEFAULT NULL COMMENT '更新时间',
+  `remark` varchar(200) DEFAULT NULL COMMENT '备注',
+  PRIMARY KEY (`id`),
+  UNIQUE KEY `uk_role_key` (`role_key`),
+  UNIQUE KEY `uk_rolename` (`name`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COMMENT='角色信息表';
+
+CREATE TABLE IF NOT EXISTS `sys_role_menu` (
+  `role_id` char(32) NOT NULL COMMENT '角色ID',
+  `menu_id` char(32) NOT NULL COMMENT '菜单ID',
+  PRIMARY KEY (`role_id`,`menu_id`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COMMENT='角色和菜单关联表';
+
+CREATE TABLE IF NOT EXISTS `sys_user` (
+  `id` char(32) NOT NULL COMMENT '用户ID',
+  `dept_id` char(32) DEFAULT NULL COMMENT '部门ID',
+  `name` varchar(30) DEFAULT NULL COMMENT '用户账号',
+  `email` varchar(50) DEFAULT NULL COMMENT '用户邮箱',
+  `phone_number` varchar(11) DEFAULT NULL COMMENT '手机号码',
+  `sex` char(1) DEFAULT NULL COMMENT '用户性别(0未知 1男 2女)',
+  `password` varchar(100) DEFAULT NULL COMMENT '密码',
+  `avatar` varchar(100) DEFAULT NULL COMMENT '头像',
+  `status` int DEFAULT NULL COMMENT '账号状态(0停用 1正常)',
+  `login_ip` varchar(128) DEFAULT NULL COMMENT '最后登录IP',
+  `login_time` datetime DEFAULT NULL COMMENT '最后登录时间',
+  `create_by` varchar(30) DEFAULT NULL COMMENT '创建者',
+  `create_time` datetime DEFAULT NULL COMMENT '创建时间',
+  `update_by` varchar(30) DEFAULT NULL COMMENT '更新者',
+  `update_time` datetime DEFAULT NULL COMMENT '更新时间',
+  `remark` varchar(200) DEFAULT NULL COMMENT '备注',
+  PRIMARY KEY (`id`),
+  UNIQUE KEY `uk_username` (`name`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COMMENT='用户信息表';
+
+CREATE TABLE IF NOT EXISTS `sys_user_role` (
+  `user_id` char(32) NOT NULL COMMENT '用户ID',
+  `role_id` char(32) NOT NULL COMMENT '角色ID',
+  PRIMARY KEY (`user_id`,`role_id`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COMMENT='用户和角色关联表';
@@ -0,0 +1,102 @@
+-- 创建演示库的全部表结构（SQLite）
+-- 使用 IF NOT EXISTS，已按旧版建表脚本建好表的数据库执行时不会报错
+
+-- 部门表
+CREATE TABLE IF NOT EXISTS sys_dept (
+  id char(32) NOT NULL, -- 部门id
+  name varchar(30) DEFAULT NULL, -- 部门名称
+  email varchar(50) DEFAULT NULL, -- 邮箱
+  telephone varchar(11) DEFAULT NULL, -- 联系电话
+  address varchar(200) DEFAULT NULL, -- 地址
+  logo varchar(100) DEFAULT NULL, -- logo地址
+  parent_id char(32) DEFAULT NULL, -- 父部门id
+  seq_no int DEFAULT NULL, -- 显示顺序
+  status int DEFAULT NULL, -- 部门状态(0正常 1停用)
+  create_by varchar(30) DEFAULT NULL, -- 创建者
+  create_time datetime DEFAULT NULL, -- 创建时间
+  update_by varchar(30) DEFAULT NULL, -- 更新者
+  update_time datetime DEFAULT NULL, -- 更新时间
+  remark varchar(200) DEFAULT NULL, -- 备注
+  PRIMARY KEY (id)
+);
+
+-- 菜单权限表
+CREATE TABLE IF NOT EXISTS sys_menu (
+  id char(32) NOT NULL, -- 菜单ID
+  name varchar(30) DEFAULT NULL, -- 菜单名称
+  parent_id char(32) DEFAULT NULL, -- 父菜单ID
+  seq_no int DEFAULT NULL, -- 显示顺序
+  menu_type char(1) DEFAULT NULL, -- 菜单类型（D目录 M菜单 B按钮
+  url varchar(200) DEFAULT NULL, -- 请求地址
+  perms varchar(100) DEFAULT NULL, -- 权限标识
+  status int DEFAULT NULL, -- 菜单状态(0停用 1正常)
+  hidden int DEFAULT NULL, -- 是否在侧边栏隐藏(0显示 1隐藏)
+  always_show int DEFAULT NULL, -- 是否始终显示根菜单(0隐藏 1显示)
+  redirect varchar(200) DEFAULT NULL, -- 重定向地址，当设置 noRedirect 的时候该路由在面包屑导航中不可被点击
+  component varchar(200) DEFAULT NULL, -- 当前路由外层包裹的组件信息(嵌套路由)
+  href varchar(200) DEFAULT NULL, -- 外部链接地址
+  icon varchar(200) DEFAULT NULL, -- 侧边栏中显示的图标
+  no_cache int DEFAULT NULL, -- 不缓存页面(0缓存 1不缓存)
+  affix int DEFAULT NULL, -- 页面附加在标签视图中(0不附加 1附加)
+  breadcrumb int DEFAULT NULL, -- 该项目将隐藏在breadcrumb中(0隐藏 1显示)
+  active_menu varchar(200) DEFAULT NULL, -- 如果设置路径，侧边栏会突出显示您设置的路径(例: /example/list)
+  create_by varchar(30) DEFAULT NULL, -- 创建者
+  create_time datetime DEFAULT NULL, -- 创建时间
+  update_by varchar(30) DEFAULT NULL, -- 更新者
+  update_time datetime DEFAULT NULL, -- 更新时间
+  remark varchar(200) DEFAULT NULL, -- 备注
+  PRIMARY KEY (id)
+);
+
+-- 角色信息表
+CREATE TABLE IF NOT EXISTS sys_role (
+  id char(32) NOT NULL, -- 角色ID
+  name varchar(30) DEFAULT NULL, -- 角色名称
+  role_key varchar(100) DEFAULT NULL, -- 角色权限字符串
+  seq_no int DEFAULT NULL, -- 显示顺序
+  status int DEFAULT NULL, -- 角色状态(0停用 1正常)
+  create_by varchar(30) DEFAULT NULL, -- 创建者
+  create_time datetime DEFAULT NULL, -- 创建时间
+  update_by varchar(30) DEFAULT NULL, -- 更新者
+  update_time datetime DEFAULT NULL, -- 更新时间
+  remark varchar(200) DEFAULT NULL, -- 备注
+  PRIMARY KEY (id),
+  CONSTRAINT uk_role_key UNIQUE (role_key),
+  CONSTRAINT uk_rolename UNIQUE (name)
+);
+
+-- 角色和菜单关联表
+CREATE TABLE IF NOT EXISTS sys_role_menu (
+  role_id char(32) NOT NULL, -- 角色ID
+  menu_id char(32) NOT NULL, -- 菜单ID
+  PRIMARY KEY (role_id,menu_id)
+);
+
+-- 用户信息表
+CREATE TABLE IF NOT EXISTS sys_user (
+  id char(32) NOT NULL, -- 用户ID
+  dept_id char(32) DEFAULT NULL, -- 部门ID
+  name varchar(30) DEFAULT NULL, -- 用户账号
+  email varchar(50) DEFAULT NULL, -- 用户邮箱
+  phone_number varchar(11) DEFAULT NULL, -- 手机号码
+  sex char(1) DEFAULT NULL, -- 用户性别(0未知 1男 2女)
+  password varchar(100) DEFAULT NULL, -- 密码
+  avatar varchar(100) DEFAULT NULL, -- 头像
+  status int DEFAULT NULL, -- 账号状态(0停用 1正常)
+  login_ip varchar(128) DEFAULT NULL, -- 最后登录IP
+  login_time datetime DEFAULT NULL, -- 最后登录时间
+  create_by varchar(30) DEFAULT NULL, -- 创建者
+  create_time datetime DEFAULT NULL, -- 创建时间
+  update_by varchar(30) DEFAULT NULL, -- 更新者
+  update_time datetime DEFAULT NULL, -- 更新时间
+  remark varchar(200) DEFAULT NULL, -- 备注
+  PRIMARY KEY (id),
+  CONSTRAINT uk_username UNIQUE (name)
+);
+
+-- 用户和角色关联表
+CREATE TABLE IF NOT EXISTS sys_user_role (
+  user_id char(32) NOT NULL, -- 用户ID
+  role_id char(32) NOT NULL, -- 角色ID
+  PRIMARY KEY (user_id,role_id)
+);
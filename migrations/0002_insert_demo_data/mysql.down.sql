@@ -0,0 +1,83 @@
+-- 删除演示数据（MySQL），只删除本迁移导入的记录
+
+DELETE FROM sys_user_role WHERE (user_id, role_id) IN (
+('03a3aee395b04c43b2bc08af426fe0a8', '36f0df335b6a4293b3ebd8e7a27e6026'),
+('0aa01bb4d3f7423f9e11336cf5ca6ef5', '36f0df335b6a4293b3ebd8e7a27e6026'),
+('9057fae81b7345e2ac725346278fb9bb', '3f536e29949b4723a8fb4514411d50df')
+);
+
+DELETE FROM sys_user WHERE id IN (
+'0aa01bb4d3f7423f9e11336cf5ca6ef5',
+'9057fae81b7345e2ac725346278fb9bb'
+);
+
+DELETE FROM sys_role_menu WHERE (role_id, menu_id) IN (
+('36f0df335b6a4293b3ebd8e7a27e6026', '0471759cae664f9e910df35b22610397'),
+('36f0df335b6a4293b3ebd8e7a27e6026', '0ed02fde4a13409d8e0d7d50e1afdee9'),
+('36f0df335b6a4293b3ebd8e7a27e6026', '0fdf3ff4c5b348deb960e989e6ee83af'),
+('36f0df335b6a4293b3ebd8e7a27e6026', '1d5879088287492098a1adf2cf4b3bd3'),
+('36f0df335b6a4293b3ebd8e7a27e6026', '233daa1502c344d685adc9341d11d1e9'),
+('36f0df335b6a4293b3ebd8e7a27e6026', '3779ea2c1666420a8e1e4f7bd5556305'),
+('36f0df335b6a4293b3ebd8e7a27e6026', '4ee4ba300f9e4baa8a319b13a8eba9a8'),
+('36f0df335b6a4293b3ebd8e7a27e6026', '6c099c5d5f044d50b22ef89337e2a2fc'),
+('36f0df335b6a4293b3ebd8e7a27e6026', '7aaf9913860a4510b709026ab7a424a2'),
+('36f0df335b6a4293b3ebd8e7a27e6026', '7d0a685469044d549409059459b0a30c'),
+('36f0df335b6a4293b3ebd8e7a27e6026', '94da045292c94840ab35f97920784c3b'),
+('36f0df335b6a4293b3ebd8e7a27e6026', 'a36d7bcfc2a441359a48b00331a33e37'),
+('36f0df335b6a4293b3ebd8e7a27e6026', 'a519fd3031a6445d91a8ac06d7a169ea'),
+('36f0df335b6a4293b3ebd8e7a27e6026', 'abb861bd5da94a4e884b5e70758754be'),
+('36f0df335b6a4293b3ebd8e7a27e6026', 'acd5dd88d3dd4154bbfb56899dfc1eb6'),
+('36f0df335b6a4293b3ebd8e7a27e6026', 'b75d2b9178f84a34bb5b5bad67efc030'),
+('36f0df335b6a4293b3ebd8e7a27e6026', 'b787bac121734a13bc37caed22e402a0'),
+('36f0df335b6a4293b3ebd8e7a27e6026', 'bc9ae5bd610848abae58f0f56387c7df'),
+('36f0df335b6a4293b3ebd8e7a27e6026', 'c083a729a60447d18893fe3f4d206d6c'),
+('36f0df335b6a4293b3ebd8e7a27e6026', 'c1ab532b91fb405fb223ef679a656c8f'),
+('36f0df335b6a4293b3ebd8e7a27e6026', 'd040bcff21954c748f862645d2b43711'),
+('36f0df335b6a4293b3ebd8e7a27e6026', 'd815a5167ad84c2aab29aa9ab080ca7c'),
+('36f0df335b6a4293b3ebd8e7a27e6026', 'e93ac37073b84f569f46b307e9ab4cc6'),
+('36f0df335b6a4293b3ebd8e7a27e6026', 'ec0b6e0593ea42c3931a594b49c50f30')
+);
+
+DELETE FROM sys_role WHERE id IN (
+'36f0df335b6a4293b3ebd8e7a27e6026',
+'3f536e29949b4723a8fb4514411d50df'
+);
+
+DELETE FROM sys_menu WHERE id IN (
+'0471759cae664f9e910df35b22610397',
+'0ed02fde4a13409d8e0d7d50e1afdee9',
+'0fdf3ff4c5b348deb960e989e6ee83af',
+'1d5879088287492098a1adf2cf4b3bd3',
+'233daa1502c344d685adc9341d11d1e9',
+'3779ea2c1666420a8e1e4f7bd5556305',
+'4ee4ba300f9e4baa8a319b13a8eba9a8',
+'6c099c5d5f044d50b22ef89337e2a2fc',
+'7aaf9913860a4510b709026ab7a424a2',
+'7d0a685469044d549409059459b0a30c',
+'94da045292c94840ab35f97920784c3b',
+'9ac1f8a41eae449fa5a0656f90ae88f0',
+'a36d7bcfc2a441359a48b00331a33e37',
+'a519fd3031a6445d91a8ac06d7a169ea',
+'abb861bd5da94a4e884b5e70758754be',
+'acd5dd88d3dd4154bbfb56899dfc1eb6',
+'b75d2b9178f84a34bb5b5bad67efc030',
+'b787bac121734a13bc37caed22e402a0',
+'bc9ae5bd610848abae58f0f56387c7df',
+'c083a729a60447d18893fe3f4d206d6c',
+'c1ab532b91fb405fb223ef679a656c8f',
+'d040bcff21954c748f862645d2b43711',
+'d815a5167ad84c2aab29aa9ab080ca7c',
+'e93ac37073b84f569f46b307e9ab4cc6',
+'ec0b6e0593ea42c3931a594b49c50f30'
+);
+
+DELETE FROM sys_dept WHERE id IN (
+'065a3eb180214ccfbb653f63287d285d',
+'0753bee6314c4f6fa01cb5166e6e4b14',
+'3fd2fbc8c93e4a20a6e46387b36c0264',
+'6bcbfa6a71fa454d8363b885ccbcb30a',
+'88921a7b70a04ea2b7d1db1651ffe666',
+'a308936d3b5d49e185fe15bf7d71b688',
+'ebd8118fe94247809abab8c72579cf7d',
+'f9271a783e074715aceeb4d9b427004d'
+);
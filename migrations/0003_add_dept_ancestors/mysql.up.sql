@@ -0,0 +1,16 @@
+-- 部门表增加祖级路径（MySQL）
+-- 祖级路径由根部门到父级部门的id依次组成，以 / 分隔且首尾都带 /，根部门为 /，
+-- 某个部门的全部下级部门的祖级路径都以 `该部门的祖级路径 + 该部门id + /` 开头，按前缀匹配即可使用索引查询整棵子树
+-- 表的默认排序规则不区分大小写，前缀匹配会把只有大小写不同的部门id当作同一路径，字段使用二进制排序规则
+
+ALTER TABLE `sys_dept` ADD COLUMN `ancestors` varchar(500) CHARACTER SET utf8mb4 COLLATE utf8mb4_bin DEFAULT NULL COMMENT '祖级路径';
+
+CREATE INDEX `idx_sys_dept_ancestors` ON `sys_dept` (`ancestors`);
+
+-- 从根部门开始逐级回填已有部门的祖级路径，无法追溯到根部门的部门保持NULL
+WITH RECURSIVE dept_path (id, ancestors) AS (
+  SELECT id, CAST('/' AS CHAR(500)) FROM sys_dept WHERE parent_id IS NULL OR parent_id = ''
+  UNION ALL
+  SELECT d.id, CONCAT(p.ancestors, p.id, '/') FROM sys_dept d JOIN dept_path p ON d.parent_id = p.id
+)
+UPDATE `sys_dept` d JOIN dept_path p ON d.id = p.id SET d.ancestors = p.ancestors;
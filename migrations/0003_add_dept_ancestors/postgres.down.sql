@@ -0,0 +1,3 @@
+-- 删除部门表的祖级路径（PostgreSQL），字段上的索引随字段一起删除
+
+ALTER TABLE sys_dept DROP COLUMN IF EXISTS ancestors;
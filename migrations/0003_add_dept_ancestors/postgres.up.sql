@@ -0,0 +1,16 @@
+-- 部门表增加祖级路径（PostgreSQL）
+-- 祖级路径由根部门到父级部门的id依次组成，以 / 分隔且首尾都带 /，根部门为 /，
+-- 某个部门的全部下级部门的祖级路径都以 `该部门的祖级路径 + 该部门id + /` 开头，按前缀匹配即可使用索引查询整棵子树
+-- PostgreSQL的LIKE区分大小写，数据库排序规则不是C时，LIKE前缀匹配只能使用 varchar_pattern_ops 索引
+
+ALTER TABLE sys_dept ADD COLUMN IF NOT EXISTS ancestors varchar(500) DEFAULT NULL; -- 祖级路径
+
+CREATE INDEX IF NOT EXISTS idx_sys_dept_ancestors ON sys_dept (ancestors varchar_pattern_ops);
+
+-- 从根部门开始逐级回填已有部门的祖级路径，无法追溯到根部门的部门保持NULL
+WITH RECURSIVE dept_path (id, ancestors) AS (
+  SELECT id, CAST('/' AS text) FROM sys_dept WHERE parent_id IS NULL OR parent_id = ''
+  UNION ALL
+  SELECT d.id, p.ancestors || p.id || '/' FROM sys_dept d JOIN dept_path p ON d.parent_id = p.id
+)
+UPDATE sys_dept SET ancestors = p.ancestors FROM dept_path p WHERE sys_dept.id = p.id;
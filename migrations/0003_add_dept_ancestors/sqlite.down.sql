@@ -0,0 +1,4 @@
+-- 删除部门表的祖级路径（SQLite），删除字段前需要先删除字段上的索引
+
+DROP INDEX IF EXISTS idx_sys_dept_ancestors;
+ALTER TABLE sys_dept DROP COLUMN ancestors;
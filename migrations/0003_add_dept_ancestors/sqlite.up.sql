@@ -0,0 +1,17 @@
+-- 部门表增加祖级路径（SQLite）
+-- 祖级路径由根部门到父级部门的id依次组成，以 / 分隔且首尾都带 /，根部门为 /，
+-- 某个部门的全部下级部门的祖级路径都以 `该部门的祖级路径 + 该部门id + /` 开头，按前缀匹配即可使用索引查询整棵子树
+-- SQLite的LIKE默认不区分大小写，会把只有大小写不同的部门id当作同一路径，字段使用默认的BINARY排序规则，
+-- 前缀匹配使用区分大小写的GLOB，同样可以使用索引
+
+ALTER TABLE sys_dept ADD COLUMN ancestors varchar(500) DEFAULT NULL; -- 祖级路径
+
+CREATE INDEX IF NOT EXISTS idx_sys_dept_ancestors ON sys_dept (ancestors);
+
+-- 从根部门开始逐级回填已有部门的祖级路径，无法追溯到根部门的部门保持NULL
+WITH RECURSIVE dept_path (id, ancestors) AS (
+  SELECT id, '/' FROM sys_dept WHERE parent_id IS NULL OR parent_id = ''
+  UNION ALL
+  SELECT d.id, p.ancestors || p.id || '/' FROM sys_dept d JOIN dept_path p ON d.parent_id = p.id
+)
+UPDATE sys_dept SET ancestors = p.ancestors FROM dept_path p WHERE sys_dept.id = p.id;
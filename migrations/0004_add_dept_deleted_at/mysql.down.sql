@@ -0,0 +1,4 @@
+-- 删除部门表的删除时间（MySQL），已删除的部门先彻底删除，否则回滚后会重新出现
+
+DELETE FROM `sys_dept` WHERE `deleted_at` IS NOT NULL;
+ALTER TABLE `sys_dept` DROP COLUMN `deleted_at`;
@@ -0,0 +1,4 @@
+-- 部门表增加删除时间（PostgreSQL）
+-- 删除部门时只记录删除时间，NULL表示未删除，数据访问层的查询默认排除已删除的部门，恢复时重新置为NULL
+
+ALTER TABLE sys_dept ADD COLUMN IF NOT EXISTS deleted_at timestamp(0) DEFAULT NULL; -- 删除时间
@@ -5,19 +5,25 @@
 //! # 主要功能
 //!
 //! - 查询部门列表: [list_depts]
+//! - 分页查询部门列表: [page_depts]
 //! - 获取部门树: [get_dept_tree]
 //! - 添加部门: [add_dept]
 //! - 编辑部门: [edit_dept]
 //! - 删除部门: [delete_dept]
+//! - 恢复部门: [restore_dept]
+//! - 彻底删除部门: [purge_dept]
+//! - 移动部门: [move_dept]
 //! - 修改部门状态: [edit_dept_status]
 //!
 use rocket::serde::json::Json;
 use rocket::{State, delete, post, put, routes};
 
-use crate::params::dept_param::DeptParam;
-use crate::services::dept::dept_service::DeptService;
-use crate::views::{dept_tree::DeptTree, dept_vo::DeptVO};
-use common_wrapper::{ListWrapper, ResponseWrapper};
+use common_validation::{CreateGroup, UpdateGroup, ValidatedJson};
+use common_wrapper::i18n::Locale;
+use common_wrapper::{ListWrapper, PageWrapper, ResponseWrapper, SingleWrapper};
+use dept_core::params::dept_param::DeptParam;
+use dept_core::services::dept::dept_service::DeptService;
+use dept_core::views::{dept_tree::DeptTree, dept_vo::DeptVO};
 
 /// 部门控制器
 /// 查询部门列表
@@ -28,16 +34,36 @@ use common_wrapper::{ListWrapper, ResponseWrapper};
 ///
 /// - `dept_param`: 部门查询参数，类型: [Json]<[DeptParam]>，通过请求体传入
 /// - `dept_service`: 部门服务实例，类型: &[State]<[Box]<dyn [DeptService] + Send + Sync>>，通过Rocket依赖注入提供
+/// - `locale`: 响应消息的语言，类型: [Locale]，由请求头 `Accept-Language` 解析
 ///
 /// # 返回值
 ///
-/// 返回JSON格式的部门列表结果，类型: [Json]<[ListWrapper]<[DeptVO]>>，参见: [ListWrapper]<[DeptVO]>
+/// 返回JSON格式的部门列表结果，HTTP状态码由响应码决定，类型: [ListWrapper]<[DeptVO]>，参见: [ListWrapper]<[DeptVO]>
 #[post("/list", data = "<dept_param>")]
-pub async fn list_depts(dept_param: Json<DeptParam>, dept_service: &State<Box<dyn DeptService + Send + Sync>>) -> Json<ListWrapper<DeptVO>> {
-    let result: ListWrapper<DeptVO> = dept_service
-        .select_dept_vo_list(dept_param.into_inner())
-        .await;
-    Json(result)
+pub async fn list_depts(dept_param: Json<DeptParam>, dept_service: &State<Box<dyn DeptService + Send + Sync>>, locale: Locale) -> ListWrapper<DeptVO> {
+    dept_service
+        .select_dept_vo_list(dept_param.into_inner(), locale)
+        .await
+}
+
+/// 分页查询部门列表
+///
+/// 根据参数中的 `pageNum`、`pageSize` 在数据库端分页查询部门信息，返回当前页的部门VO列表及分页信息
+///
+/// # 参数
+///
+/// - `dept_param`: 部门查询参数，类型: [Json]<[DeptParam]>，通过请求体传入
+/// - `dept_service`: 部门服务实例，类型: &[State]<[Box]<dyn [DeptService] + Send + Sync>>，通过Rocket依赖注入提供
+/// - `locale`: 响应消息的语言，类型: [Locale]，由请求头 `Accept-Language` 解析
+///
+/// # 返回值
+///
+/// 返回JSON格式的部门分页结果，HTTP状态码由响应码决定，类型: [PageWrapper]<[DeptVO]>，参见: [PageWrapper]<[DeptVO]>
+#[post("/page", data = "<dept_param>")]
+pub async fn page_depts(dept_param: Json<DeptParam>, dept_service: &State<Box<dyn DeptService + Send + Sync>>, locale: Locale) -> PageWrapper<DeptVO> {
+    dept_service
+        .select_dept_vo_page(dept_param.into_inner(), locale)
+        .await
 }
 
 /// 获取部门树
@@ -48,87 +74,146 @@ pub async fn list_depts(dept_param: Json<DeptParam>, dept_service: &State<Box<dy
 ///
 /// - `dept_param`: 部门查询参数，类型: [Json]<[DeptParam]>，通过请求体传入
 /// - `dept_service`: 部门服务实例，类型: &[State]<[Box]<dyn [DeptService] + Send + Sync>>，通过Rocket依赖注入提供
+/// - `locale`: 响应消息的语言，类型: [Locale]，由请求头 `Accept-Language` 解析
 ///
 /// # 返回值
 ///
-/// 返回JSON格式的部门树结果，类型: [Json]<[ListWrapper]<[DeptTree]>>，参见: [ListWrapper]<[DeptTree]>
+/// 返回JSON格式的部门树结果，HTTP状态码由响应码决定，类型: [ListWrapper]<[DeptTree]>，参见: [ListWrapper]<[DeptTree]>
 #[post("/getDeptTree", data = "<dept_param>")]
-pub async fn get_dept_tree(dept_param: Json<DeptParam>, dept_service: &State<Box<dyn DeptService + Send + Sync>>) -> Json<ListWrapper<DeptTree>> {
-    let result = dept_service.get_dept_tree(dept_param.into_inner()).await;
-    Json(result)
+pub async fn get_dept_tree(dept_param: Json<DeptParam>, dept_service: &State<Box<dyn DeptService + Send + Sync>>, locale: Locale) -> ListWrapper<DeptTree> {
+    dept_service
+        .get_dept_tree(dept_param.into_inner(), locale)
+        .await
 }
 
 /// 添加部门
 ///
-/// 新增一个部门信息
+/// 新增一个部门信息，请求体按 [CreateGroup] 分组验证，验证失败时直接返回参数错误
 ///
 /// # 参数
 ///
-/// - `dept_param`: 部门参数，类型: [Json]<[DeptParam]>，通过请求体传入
+/// - `dept_param`: 部门参数，类型: [ValidatedJson]<[DeptParam], [CreateGroup]>，通过请求体传入
 /// - `dept_service`: 部门服务实例，类型: &[State]<[Box]<dyn [DeptService] + Send + Sync>>，通过Rocket依赖注入提供
+/// - `locale`: 响应消息的语言，类型: [Locale]，由请求头 `Accept-Language` 解析
 ///
 /// # 返回值
 ///
-/// 返回操作结果，类型: [Json]<[ResponseWrapper]>，参见: [ResponseWrapper]
+/// 返回操作结果，HTTP状态码由响应码决定，类型: [ResponseWrapper]，参见: [ResponseWrapper]
 #[post("/add", data = "<dept_param>")]
-pub async fn add_dept(dept_param: Json<DeptParam>, dept_service: &State<Box<dyn DeptService + Send + Sync>>) -> Json<ResponseWrapper> {
-    let result = dept_service.add_dept(dept_param.into_inner()).await;
-    Json(result)
+pub async fn add_dept(dept_param: ValidatedJson<DeptParam, CreateGroup>, dept_service: &State<Box<dyn DeptService + Send + Sync>>, locale: Locale) -> ResponseWrapper {
+    dept_service.add_dept(dept_param.into_inner(), locale).await
 }
 
 /// 编辑部门
 ///
-/// 修改部门信息
+/// 修改部门信息，请求体按 [UpdateGroup] 分组验证，验证失败时直接返回参数错误
 ///
 /// # 参数
 ///
-/// - `dept_param`: 部门参数，类型: [Json]<[DeptParam]>，通过请求体传入
+/// - `dept_param`: 部门参数，类型: [ValidatedJson]<[DeptParam], [UpdateGroup]>，通过请求体传入
 /// - `dept_service`: 部门服务实例，类型: &[State]<[Box]<dyn [DeptService] + Send + Sync>>，通过Rocket依赖注入提供
+/// - `locale`: 响应消息的语言，类型: [Locale]，由请求头 `Accept-Language` 解析
 ///
 /// # 返回值
 ///
-/// 返回操作结果，类型: [Json]<[ResponseWrapper]>，参见: [ResponseWrapper]
+/// 返回操作结果，HTTP状态码由响应码决定，类型: [ResponseWrapper]，参见: [ResponseWrapper]
 #[put("/edit", data = "<dept_param>")]
-pub async fn edit_dept(dept_param: Json<DeptParam>, dept_service: &State<Box<dyn DeptService + Send + Sync>>) -> Json<ResponseWrapper> {
-    let result = dept_service.edit_dept(dept_param.into_inner()).await;
-    Json(result)
+pub async fn edit_dept(dept_param: ValidatedJson<DeptParam, UpdateGroup>, dept_service: &State<Box<dyn DeptService + Send + Sync>>, locale: Locale) -> ResponseWrapper {
+    dept_service
+        .edit_dept(dept_param.into_inner(), locale)
+        .await
 }
 
 /// 删除部门
 ///
-/// 根据部门ID删除指定部门
+/// 根据部门ID逻辑删除指定部门，删除后可以恢复
 ///
 /// # 参数
 ///
 /// - `dept_id`: 部门ID，类型: [String]，通过URL路径传入
 /// - `dept_service`: 部门服务实例，类型: &[State]<[Box]<dyn [DeptService] + Send + Sync>>，通过Rocket依赖注入提供
+/// - `locale`: 响应消息的语言，类型: [Locale]，由请求头 `Accept-Language` 解析
 ///
 /// # 返回值
 ///
-/// 返回操作结果，类型: [Json]<[ResponseWrapper]>，参见: [ResponseWrapper]
+/// 返回操作结果，HTTP状态码由响应码决定，类型: [ResponseWrapper]，参见: [ResponseWrapper]
 #[delete("/delete/<dept_id>")]
-pub async fn delete_dept(dept_id: String, dept_service: &State<Box<dyn DeptService + Send + Sync>>) -> Json<ResponseWrapper> {
-    let result = dept_service.delete_dept(&dept_id).await;
-    Json(result)
+pub async fn delete_dept(dept_id: String, dept_service: &State<Box<dyn DeptService + Send + Sync>>, locale: Locale) -> ResponseWrapper {
+    dept_service.delete_dept(&dept_id, locale).await
+}
+
+/// 恢复部门
+///
+/// 根据部门ID恢复已删除的部门，原父级部门需要存在且未停用
+///
+/// # 参数
+///
+/// - `dept_id`: 部门ID，类型: [String]，通过URL路径传入
+/// - `dept_service`: 部门服务实例，类型: &[State]<[Box]<dyn [DeptService] + Send + Sync>>，通过Rocket依赖注入提供
+/// - `locale`: 响应消息的语言，类型: [Locale]，由请求头 `Accept-Language` 解析
+///
+/// # 返回值
+///
+/// 返回操作结果，HTTP状态码由响应码决定，类型: [ResponseWrapper]，参见: [ResponseWrapper]
+#[post("/restore/<dept_id>")]
+pub async fn restore_dept(dept_id: String, dept_service: &State<Box<dyn DeptService + Send + Sync>>, locale: Locale) -> ResponseWrapper {
+    dept_service.restore_dept(&dept_id, locale).await
+}
+
+/// 彻底删除部门
+///
+/// 根据部门ID彻底删除已删除的部门，删除后无法恢复
+///
+/// # 参数
+///
+/// - `dept_id`: 部门ID，类型: [String]，通过URL路径传入
+/// - `dept_service`: 部门服务实例，类型: &[State]<[Box]<dyn [DeptService] + Send + Sync>>，通过Rocket依赖注入提供
+/// - `locale`: 响应消息的语言，类型: [Locale]，由请求头 `Accept-Language` 解析
+///
+/// # 返回值
+///
+/// 返回操作结果，HTTP状态码由响应码决定，类型: [ResponseWrapper]，参见: [ResponseWrapper]
+#[delete("/purge/<dept_id>")]
+pub async fn purge_dept(dept_id: String, dept_service: &State<Box<dyn DeptService + Send + Sync>>, locale: Locale) -> ResponseWrapper {
+    dept_service.purge_dept(&dept_id, locale).await
+}
+
+/// 移动部门
+///
+/// 将部门连同其下级部门移动到新的父级部门下，新的父级部门不能为部门本身或其下级部门
+///
+/// # 参数
+///
+/// - `id`: 部门ID，类型: [String]，通过URL路径传入
+/// - `new_parent_id`: 新的父级部门ID，类型: [String]，通过URL路径传入
+/// - `dept_service`: 部门服务实例，类型: &[State]<[Box]<dyn [DeptService] + Send + Sync>>，通过Rocket依赖注入提供
+/// - `locale`: 响应消息的语言，类型: [Locale]，由请求头 `Accept-Language` 解析
+///
+/// # 返回值
+///
+/// 返回操作结果，HTTP状态码由响应码决定，类型: [ResponseWrapper]，参见: [ResponseWrapper]
+#[put("/move/<id>/<new_parent_id>")]
+pub async fn move_dept(id: String, new_parent_id: String, dept_service: &State<Box<dyn DeptService + Send + Sync>>, locale: Locale) -> ResponseWrapper {
+    dept_service.move_dept(&id, &new_parent_id, locale).await
 }
 
 /// 修改部门状态
 ///
-/// 根据部门ID修改部门状态
+/// 根据部门ID修改部门状态，按部门状态策略停用下级部门、检查上级部门
 ///
 /// # 参数
 ///
 /// - `id`: 部门ID，类型: [String]，通过URL路径传入
 /// - `status`: 部门状态，类型: [i32]，通过URL路径传入
 /// - `dept_service`: 部门服务实例，类型: &[State]<[Box]<dyn [DeptService] + Send + Sync>>，通过Rocket依赖注入提供
+/// - `locale`: 响应消息的语言，类型: [Locale]，由请求头 `Accept-Language` 解析
 ///
 /// # 返回值
 ///
-/// 返回操作结果，类型: [Json]<[ResponseWrapper]>，参见: [ResponseWrapper]
+/// 返回部门本身及下级部门实际更新的行数，HTTP状态码由响应码决定，类型: [SingleWrapper]<[u64]>
 #[put("/editStatus/<id>/<status>")]
-pub async fn edit_dept_status(id: String, status: i32, dept_service: &State<Box<dyn DeptService + Send + Sync>>) -> Json<ResponseWrapper> {
-    let result = dept_service.edit_dept_status(&id, status).await;
-    Json(result)
+pub async fn edit_dept_status(id: String, status: i32, dept_service: &State<Box<dyn DeptService + Send + Sync>>, locale: Locale) -> SingleWrapper<u64> {
+    dept_service.edit_dept_status(&id, status, locale).await
 }
 
 /// 注册部门相关路由
@@ -139,5 +224,5 @@ pub async fn edit_dept_status(id: String, status: i32, dept_service: &State<Box<
 ///
 /// 返回部门相关路由列表，类型: [Vec]<rocket::Route>
 pub fn routes() -> Vec<rocket::Route> {
-    routes![list_depts, get_dept_tree, add_dept, edit_dept, delete_dept, edit_dept_status]
+    routes![list_depts, page_depts, get_dept_tree, add_dept, edit_dept, delete_dept, restore_dept, purge_dept, move_dept, edit_dept_status]
 }
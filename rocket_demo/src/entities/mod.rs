@@ -1,5 +0,0 @@
-//! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.16
-
-pub mod prelude;
-
-pub mod sys_dept;
@@ -1,3 +0,0 @@
-//! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.16
-
-pub use super::sys_dept::Entity as SysDept;
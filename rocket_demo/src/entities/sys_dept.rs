@@ -1,28 +0,0 @@
-//! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.16
-
-use sea_orm::entity::prelude::*;
-
-#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
-#[sea_orm(table_name = "sys_dept")]
-pub struct Model {
-    #[sea_orm(primary_key, auto_increment = false)]
-    pub id: String,
-    pub name: Option<String>,
-    pub email: Option<String>,
-    pub telephone: Option<String>,
-    pub address: Option<String>,
-    pub logo: Option<String>,
-    pub parent_id: Option<String>,
-    pub seq_no: Option<i32>,
-    pub status: Option<i32>,
-    pub create_by: Option<String>,
-    pub create_time: Option<DateTime>,
-    pub update_by: Option<String>,
-    pub update_time: Option<DateTime>,
-    pub remark: Option<String>,
-}
-
-#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
-pub enum Relation {}
-
-impl ActiveModelBehavior for ActiveModel {}
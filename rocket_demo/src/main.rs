@@ -1,65 +1,58 @@
-mod config;
 mod controllers;
-mod models;
-mod params;
-mod repositories;
-mod services;
-mod views;
-
-// 只在使用SeaORM时才导入entities模块
-#[cfg(feature = "seaorm_impl")]
-mod entities;
-// 只在使用Diesel时才导入schema模块
-#[cfg(feature = "diesel_impl")]
-mod schema;
 
 use controllers::{dept::controller as dept_controller, index::controller as index_controller};
+use dept_core::services::dept::{dept_service::DeptService, dept_service_impl::DeptServiceImpl, dept_status_policy::DeptStatusPolicy};
 use rocket::figment::{
     Figment,
     providers::{Env, Format, Toml},
 };
-use services::dept::{dept_service::DeptService, dept_service_impl::DeptServiceImpl};
 
 // 为每种实现定义类型别名，简化条件编译代码
 #[cfg(feature = "sqlx_impl")]
-use repositories::dept::sqlx_impl::DeptRepositorySqlxImpl as DeptRepositoryImpl;
+use dept_core::repositories::dept::sqlx_impl::DeptRepositorySqlxImpl as DeptRepositoryImpl;
 
 #[cfg(feature = "diesel_impl")]
-use repositories::dept::diesel_impl::DeptRepositoryDieselImpl as DeptRepositoryImpl;
+use dept_core::repositories::dept::diesel_impl::DeptRepositoryDieselImpl as DeptRepositoryImpl;
 
 #[cfg(feature = "seaorm_impl")]
-use repositories::dept::seaorm_impl::DeptRepositorySeaormImpl as DeptRepositoryImpl;
+use dept_core::repositories::dept::seaorm_impl::DeptRepositorySeaormImpl as DeptRepositoryImpl;
+
+#[cfg(feature = "memory_impl")]
+use dept_core::repositories::dept::memory_impl::DeptRepositoryMemoryImpl as DeptRepositoryImpl;
 
 // 统一导入trait
-use repositories::dept::dept_repository::DeptRepository;
+use dept_core::repositories::dept::dept_repository::DeptRepository;
 use std::sync::Arc;
 
 #[rocket::launch]
 async fn rocket() -> _ {
     // 根据启用的特性初始化对应的数据访问层实现
     #[cfg(feature = "diesel_impl")]
-    let repository: Arc<dyn DeptRepository> = { Arc::new(DeptRepositoryImpl::new().expect("无法创建Diesel数据库连接")) };
+    let repository = DeptRepositoryImpl::new().expect("无法创建Diesel数据库连接");
 
     #[cfg(feature = "seaorm_impl")]
-    let repository: Arc<dyn DeptRepository> = {
-        Arc::new(
-            DeptRepositoryImpl::new()
-                .await
-                .expect("无法创建SeaORM数据库连接"),
-        )
-    };
+    let repository = DeptRepositoryImpl::new()
+        .await
+        .expect("无法创建SeaORM数据库连接");
 
     #[cfg(feature = "sqlx_impl")]
-    let repository: Arc<dyn DeptRepository> = {
-        Arc::new(
-            DeptRepositoryImpl::new()
-                .await
-                .expect("无法创建SQLx数据库连接"),
-        )
-    };
+    let repository = DeptRepositoryImpl::new()
+        .await
+        .expect("无法创建SQLx数据库连接");
+
+    // 执行 migrate 子命令后直接退出，否则在启动服务前应用或校验数据库迁移
+    #[cfg(any(feature = "sqlx_impl", feature = "diesel_impl", feature = "seaorm_impl"))]
+    dept_core::migrations::prepare(&repository).await;
+
+    // 内存实现不需要数据库，启动时载入演示数据，进程退出后修改即丢失
+    #[cfg(feature = "memory_impl")]
+    let repository = DeptRepositoryImpl::with_demo_data();
+
+    let repository: Arc<dyn DeptRepository> = Arc::new(repository);
 
-    // 初始化部门服务
-    let dept_service = Box::new(DeptServiceImpl::new(repository)) as Box<dyn DeptService + Send + Sync>;
+    // 初始化部门服务，修改部门状态时的层级策略可通过环境变量 DEPT_STATUS_POLICY 设置，默认为 independent
+    let status_policy = DeptStatusPolicy::from_env().expect("无法解析部门状态策略");
+    let dept_service = Box::new(DeptServiceImpl::new(repository).with_status_policy(status_policy)) as Box<dyn DeptService + Send + Sync>;
 
     // 创建自定义配置，配置优先级从低到高为：
     // 1. Rocket框架内置默认值（如address=127.0.0.1, port=8000）
@@ -74,4 +67,6 @@ async fn rocket() -> _ {
         .manage(dept_service)
         .mount("/", index_controller::routes())
         .mount("/dept", dept_controller::routes())
+        // 注册400捕获器，请求体未通过分组验证时以JSON返回参数错误
+        .register("/", common_validation::extract::rocket_extractor::catchers())
 }
@@ -1,4 +0,0 @@
-//! 部门服务模块
-
-pub mod dept_service;
-pub mod dept_service_impl;
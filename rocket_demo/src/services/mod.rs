@@ -1,10 +0,0 @@
-//! 系统业务层模块
-//!
-//! 该模块包含了系统中所有业务逻辑的实现。
-//! 业务层负责处理具体的业务逻辑，调用数据访问层进行数据操作，并向上层提供统一的业务接口。
-//!
-//! # 模块组织
-//!
-//! - [dept] - 部门相关业务逻辑实现，参见: [crate::repositories::dept]
-
-pub mod dept;